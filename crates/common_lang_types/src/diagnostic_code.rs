@@ -0,0 +1,92 @@
+use std::{fmt, str::FromStr};
+
+const DIAGNOSTIC_CODE_PREFIX: &str = "ISO";
+
+/// A stable code that identifies a kind of diagnostic, e.g. ISO0201. Codes are never
+/// reused, so they can be used to link to documentation, or to search for an error.
+/// `isograph explain ISO0201` prints a long-form explanation of the diagnostic.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct DiagnosticCode(pub u16);
+
+impl fmt::Display for DiagnosticCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{DIAGNOSTIC_CODE_PREFIX}{:04}", self.0)
+    }
+}
+
+impl FromStr for DiagnosticCode {
+    type Err = InvalidDiagnosticCode;
+
+    /// Parses codes such as ISO0201. The prefix is case-insensitive, and optional.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = match s.get(..DIAGNOSTIC_CODE_PREFIX.len()) {
+            Some(prefix) if prefix.eq_ignore_ascii_case(DIAGNOSTIC_CODE_PREFIX) => {
+                &s[DIAGNOSTIC_CODE_PREFIX.len()..]
+            }
+            _ => s,
+        };
+        if digits.is_empty() || digits.len() > 4 || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(InvalidDiagnosticCode(s.to_string()));
+        }
+        digits
+            .parse()
+            .map(DiagnosticCode)
+            .map_err(|_| InvalidDiagnosticCode(s.to_string()))
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvalidDiagnosticCode(pub String);
+
+impl fmt::Display for InvalidDiagnosticCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` is not a valid diagnostic code. Diagnostic codes look like ISO0201.",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for InvalidDiagnosticCode {}
+
+/// Implemented by errors that are reported to the user, so that each kind of error
+/// can be identified by a stable code.
+pub trait HasDiagnosticCode {
+    fn diagnostic_code(&self) -> DiagnosticCode;
+
+    /// A replacement for the erroneous text, e.g. the name of an existing field if
+    /// the selected field does not exist. Editors offer this as a quick fix.
+    fn suggestion(&self) -> Option<String> {
+        None
+    }
+
+    /// Whether the diagnostic reports the use of something that is deprecated, e.g. a
+    /// field marked @deprecated. Editors render the text at its location struck through.
+    fn reports_deprecation(&self) -> bool {
+        false
+    }
+}
+
+/// Errors cause compilation to fail, whereas warnings are only reported.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diagnostic_codes_round_trip() {
+        assert_eq!(DiagnosticCode(201).to_string(), "ISO0201");
+        assert_eq!("ISO0201".parse(), Ok(DiagnosticCode(201)));
+        assert_eq!("iso0201".parse(), Ok(DiagnosticCode(201)));
+        assert_eq!("0201".parse(), Ok(DiagnosticCode(201)));
+        assert!("ISO".parse::<DiagnosticCode>().is_err());
+        assert!("ISO02011".parse::<DiagnosticCode>().is_err());
+        assert!("E0201".parse::<DiagnosticCode>().is_err());
+    }
+}
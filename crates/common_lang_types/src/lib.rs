@@ -1,18 +1,22 @@
 mod absolute_and_relative_path;
+mod diagnostic_code;
 mod location;
 mod path_and_content;
 mod selectable_name;
 mod span;
 mod string_key_types;
 mod string_types;
+mod suggestion;
 mod text_with_carats;
 mod type_and_field;
 
 pub use absolute_and_relative_path::*;
+pub use diagnostic_code::*;
 pub use location::*;
 pub use path_and_content::*;
 pub use selectable_name::*;
 pub use span::*;
 pub use string_key_types::*;
 pub use string_types::*;
+pub use suggestion::*;
 pub use type_and_field::*;
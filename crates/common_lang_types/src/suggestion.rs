@@ -0,0 +1,97 @@
+use std::fmt::Display;
+
+use intern::Lookup;
+
+/// Returns the candidate that is closest to `name`, if any candidate is close enough
+/// that `name` is likely a typo of it (e.g. `fullname` and `fullName`). Differences
+/// in case are ignored, and ties are broken in favor of the earliest candidate.
+pub fn closest_match<T: Lookup + Copy>(
+    name: &str,
+    candidates: impl IntoIterator<Item = T>,
+) -> Option<T> {
+    let name = name.to_lowercase();
+    let max_distance = (name.chars().count() / 3).max(1);
+    candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            let distance = edit_distance(&name, &candidate.lookup().to_lowercase());
+            (distance <= max_distance).then_some((distance, candidate))
+        })
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Format a suggestion, to be appended to an error message.
+pub fn did_you_mean(suggestion: Option<impl Display>) -> String {
+    match suggestion {
+        Some(suggestion) => format!(" Did you mean `{suggestion}`?"),
+        None => String::new(),
+    }
+}
+
+/// The number of insertions, deletions, substitutions and transpositions of adjacent
+/// characters needed to turn one string into the other.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+
+    // distances[i][j] is the edit distance between a[..i] and b[..j]
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let substitution_cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + substitution_cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use intern::string_key::{Intern, StringKey};
+
+    use super::*;
+
+    fn candidates(names: &[&str]) -> Vec<StringKey> {
+        names.iter().map(|name| name.intern()).collect()
+    }
+
+    #[test]
+    fn closest_match_ignores_case_and_rejects_distant_names() {
+        let fields = candidates(&["firstName", "fullName", "id"]);
+        assert_eq!(
+            closest_match("fullname", fields.clone()).map(Lookup::lookup),
+            Some("fullName")
+        );
+        assert_eq!(
+            closest_match("fulName", fields.clone()).map(Lookup::lookup),
+            Some("fullName")
+        );
+        assert_eq!(
+            closest_match("nmae", candidates(&["name", "nickname"])).map(Lookup::lookup),
+            Some("name")
+        );
+        assert_eq!(closest_match("avatar", fields), None);
+    }
+
+    #[test]
+    fn edit_distance_counts_transpositions_once() {
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("nmae", "name"), 1);
+    }
+}
@@ -15,10 +15,11 @@ use std::{borrow::Cow, collections::BTreeSet, path::PathBuf};
 use crate::generate_artifacts::ClientFieldOutputType;
 use crate::{
     generate_artifacts::{
-        generate_client_field_parameter_type, generate_client_field_updatable_data_type,
-        generate_output_type, generate_parameters, ClientFieldFunctionImportStatement,
-        RESOLVER_OUTPUT_TYPE, RESOLVER_OUTPUT_TYPE_FILE_NAME, RESOLVER_PARAMETERS_TYPE_FILE_NAME,
-        RESOLVER_PARAM_TYPE, RESOLVER_PARAM_TYPE_FILE_NAME, RESOLVER_READER_FILE_NAME,
+        format_optional_jsdoc, generate_client_field_parameter_type,
+        generate_client_field_updatable_data_type, generate_output_type, generate_parameters,
+        ClientFieldFunctionImportStatement, RESOLVER_OUTPUT_TYPE, RESOLVER_OUTPUT_TYPE_FILE_NAME,
+        RESOLVER_PARAMETERS_TYPE_FILE_NAME, RESOLVER_PARAM_TYPE, RESOLVER_PARAM_TYPE_FILE_NAME,
+        RESOLVER_READER_FILE_NAME,
     },
     import_statements::{
         param_type_imports_to_import_param_statement, param_type_imports_to_import_statement,
@@ -307,13 +308,19 @@ pub(crate) fn generate_eager_reader_param_type_artifact<TNetworkProtocol: Networ
         "".to_string()
     };
 
+    let param_type_description =
+        format_optional_jsdoc(client_scalar_selectable.description(), None, 0);
+    let data_description = format_optional_jsdoc(parent_type.description, None, 1);
+
     let param_type_content = format!(
         "{param_type_import_statement}\
         {link_field_imports}\
         {start_update_imports}\
         {loadable_field_imports}\
         {parameters_import}\n\
+        {param_type_description}\
         export type {reader_param_type} = {{\n\
+        {data_description}\
         {indent}readonly data: {client_field_parameter_type},\n\
         {indent}readonly parameters: {parameters_type},\n\
         {start_update_type}\
@@ -348,9 +355,12 @@ pub(crate) fn generate_eager_reader_output_type_artifact<TNetworkProtocol: Netwo
         SelectionType::Scalar(client_field) => generate_output_type(client_field),
     };
 
+    let output_type_description = format_optional_jsdoc(client_field.description(), None, 0);
+
     let output_type_text = format!(
         "import type React from 'react';\n\
         {function_import_statement}\n\
+        {output_type_description}\
         export type {}__{}__output_type = {};",
         parent_type.name,
         client_field.name(),
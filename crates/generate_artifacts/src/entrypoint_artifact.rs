@@ -4,7 +4,7 @@ use common_lang_types::{
     ArtifactPathAndContent, IsographObjectTypeName, ObjectTypeAndFieldName, QueryOperationName,
     QueryText, VariableName,
 };
-use isograph_config::GenerateFileExtensionsOption;
+use isograph_config::{GenerateFileExtensionsOption, QueryTextFragmentsOption};
 use isograph_lang_types::{
     ClientScalarSelectableId, DefinitionLocation, ScalarSelectionDirectiveSet, SelectionType,
     ServerObjectEntityId,
@@ -14,8 +14,9 @@ use isograph_schema::{
     current_target_merged_selections, get_imperatively_loaded_artifact_info,
     get_reachable_variables, initial_variable_context, ClientScalarOrObjectSelectable,
     ClientScalarSelectable, FieldToCompletedMergeTraversalStateMap, FieldTraversalResult,
-    MergedSelectionMap, NetworkProtocol, RootOperationName, RootRefetchedPath,
-    ScalarClientFieldTraversalState, Schema, ServerObjectEntity, ValidatedVariableDefinition,
+    MergedSelectionMap, MergedServerSelection, NetworkProtocol, RootOperationName,
+    RootRefetchedPath, ScalarClientFieldTraversalState, Schema, ServerObjectEntity,
+    ValidatedVariableDefinition,
 };
 
 use crate::{
@@ -26,6 +27,7 @@ use crate::{
     },
     imperatively_loaded_fields::get_artifact_for_imperatively_loaded_field,
     normalization_ast_text::generate_normalization_ast_text,
+    persisted_queries::PersistedQueries,
 };
 
 #[derive(Debug)]
@@ -33,9 +35,18 @@ struct EntrypointArtifactInfo<'schema, TNetworkProtocol: NetworkProtocol> {
     query_name: QueryOperationName,
     parent_type: &'schema ServerObjectEntity<TNetworkProtocol>,
     query_text: QueryText,
+    /// The id of the persisted query, if persisted queries are enabled. If so, the
+    /// query text is not included in the generated artifacts.
+    operation_id: Option<String>,
+    /// Query, Mutation or Subscription. The runtime uses this to determine
+    /// which network function to call.
+    operation_kind: String,
     normalization_ast_text: NormalizationAstText,
     refetch_query_artifact_import: RefetchQueryArtifactImport,
     concrete_type: IsographObjectTypeName,
+    /// The client fields selected with @loadable(defer: true), whose selections are
+    /// selected in deferred fragments, labeled with the client field's name.
+    deferred_fragment_labels: BTreeSet<ObjectTypeAndFieldName>,
 }
 
 pub(crate) fn generate_entrypoint_artifacts<TNetworkProtocol: NetworkProtocol>(
@@ -43,6 +54,8 @@ pub(crate) fn generate_entrypoint_artifacts<TNetworkProtocol: NetworkProtocol>(
     entrypoint_id: ClientScalarSelectableId,
     encountered_client_type_map: &mut FieldToCompletedMergeTraversalStateMap,
     file_extensions: GenerateFileExtensionsOption,
+    query_text_fragments: QueryTextFragmentsOption,
+    persisted_queries: &mut PersistedQueries,
 ) -> Vec<ArtifactPathAndContent> {
     let entrypoint = schema.client_field(entrypoint_id);
 
@@ -74,6 +87,8 @@ pub(crate) fn generate_entrypoint_artifacts<TNetworkProtocol: NetworkProtocol>(
             .map(|variable_definition| &variable_definition.item),
         &schema.find_mutation(),
         file_extensions,
+        query_text_fragments,
+        persisted_queries,
     )
 }
 
@@ -90,6 +105,8 @@ pub(crate) fn generate_entrypoint_artifacts_with_client_field_traversal_result<
     variable_definitions: impl Iterator<Item = &'a ValidatedVariableDefinition> + 'a,
     default_root_operation: &Option<(&ServerObjectEntityId, &RootOperationName)>,
     file_extensions: GenerateFileExtensionsOption,
+    query_text_fragments: QueryTextFragmentsOption,
+    persisted_queries: &mut PersistedQueries,
 ) -> Vec<ArtifactPathAndContent> {
     let query_name = entrypoint.name.into();
     // TODO when we do not call generate_entrypoint_artifact extraneously,
@@ -121,7 +138,10 @@ pub(crate) fn generate_entrypoint_artifacts_with_client_field_traversal_result<
         merged_selection_map,
         variable_definitions,
         root_operation_name,
+        query_text_fragments,
     );
+    let operation_id = persisted_queries.persist(&query_text);
+    let operation_kind = operation_kind(root_operation_name);
     let refetch_paths_with_variables = traversal_state
         .refetch_paths
         .iter()
@@ -131,7 +151,8 @@ pub(crate) fn generate_entrypoint_artifacts_with_client_field_traversal_result<
                 | ScalarSelectionDirectiveSet::None(_) => {
                     current_target_merged_selections(&path.linked_fields, merged_selection_map)
                 }
-                ScalarSelectionDirectiveSet::Loadable(_) => {
+                ScalarSelectionDirectiveSet::Loadable(_)
+                | ScalarSelectionDirectiveSet::Connection(_) => {
                     // Note: it would be cleaner to include a reference to the merged selection set here via
                     // the selection_variant variable, instead of by looking it up like this.
                     &encountered_client_type_map
@@ -160,6 +181,8 @@ pub(crate) fn generate_entrypoint_artifacts_with_client_field_traversal_result<
 
     let normalization_ast_text =
         generate_normalization_ast_text(schema, merged_selection_map.values(), 1);
+    let mut deferred_fragment_labels = BTreeSet::new();
+    collect_deferred_fragment_labels(merged_selection_map, &mut deferred_fragment_labels);
 
     let concrete_type = schema.server_entity_data.server_object_entity(
         if schema
@@ -183,11 +206,14 @@ pub(crate) fn generate_entrypoint_artifacts_with_client_field_traversal_result<
 
     let mut paths_and_contents = EntrypointArtifactInfo {
         query_text,
+        operation_id,
+        operation_kind,
         query_name,
         parent_type: parent_object,
         normalization_ast_text,
         refetch_query_artifact_import,
         concrete_type: concrete_type.name,
+        deferred_fragment_labels,
     }
     .path_and_content(file_extensions);
 
@@ -207,12 +233,57 @@ pub(crate) fn generate_entrypoint_artifacts_with_client_field_traversal_result<
             schema,
             artifact_info,
             file_extensions,
+            query_text_fragments,
+            persisted_queries,
         ))
     }
 
     paths_and_contents
 }
 
+fn collect_deferred_fragment_labels(
+    selection_map: &MergedSelectionMap,
+    labels: &mut BTreeSet<ObjectTypeAndFieldName>,
+) {
+    for selection in selection_map.values() {
+        match selection {
+            MergedServerSelection::ScalarField(_) => {}
+            MergedServerSelection::LinkedField(linked_field) => {
+                collect_deferred_fragment_labels(&linked_field.selection_map, labels)
+            }
+            MergedServerSelection::InlineFragment(inline_fragment) => {
+                collect_deferred_fragment_labels(&inline_fragment.selection_map, labels)
+            }
+            MergedServerSelection::DeferredFragment(deferred_fragment) => {
+                labels.insert(deferred_fragment.label);
+                collect_deferred_fragment_labels(&deferred_fragment.selection_map, labels)
+            }
+        }
+    }
+}
+
+/// Maps the label of each deferred fragment to a loader for the reader of the client
+/// field selected with @loadable(defer: true). The reader is only imported once the
+/// deferred payload arrives, so that it is not part of the entrypoint's bundle.
+fn generate_deferred_fragment_readers(
+    deferred_fragment_labels: &BTreeSet<ObjectTypeAndFieldName>,
+    current_type_name: IsographObjectTypeName,
+) -> String {
+    if deferred_fragment_labels.is_empty() {
+        return String::new();
+    }
+    let mut readers = "  deferredFragmentReaders: {\n".to_string();
+    for label in deferred_fragment_labels {
+        readers.push_str(&format!(
+            "    \"{}\": () => import(\"{}\").then(module => module.default),\n",
+            label.underscore_separated(),
+            label.relative_path(current_type_name, *RESOLVER_READER),
+        ));
+    }
+    readers.push_str("  },\n");
+    readers
+}
+
 fn generate_refetch_query_artifact_import(
     root_refetched_paths: &[(
         RootRefetchedPath,
@@ -271,21 +342,25 @@ impl<TNetworkProtocol: NetworkProtocol> EntrypointArtifactInfo<'_, TNetworkProto
             query_name,
             parent_type,
             query_text,
+            operation_id,
             normalization_ast_text,
             ..
         } = &self;
         let field_name = (*query_name).into();
         let type_name = parent_type.name;
 
-        vec![
-            ArtifactPathAndContent {
+        let mut paths_and_contents = vec![];
+        if operation_id.is_none() {
+            paths_and_contents.push(ArtifactPathAndContent {
                 file_content: format!("export default '{}';", query_text),
                 file_name: *QUERY_TEXT_FILE_NAME,
                 type_and_field: Some(ObjectTypeAndFieldName {
                     type_name,
                     field_name,
                 }),
-            },
+            });
+        }
+        paths_and_contents.extend([
             ArtifactPathAndContent {
                 file_content: format!(
                     "import type {{NormalizationAst}} from '@isograph/react';\n\
@@ -310,7 +385,8 @@ impl<TNetworkProtocol: NetworkProtocol> EntrypointArtifactInfo<'_, TNetworkProto
                     field_name,
                 }),
             },
-        ]
+        ]);
+        paths_and_contents
     }
 
     fn file_contents(self, file_extensions: GenerateFileExtensionsOption) -> String {
@@ -319,6 +395,9 @@ impl<TNetworkProtocol: NetworkProtocol> EntrypointArtifactInfo<'_, TNetworkProto
             query_name,
             parent_type,
             concrete_type,
+            operation_id,
+            operation_kind,
+            deferred_fragment_labels,
             ..
         } = self;
         let ts_file_extension = file_extensions.ts();
@@ -331,13 +410,33 @@ impl<TNetworkProtocol: NetworkProtocol> EntrypointArtifactInfo<'_, TNetworkProto
         let output_type_file_name = *RESOLVER_OUTPUT_TYPE;
         let query_text_file_name = *QUERY_TEXT;
         let normalization_text_file_name = *NORMALIZATION_AST;
+        let deferred_fragment_readers =
+            generate_deferred_fragment_readers(&deferred_fragment_labels, parent_type.name);
+        let (query_text_import, network_request_info) = match operation_id {
+            Some(operation_id) => (
+                String::new(),
+                format!(
+                    "{}  kind: \"PersistedNetworkRequestInfo\",\n\
+                    {}  operationId: \"{operation_id}\",\n",
+                    "  ", "  "
+                ),
+            ),
+            None => (
+                format!("import queryText from './{query_text_file_name}{ts_file_extension}';\n"),
+                format!(
+                    "{}  kind: \"NetworkRequestInfo\",\n\
+                    {}  queryText,\n",
+                    "  ", "  "
+                ),
+            ),
+        };
         format!(
             "import type {{IsographEntrypoint, \
             NormalizationAst, RefetchQueryNormalizationArtifactWrapper}} from '@isograph/react';\n\
             import {{{entrypoint_params_typename}}} from './{param_type_file_name}{ts_file_extension}';\n\
             import {{{entrypoint_output_type_name}}} from './{output_type_file_name}{ts_file_extension}';\n\
             import readerResolver from './{resolver_reader_file_name}{ts_file_extension}';\n\
-            import queryText from './{query_text_file_name}{ts_file_extension}';\n\
+            {query_text_import}\
             import normalizationAst from './{normalization_text_file_name}{ts_file_extension}';\n\
             {refetch_query_artifact_import}\n\n\
             const artifact: IsographEntrypoint<\n\
@@ -346,9 +445,9 @@ impl<TNetworkProtocol: NetworkProtocol> EntrypointArtifactInfo<'_, TNetworkProto
             {}NormalizationAst\n\
             > = {{\n\
             {}kind: \"Entrypoint\",\n\
+            {}operationKind: \"{operation_kind}\",\n\
             {}networkRequestInfo: {{\n\
-            {}  kind: \"NetworkRequestInfo\",\n\
-            {}  queryText,\n\
+            {network_request_info}\
             {}  normalizationAst,\n\
             {}}},\n\
             {}concreteType: \"{concrete_type}\",\n\
@@ -357,13 +456,23 @@ impl<TNetworkProtocol: NetworkProtocol> EntrypointArtifactInfo<'_, TNetworkProto
             {}  nestedRefetchQueries,\n\
             {}  readerArtifact: readerResolver,\n\
             {}}},\n\
+            {deferred_fragment_readers}\
             }};\n\n\
             export default artifact;\n",
-            "  ", "  ", "  ","  ", "  ", "  ", "  ", "  ", "  ", "  ", "  ", "  ", "  ", "  ", "  ",
+            "  ", "  ", "  ","  ", "  ", "  ", "  ", "  ", "  ", "  ", "  ", "  ", "  ", "  ",
         )
     }
 }
 
+/// The root operation name (e.g. subscription), capitalized (e.g. Subscription).
+fn operation_kind(root_operation_name: &RootOperationName) -> String {
+    let mut chars = root_operation_name.0.chars();
+    match chars.next() {
+        Some(first_char) => first_char.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
 fn variable_names_to_string(
     variable_names: &BTreeSet<VariableName>,
     field_variables: impl Iterator<Item = VariableName>,
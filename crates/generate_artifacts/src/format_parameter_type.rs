@@ -79,7 +79,7 @@ fn format_server_field_type<TNetworkProtocol: NetworkProtocol>(
         ServerEntityId::Scalar(scalar_entity_id) => schema
             .server_entity_data
             .server_scalar_entity(scalar_entity_id)
-            .javascript_name
+            .javascript_type()
             .to_string(),
     }
 }
@@ -17,11 +17,12 @@ use isograph_lang_types::{
     UnionVariant, VariableDefinition,
 };
 use isograph_schema::{
-    accessible_client_fields, description, inline_fragment_reader_selection_set,
-    output_type_annotation, selection_map_wrapped, ClientFieldVariant, ClientScalarSelectable,
-    ClientSelectableId, FieldTraversalResult, NameAndArguments, NetworkProtocol, NormalizationKey,
-    ScalarSelectableId, Schema, SchemaServerObjectSelectableVariant, UserWrittenClientTypeInfo,
-    ValidatedSelection, ValidatedVariableDefinition, WrappedSelectionMapSelection,
+    accessible_client_fields, deprecation_reason, description,
+    inline_fragment_reader_selection_set, output_type_annotation, selection_map_wrapped,
+    ClientFieldVariant, ClientScalarSelectable, ClientSelectableId, FieldTraversalResult,
+    NetworkProtocol, RefetchStrategy, ScalarSelectableId, Schema,
+    SchemaServerObjectSelectableVariant, UserWrittenClientTypeInfo, ValidatedSelection,
+    ValidatedVariableDefinition, WrappedSelectionMapSelection,
 };
 use lazy_static::lazy_static;
 use std::{
@@ -41,6 +42,7 @@ use crate::{
     format_parameter_type::format_parameter_type,
     import_statements::{LinkImports, ParamTypeImports, UpdatableImports},
     iso_overload_file::build_iso_overload_artifact,
+    persisted_queries::PersistedQueries,
     refetch_reader_artifact::{
         generate_refetch_output_type_artifact, generate_refetch_reader_artifact,
     },
@@ -99,19 +101,24 @@ pub fn get_artifact_path_and_content<TNetworkProtocol: NetworkProtocol>(
     schema: &Schema<TNetworkProtocol>,
     config: &CompilerConfig,
 ) -> Vec<ArtifactPathAndContent> {
-    let mut artifact_path_and_content = get_artifact_path_and_content_impl(schema, config);
+    let mut persisted_queries = PersistedQueries::new(config);
+    let mut artifact_path_and_content =
+        get_artifact_path_and_content_impl(schema, config, &mut persisted_queries);
     if let Some(header) = config.options.generated_file_header {
         for artifact_path_and_content in artifact_path_and_content.iter_mut() {
             artifact_path_and_content.file_content =
                 format!("// {header}\n{}", artifact_path_and_content.file_content);
         }
     }
+    // The persisted queries manifest is JSON, and thus cannot contain the header comment.
+    artifact_path_and_content.extend(persisted_queries.manifest_artifact());
     artifact_path_and_content
 }
 
 fn get_artifact_path_and_content_impl<TNetworkProtocol: NetworkProtocol>(
     schema: &Schema<TNetworkProtocol>,
     config: &CompilerConfig,
+    persisted_queries: &mut PersistedQueries,
 ) -> Vec<ArtifactPathAndContent> {
     let mut encountered_client_type_map = BTreeMap::new();
     let mut path_and_contents = vec![];
@@ -124,6 +131,8 @@ fn get_artifact_path_and_content_impl<TNetworkProtocol: NetworkProtocol>(
             *entrypoint_id,
             &mut encountered_client_type_map,
             config.options.include_file_extensions_in_import_statements,
+            config.options.query_text_fragments,
+            persisted_queries,
         );
         path_and_contents.extend(entrypoint_path_and_content);
 
@@ -203,12 +212,6 @@ fn get_artifact_path_and_content_impl<TNetworkProtocol: NetworkProtocol>(
                                 config.options.include_file_extensions_in_import_statements,
                             ));
 
-                            // Everything about this is quite sus
-                            let id_arg = ArgumentKeyAndValue {
-                                key: "id".intern().into(),
-                                value: NonConstantValue::Variable("id".intern().into()),
-                            };
-
                             let type_to_refine_to = schema.server_entity_data.server_object_entity(
                                 client_scalar_selectable.parent_object_entity_id,
                             );
@@ -220,54 +223,69 @@ fn get_artifact_path_and_content_impl<TNetworkProtocol: NetworkProtocol>(
                                 panic!("Loadable fields on root objects are not yet supported");
                             }
 
-                            let wrapped_map = selection_map_wrapped(
-                                merged_selection_map.clone(),
-                                vec![
-                                    WrappedSelectionMapSelection::InlineFragment(
-                                        type_to_refine_to.name,
-                                    ),
-                                    WrappedSelectionMapSelection::LinkedField {
-                                        server_object_selectable_name: "node".intern().into(),
-                                        arguments: vec![id_arg.clone()],
-                                        concrete_type: None,
-                                    },
-                                ],
-                            );
-                            let id_var = ValidatedVariableDefinition {
-                                name: WithLocation::new("id".intern().into(), Location::Generated),
-                                type_: GraphQLTypeAnnotation::NonNull(Box::new(
-                                    GraphQLNonNullTypeAnnotation::Named(
-                                        GraphQLNamedTypeAnnotation(WithSpan::new(
-                                            ServerEntityId::Scalar(
-                                                schema.server_entity_data.id_type_id,
+                            // The selections that wrap the merged selection map, innermost first,
+                            // and the variables that they use.
+                            let (wrapping_selections, id_var) = match client_scalar_selectable
+                                .refetch_strategy
+                                .as_ref()
+                                .expect(
+                                    "Expected refetch strategy. \
+                                    This is indicative of a bug in Isograph.",
+                                ) {
+                                RefetchStrategy::UseRefetchField(_) => {
+                                    // Everything about this is quite sus
+                                    let id_var = ValidatedVariableDefinition {
+                                        name: WithLocation::new(
+                                            "id".intern().into(),
+                                            Location::Generated,
+                                        ),
+                                        type_: GraphQLTypeAnnotation::NonNull(Box::new(
+                                            GraphQLNonNullTypeAnnotation::Named(
+                                                GraphQLNamedTypeAnnotation(WithSpan::new(
+                                                    ServerEntityId::Scalar(
+                                                        schema.server_entity_data.id_type_id,
+                                                    ),
+                                                    Span::todo_generated(),
+                                                )),
                                             ),
-                                            Span::todo_generated(),
                                         )),
-                                    ),
-                                )),
-                                default_value: None,
+                                        default_value: None,
+                                    };
+                                    (
+                                        schema.refetch_field_subfields(type_to_refine_to.name),
+                                        Some(id_var),
+                                    )
+                                }
+                                RefetchStrategy::RefetchFromRoot(refetch_from_root_strategy) => {
+                                    let mut wrapping_selections =
+                                        vec![WrappedSelectionMapSelection::InlineFragment(
+                                            type_to_refine_to.name,
+                                        )];
+                                    wrapping_selections
+                                        .extend(refetch_from_root_strategy.path.iter().cloned());
+                                    (wrapping_selections, None)
+                                }
                             };
+
+                            let wrapped_map = selection_map_wrapped(
+                                merged_selection_map.clone(),
+                                wrapping_selections.clone(),
+                            );
                             let variable_definitions_iter = client_scalar_selectable
                                 .variable_definitions
                                 .iter()
                                 .map(|variable_definition| &variable_definition.item)
-                                .chain(std::iter::once(&id_var));
+                                .chain(id_var.iter());
                             let mut traversal_state = traversal_state.clone();
                             traversal_state.refetch_paths = traversal_state
                                 .refetch_paths
                                 .into_iter()
                                 .map(|(mut key, value)| {
-                                    key.0.linked_fields.insert(
-                                        0,
-                                        NormalizationKey::InlineFragment(type_to_refine_to.name),
-                                    );
-                                    key.0.linked_fields.insert(
-                                        0,
-                                        NormalizationKey::ServerField(NameAndArguments {
-                                            name: "node".intern().into(),
-                                            arguments: vec![id_arg.clone()],
-                                        }),
-                                    );
+                                    for wrapping_selection in wrapping_selections.iter() {
+                                        key.0
+                                            .linked_fields
+                                            .insert(0, wrapping_selection.normalization_key());
+                                    }
                                     (key, value)
                                 })
                                 .collect();
@@ -282,6 +300,8 @@ fn get_artifact_path_and_content_impl<TNetworkProtocol: NetworkProtocol>(
                                     variable_definitions_iter,
                                     &schema.find_query(),
                                     config.options.include_file_extensions_in_import_statements,
+                                    config.options.query_text_fragments,
+                                    persisted_queries,
                                 ),
                             );
                         }
@@ -609,6 +629,7 @@ fn write_param_type_from_selection<TNetworkProtocol: NetworkProtocol>(
 
                     write_optional_description(
                         field.description,
+                        field.deprecation_reason,
                         query_type_declaration,
                         indentation_level,
                     );
@@ -623,7 +644,7 @@ fn write_param_type_from_selection<TNetworkProtocol: NetworkProtocol>(
                                 schema
                                     .server_entity_data
                                     .server_scalar_entity(scalar_entity_id)
-                                    .javascript_name
+                                    .javascript_type()
                             });
 
                     query_type_declaration.push_str(&format!(
@@ -659,6 +680,7 @@ fn write_param_type_from_selection<TNetworkProtocol: NetworkProtocol>(
 
             write_optional_description(
                 description(&field),
+                deprecation_reason(&field),
                 query_type_declaration,
                 indentation_level,
             );
@@ -699,6 +721,7 @@ fn write_param_type_from_client_field<TNetworkProtocol: NetworkProtocol>(
     let client_field = schema.client_field(client_field_id);
     write_optional_description(
         client_field.description,
+        None,
         query_type_declaration,
         indentation_level,
     );
@@ -724,7 +747,8 @@ fn write_param_type_from_client_field<TNetworkProtocol: NetworkProtocol>(
             let output_type = match scalar_field_selection.scalar_selection_directive_set {
                 ScalarSelectionDirectiveSet::Updatable(_)
                 | ScalarSelectionDirectiveSet::None(_) => inner_output_type,
-                ScalarSelectionDirectiveSet::Loadable(_) => {
+                ScalarSelectionDirectiveSet::Loadable(_)
+                | ScalarSelectionDirectiveSet::Connection(_) => {
                     loadable_fields.insert(client_field.type_and_field);
                     let provided_arguments = get_provided_arguments(
                         client_field.variable_definitions.iter().map(|x| &x.item),
@@ -783,6 +807,7 @@ fn write_updatable_data_type_from_selection<TNetworkProtocol: NetworkProtocol>(
 
                     write_optional_description(
                         field.description,
+                        field.deprecation_reason,
                         query_type_declaration,
                         indentation_level,
                     );
@@ -797,7 +822,7 @@ fn write_updatable_data_type_from_selection<TNetworkProtocol: NetworkProtocol>(
                                 schema
                                     .server_entity_data
                                     .server_scalar_entity(scalar_entity_id)
-                                    .javascript_name
+                                    .javascript_type()
                             });
 
                     match scalar_field_selection.scalar_selection_directive_set {
@@ -811,8 +836,9 @@ fn write_updatable_data_type_from_selection<TNetworkProtocol: NetworkProtocol>(
                                 print_javascript_type_declaration(&output_type)
                             ));
                         }
-                        ScalarSelectionDirectiveSet::Loadable(_) => {
-                            panic!("@loadable server fields are not supported")
+                        ScalarSelectionDirectiveSet::Loadable(_)
+                        | ScalarSelectionDirectiveSet::Connection(_) => {
+                            panic!("@loadable and @connection server fields are not supported")
                         }
                         ScalarSelectionDirectiveSet::None(_) => {
                             query_type_declaration.push_str(&format!(
@@ -843,6 +869,7 @@ fn write_updatable_data_type_from_selection<TNetworkProtocol: NetworkProtocol>(
 
             write_optional_description(
                 description(&field),
+                deprecation_reason(&field),
                 query_type_declaration,
                 indentation_level,
             );
@@ -942,21 +969,17 @@ fn format_type_for_js<TNetworkProtocol: NetworkProtocol>(
                     This is indicative of an unimplemented feature in Isograph."
                 )
             }
-            ServerEntityId::Scalar(scalar_entity_id) => {
-                schema
-                    .server_entity_data
-                    .server_scalar_entity(scalar_entity_id)
-                    .javascript_name
-            }
+            ServerEntityId::Scalar(scalar_entity_id) => schema
+                .server_entity_data
+                .server_scalar_entity(scalar_entity_id)
+                .javascript_type(),
         },
     );
 
     format_type_for_js_inner(new_type)
 }
 
-fn format_type_for_js_inner(
-    new_type: GraphQLTypeAnnotation<common_lang_types::JavascriptName>,
-) -> String {
+fn format_type_for_js_inner<T: Display>(new_type: GraphQLTypeAnnotation<T>) -> String {
     match new_type {
         GraphQLTypeAnnotation::Named(named_inner_type) => {
             format!("{} | null | void", named_inner_type.0.item)
@@ -996,17 +1019,52 @@ pub(crate) fn generate_parameters<'a, TNetworkProtocol: NetworkProtocol>(
 
 fn write_optional_description(
     description: Option<DescriptionValue>,
+    deprecation_reason: Option<DescriptionValue>,
     query_type_declaration: &mut String,
     indentation_level: u8,
 ) {
+    query_type_declaration.push_str(&format_optional_jsdoc(
+        description,
+        deprecation_reason,
+        indentation_level,
+    ));
+}
+
+/// Format a description and deprecation reason as a JSDoc comment, so that they
+/// are shown when hovering over the generated property in an editor. Returns an
+/// empty string if there is nothing to document.
+pub(crate) fn format_optional_jsdoc(
+    description: Option<DescriptionValue>,
+    deprecation_reason: Option<DescriptionValue>,
+    indentation_level: u8,
+) -> String {
+    let mut lines = vec![];
     if let Some(description) = description {
-        query_type_declaration.push_str(&"  ".repeat(indentation_level as usize).to_string());
-        query_type_declaration.push_str("/**\n");
-        query_type_declaration.push_str(description.lookup());
-        query_type_declaration.push('\n');
-        query_type_declaration.push_str(&"  ".repeat(indentation_level as usize).to_string());
-        query_type_declaration.push_str("*/\n");
+        lines.extend(description.lookup().trim().lines().map(str::to_string));
+    }
+    if let Some(deprecation_reason) = deprecation_reason {
+        lines.push(format!(
+            "@deprecated {}",
+            deprecation_reason.lookup().trim()
+        ));
+    }
+    if lines.is_empty() {
+        return "".to_string();
+    }
+
+    let indent = "  ".repeat(indentation_level as usize);
+    let mut jsdoc = format!("{indent}/**\n");
+    for line in lines {
+        // A "*/" within the description would end the comment early
+        let line = line.trim_end().replace("*/", "*\\/");
+        if line.is_empty() {
+            jsdoc.push_str(&format!("{indent} *\n"));
+        } else {
+            jsdoc.push_str(&format!("{indent} * {line}\n"));
+        }
     }
+    jsdoc.push_str(&format!("{indent} */\n"));
+    jsdoc
 }
 
 fn print_javascript_type_declaration<T: Display + Ord + Debug>(
@@ -1122,3 +1180,24 @@ pub fn get_provided_arguments<'a>(
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use intern::string_key::Intern;
+
+    use super::format_optional_jsdoc;
+
+    #[test]
+    fn formats_multiline_description_and_deprecation_as_jsdoc() {
+        let jsdoc = format_optional_jsdoc(
+            Some("The pet's name.\n\nNot unique */ at all.".intern().into()),
+            Some("Use nickname".intern().into()),
+            1,
+        );
+        assert_eq!(
+            jsdoc,
+            "  /**\n   * The pet's name.\n   *\n   * Not unique *\\/ at all.\n   * @deprecated Use nickname\n   */\n"
+        );
+        assert_eq!(format_optional_jsdoc(None, None, 1), "");
+    }
+}
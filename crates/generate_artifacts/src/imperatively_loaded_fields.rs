@@ -3,7 +3,7 @@ use common_lang_types::{
     ObjectTypeAndFieldName, QueryText,
 };
 use intern::string_key::Intern;
-use isograph_config::GenerateFileExtensionsOption;
+use isograph_config::{GenerateFileExtensionsOption, QueryTextFragmentsOption};
 use isograph_lang_types::RefetchQueryIndex;
 use isograph_schema::{
     ImperativelyLoadedFieldArtifactInfo, NetworkProtocol, Schema, REFETCH_FIELD_NAME,
@@ -12,12 +12,16 @@ use isograph_schema::{
 use crate::{
     generate_artifacts::{NormalizationAstText, QUERY_TEXT},
     normalization_ast_text::generate_normalization_ast_text,
+    persisted_queries::PersistedQueries,
 };
 
 #[derive(Debug)]
 pub(crate) struct ImperativelyLoadedEntrypointArtifactInfo {
     pub normalization_ast_text: NormalizationAstText,
     pub query_text: QueryText,
+    /// The id of the persisted query, if persisted queries are enabled. If so, the
+    /// query text is not included in the generated artifacts.
+    pub operation_id: Option<String>,
     pub root_fetchable_field: ClientScalarSelectableName,
     pub root_fetchable_field_parent_object: IsographObjectTypeName,
     pub refetch_query_index: RefetchQueryIndex,
@@ -34,6 +38,7 @@ impl ImperativelyLoadedEntrypointArtifactInfo {
             root_fetchable_field_parent_object,
             refetch_query_index,
             query_text,
+            operation_id,
             ..
         } = &self;
 
@@ -51,24 +56,26 @@ impl ImperativelyLoadedEntrypointArtifactInfo {
         let type_name = *root_fetchable_field_parent_object;
         let field_name = *root_fetchable_field;
 
-        vec![
-            ArtifactPathAndContent {
+        let mut paths_and_contents = vec![];
+        if operation_id.is_none() {
+            paths_and_contents.push(ArtifactPathAndContent {
                 file_content: format!("export default '{}';", query_text),
                 file_name: query_text_file_name,
                 type_and_field: Some(ObjectTypeAndFieldName {
                     type_name,
                     field_name: field_name.into(),
                 }),
-            },
-            ArtifactPathAndContent {
-                file_content: self.file_contents(file_extensions),
-                file_name: file_name_prefix,
-                type_and_field: Some(ObjectTypeAndFieldName {
-                    type_name,
-                    field_name: field_name.into(),
-                }),
-            },
-        ]
+            });
+        }
+        paths_and_contents.push(ArtifactPathAndContent {
+            file_content: self.file_contents(file_extensions),
+            file_name: file_name_prefix,
+            type_and_field: Some(ObjectTypeAndFieldName {
+                type_name,
+                field_name: field_name.into(),
+            }),
+        });
+        paths_and_contents
     }
 }
 
@@ -78,6 +85,7 @@ impl ImperativelyLoadedEntrypointArtifactInfo {
             normalization_ast_text: normalization_ast,
             concrete_type,
             refetch_query_index,
+            operation_id,
             ..
         } = self;
         let ts_file_extension = file_extensions.ts();
@@ -85,10 +93,28 @@ impl ImperativelyLoadedEntrypointArtifactInfo {
             "{}__{}__{}",
             *REFETCH_FIELD_NAME, *QUERY_TEXT, refetch_query_index.0,
         );
+        let (query_text_import, network_request_info) = match operation_id {
+            Some(operation_id) => (
+                String::new(),
+                format!(
+                    "{}  kind: \"PersistedNetworkRequestInfo\",\n\
+                    {}  operationId: \"{operation_id}\",\n",
+                    "  ", "  "
+                ),
+            ),
+            None => (
+                format!("import queryText from './{query_text_file_name}{ts_file_extension}';\n"),
+                format!(
+                    "{}  kind: \"NetworkRequestInfo\",\n\
+                    {}  queryText,\n",
+                    "  ", "  "
+                ),
+            ),
+        };
 
         format!(
             "import type {{ IsographEntrypoint, ReaderAst, FragmentReference, NormalizationAst, RefetchQueryNormalizationArtifact }} from '@isograph/react';\n\
-            import queryText from './{query_text_file_name}{ts_file_extension}';\n\n\
+            {query_text_import}\n\
             const normalizationAst: NormalizationAst = {{\n\
             {}kind: \"NormalizationAst\",\n\
             {}selections: {normalization_ast},\n\
@@ -96,8 +122,7 @@ impl ImperativelyLoadedEntrypointArtifactInfo {
             const artifact: RefetchQueryNormalizationArtifact = {{\n\
             {}kind: \"RefetchQuery\",\n\
             {}networkRequestInfo: {{\n\
-            {}  kind: \"NetworkRequestInfo\",\n\
-            {}  queryText,\n\
+            {network_request_info}\
             {}  normalizationAst,\n\
             {}}},\n\
             {}concreteType: \"{concrete_type}\",\n\
@@ -110,8 +135,6 @@ impl ImperativelyLoadedEntrypointArtifactInfo {
             "  ",
             "  ",
             "  ",
-            "  ",
-            "  ",
 
         )
     }
@@ -121,6 +144,8 @@ pub(crate) fn get_artifact_for_imperatively_loaded_field<TNetworkProtocol: Netwo
     schema: &Schema<TNetworkProtocol>,
     imperatively_loaded_field_artifact_info: ImperativelyLoadedFieldArtifactInfo,
     file_extensions: GenerateFileExtensionsOption,
+    query_text_fragments: QueryTextFragmentsOption,
+    persisted_queries: &mut PersistedQueries,
 ) -> Vec<ArtifactPathAndContent> {
     let ImperativelyLoadedFieldArtifactInfo {
         merged_selection_set,
@@ -141,7 +166,9 @@ pub(crate) fn get_artifact_for_imperatively_loaded_field<TNetworkProtocol: Netwo
             .iter()
             .map(|variable_definition| &variable_definition.item),
         &root_operation_name,
+        query_text_fragments,
     );
+    let operation_id = persisted_queries.persist(&query_text);
 
     let normalization_ast_text =
         generate_normalization_ast_text(schema, merged_selection_set.values(), 1);
@@ -149,6 +176,7 @@ pub(crate) fn get_artifact_for_imperatively_loaded_field<TNetworkProtocol: Netwo
     ImperativelyLoadedEntrypointArtifactInfo {
         normalization_ast_text,
         query_text,
+        operation_id,
         root_fetchable_field,
         root_fetchable_field_parent_object: root_parent_object,
         refetch_query_index,
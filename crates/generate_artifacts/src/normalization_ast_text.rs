@@ -1,6 +1,6 @@
 use isograph_schema::{
-    MergedInlineFragmentSelection, MergedLinkedFieldSelection, MergedScalarFieldSelection,
-    MergedServerSelection, NetworkProtocol, Schema,
+    MergedDeferredFragmentSelection, MergedInlineFragmentSelection, MergedLinkedFieldSelection,
+    MergedScalarFieldSelection, MergedServerSelection, NetworkProtocol, Schema,
 };
 
 use crate::generate_artifacts::{get_serialized_field_arguments, NormalizationAstText};
@@ -27,8 +27,12 @@ fn generate_normalization_ast_node<TNetworkProtocol: NetworkProtocol>(
     match &item {
         MergedServerSelection::ScalarField(scalar_field) => {
             let MergedScalarFieldSelection {
-                name, arguments, ..
+                name,
+                arguments,
+                id_alias,
             } = scalar_field;
+            // Id fields that are not named id are selected as id, and thus stored as id
+            let name = id_alias.unwrap_or(*name);
             let indent = "  ".repeat(indentation_level as usize);
             let indent_2 = "  ".repeat((indentation_level + 1) as usize);
             let serialized_arguments =
@@ -99,5 +103,28 @@ fn generate_normalization_ast_node<TNetworkProtocol: NetworkProtocol>(
                 {indent}}},\n"
             )
         }
+        MergedServerSelection::DeferredFragment(deferred_fragment) => {
+            let MergedDeferredFragmentSelection {
+                label,
+                selection_map,
+            } = deferred_fragment;
+            let label = label.underscore_separated();
+            let indent = "  ".repeat(indentation_level as usize);
+            let indent_2 = "  ".repeat((indentation_level + 1) as usize);
+
+            let selections = generate_normalization_ast_text(
+                schema,
+                selection_map.values(),
+                indentation_level + 1,
+            );
+
+            format!(
+                "{indent}{{\n\
+                {indent_2}kind: \"Defer\",\n\
+                {indent_2}label: \"{label}\",\n\
+                {indent_2}selections: {selections},\n\
+                {indent}}},\n"
+            )
+        }
     }
 }
@@ -0,0 +1,83 @@
+use std::collections::BTreeMap;
+
+use common_lang_types::{ArtifactFileName, ArtifactPathAndContent, QueryText};
+use intern::string_key::Intern;
+use isograph_config::CompilerConfig;
+use lazy_static::lazy_static;
+use sha2::{Digest, Sha256};
+
+lazy_static! {
+    pub static ref PERSISTED_QUERIES_FILE_NAME: ArtifactFileName =
+        "persisted_queries.json".intern().into();
+}
+
+/// The queries encountered while generating artifacts, if persisted queries are enabled.
+#[derive(Debug)]
+pub(crate) struct PersistedQueries {
+    /// A map from the id of each query to the query text, as it is sent over the network.
+    manifest: Option<BTreeMap<String, String>>,
+}
+
+impl PersistedQueries {
+    pub(crate) fn new(config: &CompilerConfig) -> Self {
+        PersistedQueries {
+            manifest: config.options.persisted_queries.then(BTreeMap::new),
+        }
+    }
+
+    /// If persisted queries are enabled, record the query and return its id, which is
+    /// the sha256 hash of the query text. Otherwise, return None, in which case the
+    /// query text should be included in the generated artifact.
+    pub(crate) fn persist(&mut self, query_text: &QueryText) -> Option<String> {
+        let manifest = self.manifest.as_mut()?;
+
+        // The query text is generated inside of a single-quoted string literal, with
+        // escaped newlines. The string that is sent over the network contains no newlines.
+        let query_text = query_text.0.replace("\\\n", "");
+        let id = format!("{:x}", Sha256::digest(query_text.as_bytes()));
+        manifest.insert(id.clone(), query_text);
+        Some(id)
+    }
+
+    /// An artifact containing the map from the id of each query to its query text,
+    /// which can be uploaded to the server.
+    pub(crate) fn manifest_artifact(self) -> Option<ArtifactPathAndContent> {
+        let manifest = self.manifest?;
+        Some(ArtifactPathAndContent {
+            file_content: format!(
+                "{}\n",
+                serde_json::to_string_pretty(&manifest)
+                    .expect("Expected persisted queries to be serializable")
+            ),
+            file_name: *PERSISTED_QUERIES_FILE_NAME,
+            type_and_field: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use common_lang_types::QueryText;
+
+    use super::PersistedQueries;
+
+    #[test]
+    fn persists_query_text_without_escaped_newlines() {
+        let mut persisted_queries = PersistedQueries {
+            manifest: Some(BTreeMap::new()),
+        };
+        let id = persisted_queries
+            .persist(&QueryText("query A {\\\n  id,\\\n}".to_string()))
+            .unwrap();
+        assert_eq!(
+            id,
+            "92190e5c6ce15526af2a4660827aac31ae1b76e0f63dfdc96392b85d11f4c0ed"
+        );
+        assert_eq!(
+            persisted_queries.manifest.unwrap().get(&id).unwrap(),
+            "query A {  id,}"
+        );
+    }
+}
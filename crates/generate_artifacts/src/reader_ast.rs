@@ -4,7 +4,7 @@ use common_lang_types::{ClientScalarSelectableName, ObjectTypeAndFieldName, With
 use isograph_lang_types::{
     DefinitionLocation, EmptyDirectiveSet, LoadableDirectiveParameters,
     ObjectSelectionDirectiveSet, RefetchQueryIndex, ScalarSelectionDirectiveSet,
-    SelectionTypeContainingSelections,
+    SelectionTypeContainingSelections, ServerScalarSelectableId,
 };
 use isograph_schema::{
     categorize_field_loadability, transform_arguments_with_child_context, ClientFieldVariant,
@@ -33,11 +33,15 @@ fn generate_reader_ast_node<TNetworkProtocol: NetworkProtocol>(
     match &selection.item {
         SelectionTypeContainingSelections::Scalar(scalar_field_selection) => {
             match scalar_field_selection.associated_data {
-                DefinitionLocation::Server(_) => server_defined_scalar_field_ast_node(
-                    scalar_field_selection,
-                    indentation_level,
-                    initial_variable_context,
-                ),
+                DefinitionLocation::Server(server_scalar_selectable_id) => {
+                    server_defined_scalar_field_ast_node(
+                        scalar_field_selection,
+                        schema,
+                        server_scalar_selectable_id,
+                        indentation_level,
+                        initial_variable_context,
+                    )
+                }
                 DefinitionLocation::Client(client_field_id) => {
                     let client_field = schema.client_field(client_field_id);
                     scalar_client_defined_field_ast_node(
@@ -474,16 +478,35 @@ fn loadably_selected_field_ast_node<TNetworkProtocol: NetworkProtocol>(
     )
 }
 
-fn server_defined_scalar_field_ast_node(
+fn server_defined_scalar_field_ast_node<TNetworkProtocol: NetworkProtocol>(
     scalar_field_selection: &ValidatedScalarSelection,
+    schema: &Schema<TNetworkProtocol>,
+    server_scalar_selectable_id: ServerScalarSelectableId,
     indentation_level: u8,
     initial_variable_context: &VariableContext,
 ) -> String {
+    // Id fields that are not named id (see the id_fields config option) are
+    // normalized under id, so that is where we must read them from.
+    let parent_object_entity_id = schema
+        .server_scalar_selectable(server_scalar_selectable_id)
+        .parent_object_entity_id;
+    let is_id_field = schema
+        .server_entity_data
+        .server_object_entity_available_selectables
+        .get(&parent_object_entity_id)
+        .and_then(|(_, id_field, _)| *id_field)
+        .is_some_and(|id_field| {
+            ServerScalarSelectableId::from(id_field) == server_scalar_selectable_id
+        });
     let field_name = scalar_field_selection.name.item;
-    let alias = scalar_field_selection
-        .reader_alias
-        .map(|x| format!("\"{}\"", x.item))
-        .unwrap_or("null".to_string());
+    let (field_name, alias) = match scalar_field_selection.reader_alias {
+        Some(alias) if is_id_field => ("id".to_string(), format!("\"{}\"", alias.item)),
+        None if is_id_field && field_name != "id" => {
+            ("id".to_string(), format!("\"{field_name}\""))
+        }
+        Some(alias) => (field_name.to_string(), format!("\"{}\"", alias.item)),
+        None => (field_name.to_string(), "null".to_string()),
+    };
     let arguments = get_serialized_field_arguments(
         &transform_arguments_with_child_context(
             scalar_field_selection
@@ -679,7 +702,7 @@ fn refetched_paths_with_path<TNetworkProtocol: NetworkProtocol>(
                                     ),
                                 );
 
-                                paths.extend(new_paths.into_iter());
+                                paths.extend(new_paths);
                             }
                         }
                     }
@@ -714,7 +737,7 @@ fn refetched_paths_with_path<TNetworkProtocol: NetworkProtocol>(
                             initial_variable_context,
                         );
 
-                        paths.extend(new_paths.into_iter());
+                        paths.extend(new_paths);
 
                         path.pop();
                     }
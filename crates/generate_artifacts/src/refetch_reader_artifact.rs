@@ -5,7 +5,7 @@ use isograph_config::GenerateFileExtensionsOption;
 use isograph_lang_types::SelectionType;
 use isograph_schema::{
     initial_variable_context, ClientScalarOrObjectSelectable, ClientScalarSelectable, FieldMapItem,
-    NetworkProtocol, PrimaryFieldInfo, RefetchedPathsMap, Schema,
+    NetworkProtocol, PrimaryFieldInfo, RefetchStrategy, RefetchedPathsMap, Schema,
 };
 
 use crate::{
@@ -29,7 +29,17 @@ pub(crate) fn generate_refetch_reader_artifact<TNetworkProtocol: NetworkProtocol
         Some(info) => {
             generate_function_import_statement_for_mutation_reader(&info.primary_field_field_map)
         }
-        None => generate_function_import_statement_for_refetch_reader(),
+        None => generate_function_import_statement_for_refetch_reader(
+            match client_field.refetch_strategy.as_ref() {
+                // Objects that are refetched from the root are found by following
+                // the root path, so no id is passed to the refetch query.
+                Some(RefetchStrategy::RefetchFromRoot(_)) => vec![],
+                _ => vec![FieldMapItem {
+                    from: "id".intern().into(),
+                    to: "id".intern().into(),
+                }],
+            },
+        ),
     };
     let parent_type = schema
         .server_entity_data
@@ -116,11 +126,10 @@ pub(crate) fn generate_refetch_output_type_artifact<TNetworkProtocol: NetworkPro
     }
 }
 
-fn generate_function_import_statement_for_refetch_reader() -> ClientFieldFunctionImportStatement {
-    let include_read_out_data = get_read_out_data(&[FieldMapItem {
-        from: "id".intern().into(),
-        to: "id".intern().into(),
-    }]);
+fn generate_function_import_statement_for_refetch_reader(
+    field_map: Vec<FieldMapItem>,
+) -> ClientFieldFunctionImportStatement {
+    let include_read_out_data = get_read_out_data(&field_map);
     let indent = "  ";
     // TODO we need to generate nested refetch queries, which may either be
     // passed from the original entrypoint or specific to the loadable field.
@@ -216,7 +225,7 @@ fn get_read_out_data(field_map: &[FieldMapItem]) -> String {
         let split_to_arg = item.split_to_arg();
         let mut path_segments = Vec::with_capacity(1 + split_to_arg.to_field_names.len());
         path_segments.push(split_to_arg.to_argument_name);
-        path_segments.extend(split_to_arg.to_field_names.into_iter());
+        path_segments.extend(split_to_arg.to_field_names);
 
         let last_index = path_segments.len() - 1;
         let mut path_so_far = "".to_string();
@@ -4,6 +4,7 @@ use common_lang_types::{
     Location, QueryOperationName, QueryText, RelativePathToSourceFile, WithLocation,
 };
 use graphql_lang_types::{GraphQLTypeSystemDocument, GraphQLTypeSystemExtensionDocument};
+use isograph_config::QueryTextFragmentsOption;
 use isograph_lang_types::SchemaSource;
 use isograph_schema::{
     MergedSelectionMap, NetworkProtocol, ProcessTypeSystemDocumentOutcome, RootOperationName,
@@ -89,6 +90,7 @@ impl NetworkProtocol for GraphQLNetworkProtocol {
         selection_map: &MergedSelectionMap,
         query_variables: impl Iterator<Item = &'a ValidatedVariableDefinition> + 'a,
         root_operation_name: &RootOperationName,
+        query_text_fragments: QueryTextFragmentsOption,
     ) -> QueryText {
         generate_query_text(
             query_name,
@@ -96,6 +98,7 @@ impl NetworkProtocol for GraphQLNetworkProtocol {
             selection_map,
             query_variables,
             root_operation_name,
+            query_text_fragments,
         )
     }
 }
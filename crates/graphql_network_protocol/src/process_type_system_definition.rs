@@ -5,10 +5,11 @@ use common_lang_types::{
     WithLocation, WithSpan,
 };
 use graphql_lang_types::{
-    GraphQLConstantValue, GraphQLDirective, GraphQLFieldDefinition, GraphQLNamedTypeAnnotation,
-    GraphQLNonNullTypeAnnotation, GraphQLScalarTypeDefinition, GraphQLTypeAnnotation,
-    GraphQLTypeSystemDefinition, GraphQLTypeSystemDocument, GraphQLTypeSystemExtension,
-    GraphQLTypeSystemExtensionDocument, GraphQLTypeSystemExtensionOrDefinition, RootOperationKind,
+    GraphQLConstantValue, GraphQLDirective, GraphQLEnumDefinition, GraphQLFieldDefinition,
+    GraphQLNamedTypeAnnotation, GraphQLNonNullTypeAnnotation, GraphQLScalarTypeDefinition,
+    GraphQLTypeAnnotation, GraphQLTypeSystemDefinition, GraphQLTypeSystemDocument,
+    GraphQLTypeSystemExtension, GraphQLTypeSystemExtensionDocument,
+    GraphQLTypeSystemExtensionOrDefinition, RootOperationKind,
 };
 use intern::string_key::Intern;
 use isograph_schema::{
@@ -26,6 +27,7 @@ use crate::{
 lazy_static! {
     static ref QUERY_TYPE: IsographObjectTypeName = "Query".intern().into();
     static ref MUTATION_TYPE: IsographObjectTypeName = "Mutation".intern().into();
+    static ref SUBSCRIPTION_TYPE: IsographObjectTypeName = "Subscription".intern().into();
     static ref ID_FIELD_NAME: ServerScalarSelectableName = "id".intern().into();
     // TODO use schema_data.string_type_id or something
     static ref STRING_TYPE_NAME: UnvalidatedTypeName = "String".intern().into();
@@ -122,15 +124,7 @@ pub fn process_graphql_type_system_document(
                 // but it might choose to allow-list them.
             }
             GraphQLTypeSystemDefinition::EnumDefinition(enum_definition) => {
-                // TODO Do not do this
-                scalars.push((
-                    process_scalar_definition(GraphQLScalarTypeDefinition {
-                        description: enum_definition.description,
-                        name: enum_definition.name.map(|x| x.unchecked_conversion()),
-                        directives: enum_definition.directives,
-                    }),
-                    location,
-                ));
+                scalars.push((process_enum_definition(enum_definition), location));
             }
             GraphQLTypeSystemDefinition::UnionTypeDefinition(union_definition) => {
                 // TODO do something reasonable here, once we add support for type refinements.
@@ -322,8 +316,9 @@ fn process_object_type_definition(
         Some(RootOperationKind::Query)
     } else if object_type_definition.name.item == *MUTATION_TYPE {
         Some(RootOperationKind::Mutation)
+    } else if object_type_definition.name.item == *SUBSCRIPTION_TYPE {
+        Some(RootOperationKind::Subscription)
     } else {
-        // TODO subscription
         None
     };
 
@@ -343,6 +338,27 @@ fn process_scalar_definition(
         description: scalar_type_definition.description,
         name: scalar_type_definition.name,
         javascript_name: *STRING_JAVASCRIPT_TYPE,
+        enum_values: None,
+        output_format: std::marker::PhantomData,
+    }
+}
+
+/// Enums are modeled as scalars with a list of allowed values. Their javascript type
+/// is the union of those values, e.g. `'ADMIN' | 'MEMBER'`.
+fn process_enum_definition(
+    enum_definition: GraphQLEnumDefinition,
+) -> ServerScalarEntity<GraphQLNetworkProtocol> {
+    let enum_values = enum_definition
+        .enum_value_definitions
+        .into_iter()
+        .map(|enum_value_definition| enum_value_definition.item.value.item)
+        .collect::<Vec<_>>();
+
+    ServerScalarEntity {
+        description: enum_definition.description,
+        name: enum_definition.name.map(|x| x.unchecked_conversion()),
+        javascript_name: *STRING_JAVASCRIPT_TYPE,
+        enum_values: Some(enum_values),
         output_format: std::marker::PhantomData,
     }
 }
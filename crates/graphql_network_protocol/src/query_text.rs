@@ -1,5 +1,10 @@
-use common_lang_types::{QueryOperationName, QueryText, UnvalidatedTypeName};
+use std::collections::{BTreeMap, BTreeSet};
+
+use common_lang_types::{
+    IsographObjectTypeName, QueryOperationName, QueryText, UnvalidatedTypeName,
+};
 use graphql_lang_types::GraphQLTypeAnnotation;
+use isograph_config::QueryTextFragmentsOption;
 use isograph_lang_types::{ArgumentKeyAndValue, NonConstantValue};
 use isograph_schema::{
     MergedSelectionMap, MergedServerSelection, RootOperationName, ServerScalarOrObjectEntity,
@@ -14,6 +19,7 @@ pub(crate) fn generate_query_text<'a>(
     selection_map: &MergedSelectionMap,
     query_variables: impl Iterator<Item = &'a ValidatedVariableDefinition> + 'a,
     root_operation_name: &RootOperationName,
+    query_text_fragments: QueryTextFragmentsOption,
 ) -> QueryText {
     let mut query_text = String::new();
 
@@ -23,11 +29,228 @@ pub(crate) fn generate_query_text<'a>(
         "{} {} {} {{\\\n",
         root_operation_name.0, query_name, variable_text
     ));
-    write_selections_for_query_text(&mut query_text, selection_map.values(), 1);
-    query_text.push('}');
+    write_selections_and_fragment_definitions(
+        &mut query_text,
+        query_name,
+        selection_map,
+        query_text_fragments,
+    );
     QueryText(query_text)
 }
 
+/// Write the selections of the operation, followed by the closing brace of the
+/// operation and the definitions of the fragments that are spread in it.
+fn write_selections_and_fragment_definitions(
+    query_text: &mut String,
+    query_name: QueryOperationName,
+    selection_map: &MergedSelectionMap,
+    query_text_fragments: QueryTextFragmentsOption,
+) {
+    let fragments = match query_text_fragments {
+        QueryTextFragmentsOption::ExtractRepeatedSelectionSets => {
+            QueryTextFragments::extract_repeated_selection_sets(query_name, selection_map)
+        }
+        QueryTextFragmentsOption::InlineAllSelectionSets => QueryTextFragments::default(),
+    };
+
+    write_selections_for_query_text(query_text, selection_map.values(), 1, &fragments);
+    query_text.push('}');
+    fragments.write_fragment_definitions(query_text);
+}
+
+/// A selection set, identified by the type on which it is selected and its text
+/// when all of its selection sets are inlined.
+type SelectionSetKey = (IsographObjectTypeName, String);
+
+/// The selection sets that are extracted into named fragments, and spread wherever
+/// they are selected.
+///
+/// Only the selection sets of inline fragments and of linked fields whose type is
+/// concrete are candidates, since the type condition of the fragment must be known.
+#[derive(Default)]
+struct QueryTextFragments<'a> {
+    /// The extracted selection sets, in the order in which they are first selected,
+    /// along with one of the selection maps from which they are generated.
+    fragments: Vec<(SelectionSetKey, &'a MergedSelectionMap)>,
+    names: BTreeMap<SelectionSetKey, String>,
+}
+
+impl<'a> QueryTextFragments<'a> {
+    /// Extract every selection set that is selected at least twice, and for which
+    /// extracting it into a fragment makes the query text shorter.
+    ///
+    /// Fragment names are generated from the query name, the type condition and the
+    /// order in which the fragments are first selected (e.g. Query__User__0), so that
+    /// they are deterministic.
+    fn extract_repeated_selection_sets(
+        query_name: QueryOperationName,
+        selection_map: &'a MergedSelectionMap,
+    ) -> Self {
+        let mut candidates = vec![];
+        let mut occurrences = BTreeMap::new();
+        collect_candidate_selection_sets(selection_map, &mut candidates, &mut occurrences);
+
+        let mut extracted = candidates
+            .into_iter()
+            .filter(|(key, _)| occurrences[key] > 1)
+            .collect::<Vec<_>>();
+
+        // Selection sets that are only ever selected within a larger extracted
+        // selection set may be spread only once, and extracting a small selection set
+        // may make the query text longer. So, we remove such fragments until every
+        // remaining fragment is worth extracting.
+        loop {
+            let fragments = QueryTextFragments::new(query_name, extracted.clone());
+            let spread_counts = fragments.spread_counts(selection_map);
+            let previous_len = extracted.len();
+            extracted.retain(|(key, _)| {
+                fragments.shortens_query_text(key, spread_counts.get(key).copied().unwrap_or(0))
+            });
+            if extracted.len() == previous_len {
+                return fragments;
+            }
+        }
+    }
+
+    fn new(
+        query_name: QueryOperationName,
+        fragments: Vec<(SelectionSetKey, &'a MergedSelectionMap)>,
+    ) -> Self {
+        let names = fragments
+            .iter()
+            .enumerate()
+            .map(|(index, (key @ (type_name, _), _))| {
+                (key.clone(), format!("{query_name}__{type_name}__{index}"))
+            })
+            .collect();
+        QueryTextFragments { fragments, names }
+    }
+
+    fn name(
+        &self,
+        type_name: IsographObjectTypeName,
+        selection_map: &MergedSelectionMap,
+    ) -> Option<&str> {
+        if self.names.is_empty() {
+            return None;
+        }
+        self.names
+            .get(&(type_name, inline_selection_set_text(selection_map)))
+            .map(|name| name.as_str())
+    }
+
+    /// How often each fragment is spread in the query text, including in the
+    /// definitions of other fragments.
+    fn spread_counts(
+        &self,
+        selection_map: &MergedSelectionMap,
+    ) -> BTreeMap<SelectionSetKey, usize> {
+        let mut spread_counts = BTreeMap::new();
+        let mut visited = BTreeSet::new();
+        self.count_spreads(selection_map, &mut spread_counts, &mut visited);
+        spread_counts
+    }
+
+    fn count_spreads(
+        &self,
+        selection_map: &MergedSelectionMap,
+        spread_counts: &mut BTreeMap<SelectionSetKey, usize>,
+        visited: &mut BTreeSet<SelectionSetKey>,
+    ) {
+        for (type_name, child_selection_map) in child_selection_sets(selection_map) {
+            let key = match type_name {
+                Some(type_name) => (type_name, inline_selection_set_text(child_selection_map)),
+                None => {
+                    self.count_spreads(child_selection_map, spread_counts, visited);
+                    continue;
+                }
+            };
+            if self.names.contains_key(&key) {
+                *spread_counts.entry(key.clone()).or_default() += 1;
+                if visited.insert(key) {
+                    self.count_spreads(child_selection_map, spread_counts, visited);
+                }
+            } else {
+                self.count_spreads(child_selection_map, spread_counts, visited);
+            }
+        }
+    }
+
+    fn shortens_query_text(&self, key: &SelectionSetKey, spread_count: usize) -> bool {
+        let (type_name, selections) = key;
+        let name = &self.names[key];
+        let inlined_len = spread_count * selections.len();
+        // e.g. "  ...Query__User__0,\\\n" at the indentation of the selection set
+        // and "\\\nfragment Query__User__0 on User {\\\n...}"
+        let spread_len = "  ...".len() + name.len() + ",\\\n".len();
+        let definition_len =
+            format!("\\\nfragment {name} on {type_name} {{\\\n}}").len() + selections.len();
+        spread_count > 1 && inlined_len > spread_count * spread_len + definition_len
+    }
+
+    fn write_fragment_definitions(&self, query_text: &mut String) {
+        for (key @ (type_name, _), selection_map) in self.fragments.iter() {
+            let name = &self.names[key];
+            query_text.push_str(&format!("\\\nfragment {name} on {type_name} {{\\\n"));
+            write_selections_for_query_text(query_text, selection_map.values(), 1, self);
+            query_text.push('}');
+        }
+    }
+}
+
+/// The selection sets that are directly nested in this selection map, along with
+/// the type on which they are selected, if it is known.
+fn child_selection_sets(
+    selection_map: &MergedSelectionMap,
+) -> impl Iterator<Item = (Option<IsographObjectTypeName>, &MergedSelectionMap)> {
+    selection_map
+        .values()
+        .filter_map(|selection| match selection {
+            MergedServerSelection::ScalarField(_) => None,
+            MergedServerSelection::LinkedField(linked_field) => {
+                Some((linked_field.concrete_type, &linked_field.selection_map))
+            }
+            MergedServerSelection::InlineFragment(inline_fragment) => Some((
+                Some(inline_fragment.type_to_refine_to),
+                &inline_fragment.selection_map,
+            )),
+            MergedServerSelection::DeferredFragment(deferred_fragment) => {
+                Some((None, &deferred_fragment.selection_map))
+            }
+        })
+}
+
+/// Collect every selection set that could be extracted into a fragment, in the order
+/// in which they are first selected, and count how often each is selected.
+fn collect_candidate_selection_sets<'a>(
+    selection_map: &'a MergedSelectionMap,
+    candidates: &mut Vec<(SelectionSetKey, &'a MergedSelectionMap)>,
+    occurrences: &mut BTreeMap<SelectionSetKey, usize>,
+) {
+    for (type_name, child_selection_map) in child_selection_sets(selection_map) {
+        if let Some(type_name) = type_name {
+            let key = (type_name, inline_selection_set_text(child_selection_map));
+            let count = occurrences.entry(key.clone()).or_default();
+            if *count == 0 {
+                candidates.push((key, child_selection_map));
+            }
+            *count += 1;
+        }
+        collect_candidate_selection_sets(child_selection_map, candidates, occurrences);
+    }
+}
+
+fn inline_selection_set_text(selection_map: &MergedSelectionMap) -> String {
+    let mut text = String::new();
+    write_selections_for_query_text(
+        &mut text,
+        selection_map.values(),
+        1,
+        &QueryTextFragments::default(),
+    );
+    text
+}
+
 fn write_variables_to_string<'a>(
     schema: &ValidatedGraphqlSchema,
     variables: impl Iterator<Item = &'a ValidatedVariableDefinition> + 'a,
@@ -66,11 +289,11 @@ fn write_variables_to_string<'a>(
     }
 }
 
-#[allow(clippy::only_used_in_recursion)]
 fn write_selections_for_query_text<'a>(
     query_text: &mut String,
     items: impl Iterator<Item = &'a MergedServerSelection> + 'a,
     indentation_level: u8,
+    fragments: &QueryTextFragments,
 ) {
     for item in items {
         match &item {
@@ -92,10 +315,12 @@ fn write_selections_for_query_text<'a>(
                 let name = linked_field.name;
                 let arguments = get_serialized_arguments_for_query_text(&linked_field.arguments);
                 query_text.push_str(&format!("{}{} {{\\\n", name, arguments));
-                write_selections_for_query_text(
+                write_selection_set_or_fragment_spread(
                     query_text,
-                    linked_field.selection_map.values(),
+                    linked_field.concrete_type,
+                    &linked_field.selection_map,
                     indentation_level + 1,
+                    fragments,
                 );
                 query_text.push_str(&format!(
                     "{}}},\\\n",
@@ -108,10 +333,27 @@ fn write_selections_for_query_text<'a>(
                     "... on {} {{\\\n",
                     inline_fragment.type_to_refine_to
                 ));
+                write_selection_set_or_fragment_spread(
+                    query_text,
+                    Some(inline_fragment.type_to_refine_to),
+                    &inline_fragment.selection_map,
+                    indentation_level + 1,
+                    fragments,
+                );
+                query_text.push_str(&"  ".repeat(indentation_level as usize).to_string());
+                query_text.push_str("},\\\n")
+            }
+            MergedServerSelection::DeferredFragment(deferred_fragment) => {
+                query_text.push_str(&"  ".repeat(indentation_level as usize).to_string());
+                query_text.push_str(&format!(
+                    "... @defer(label: \"{}\") {{\\\n",
+                    deferred_fragment.label.underscore_separated()
+                ));
                 write_selections_for_query_text(
                     query_text,
-                    inline_fragment.selection_map.values(),
+                    deferred_fragment.selection_map.values(),
                     indentation_level + 1,
+                    fragments,
                 );
                 query_text.push_str(&"  ".repeat(indentation_level as usize).to_string());
                 query_text.push_str("},\\\n")
@@ -120,6 +362,27 @@ fn write_selections_for_query_text<'a>(
     }
 }
 
+fn write_selection_set_or_fragment_spread(
+    query_text: &mut String,
+    type_name: Option<IsographObjectTypeName>,
+    selection_map: &MergedSelectionMap,
+    indentation_level: u8,
+    fragments: &QueryTextFragments,
+) {
+    match type_name.and_then(|type_name| fragments.name(type_name, selection_map)) {
+        Some(fragment_name) => {
+            query_text.push_str(&"  ".repeat(indentation_level as usize).to_string());
+            query_text.push_str(&format!("...{},\\\n", fragment_name));
+        }
+        None => write_selections_for_query_text(
+            query_text,
+            selection_map.values(),
+            indentation_level,
+            fragments,
+        ),
+    }
+}
+
 fn get_serialized_arguments_for_query_text(arguments: &[ArgumentKeyAndValue]) -> String {
     if arguments.is_empty() {
         "".to_string()
@@ -168,3 +431,164 @@ fn serialize_non_constant_value_for_graphql(value: &NonConstantValue) -> String
         ),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use intern::string_key::Intern;
+    use isograph_config::QueryTextFragmentsOption;
+    use isograph_schema::{
+        MergedLinkedFieldSelection, MergedScalarFieldSelection, MergedSelectionMap,
+        MergedServerSelection, NameAndArguments, NormalizationKey,
+    };
+
+    use super::write_selections_and_fragment_definitions;
+
+    fn scalars(names: &[&str]) -> MergedSelectionMap {
+        names
+            .iter()
+            .map(|name| {
+                (
+                    NormalizationKey::ServerField(NameAndArguments {
+                        name: name.intern().into(),
+                        arguments: vec![],
+                    }),
+                    MergedServerSelection::ScalarField(MergedScalarFieldSelection {
+                        name: name.intern().into(),
+                        arguments: vec![],
+                        id_alias: None,
+                    }),
+                )
+            })
+            .collect()
+    }
+
+    fn user() -> MergedSelectionMap {
+        scalars(&[
+            "avatarUrl",
+            "bio",
+            "company",
+            "email",
+            "id",
+            "login",
+            "name",
+            "websiteUrl",
+        ])
+    }
+
+    fn linked_fields(fields: Vec<(&str, MergedSelectionMap)>) -> MergedSelectionMap {
+        fields
+            .into_iter()
+            .map(|(name, selection_map)| {
+                (
+                    NormalizationKey::ServerField(NameAndArguments {
+                        name: name.intern().into(),
+                        arguments: vec![],
+                    }),
+                    MergedServerSelection::LinkedField(MergedLinkedFieldSelection {
+                        name: name.intern().into(),
+                        selection_map,
+                        arguments: vec![],
+                        concrete_type: Some("User".intern().into()),
+                    }),
+                )
+            })
+            .collect()
+    }
+
+    fn query_text(
+        selection_map: &MergedSelectionMap,
+        query_text_fragments: QueryTextFragmentsOption,
+    ) -> String {
+        let mut query_text = String::new();
+        write_selections_and_fragment_definitions(
+            &mut query_text,
+            "Query".intern().into(),
+            selection_map,
+            query_text_fragments,
+        );
+        query_text.replace("\\\n", "\n")
+    }
+
+    #[test]
+    fn inlines_all_selection_sets_by_default() {
+        let user = user();
+        let selection_map =
+            linked_fields(vec![("author", user.clone()), ("reviewer", user.clone())]);
+
+        assert_eq!(
+            query_text(
+                &selection_map,
+                QueryTextFragmentsOption::InlineAllSelectionSets
+            ),
+            "  author {\n    avatarUrl,\n    bio,\n    company,\n    email,\n    id,\n    \
+            login,\n    name,\n    websiteUrl,\n  },\n  \
+            reviewer {\n    avatarUrl,\n    bio,\n    company,\n    email,\n    id,\n    \
+            login,\n    name,\n    websiteUrl,\n  },\n}"
+        );
+    }
+
+    #[test]
+    fn extracts_repeated_selection_sets_into_fragments() {
+        let user = user();
+        let selection_map = linked_fields(vec![
+            ("author", user.clone()),
+            ("reviewer", user.clone()),
+            ("viewer", scalars(&["id"])),
+        ]);
+
+        assert_eq!(
+            query_text(
+                &selection_map,
+                QueryTextFragmentsOption::ExtractRepeatedSelectionSets
+            ),
+            "  author {\n    ...Query__User__0,\n  },\n  \
+            reviewer {\n    ...Query__User__0,\n  },\n  \
+            viewer {\n    id,\n  },\n}\n\
+            fragment Query__User__0 on User {\n  avatarUrl,\n  bio,\n  company,\n  \
+            email,\n  id,\n  login,\n  name,\n  websiteUrl,\n}"
+        );
+    }
+
+    #[test]
+    fn does_not_extract_selection_sets_that_are_only_spread_once() {
+        let user = user();
+        let friend = linked_fields(vec![("friend", user.clone())]);
+        // friend { ... } is repeated, so its selection set is extracted. The selection
+        // set of friend is then only spread once, in that fragment, so it is inlined.
+        let selection_map = linked_fields(vec![
+            ("author", friend.clone()),
+            ("reviewer", friend.clone()),
+        ]);
+
+        assert_eq!(
+            query_text(
+                &selection_map,
+                QueryTextFragmentsOption::ExtractRepeatedSelectionSets
+            ),
+            "  author {\n    ...Query__User__0,\n  },\n  \
+            reviewer {\n    ...Query__User__0,\n  },\n}\n\
+            fragment Query__User__0 on User {\n  friend {\n    avatarUrl,\n    \
+            bio,\n    company,\n    email,\n    id,\n    login,\n    name,\n    \
+            websiteUrl,\n  },\n}"
+        );
+    }
+
+    #[test]
+    fn does_not_extract_selection_sets_if_that_makes_the_query_text_longer() {
+        let selection_map = linked_fields(vec![
+            ("author", scalars(&["id"])),
+            ("reviewer", scalars(&["id"])),
+        ]);
+
+        assert_eq!(
+            query_text(
+                &selection_map,
+                QueryTextFragmentsOption::ExtractRepeatedSelectionSets
+            ),
+            query_text(
+                &selection_map,
+                QueryTextFragmentsOption::InlineAllSelectionSets
+            ),
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use std::{collections::BTreeMap, path::PathBuf, str::Utf8Error};
 
-use common_lang_types::{RelativePathToSourceFile, WithLocation};
+use common_lang_types::{HasDiagnosticCode, RelativePathToSourceFile, WithLocation};
 use graphql_lang_types::{GraphQLTypeSystemDocument, GraphQLTypeSystemExtensionDocument};
 use graphql_schema_parser::{parse_schema, parse_schema_extensions, SchemaParseError};
 use isograph_lang_types::SchemaSource;
@@ -51,10 +51,18 @@ pub enum BatchCompileError {
     #[error("Unable to read the file at the following path: {path:?}.\nReason: {message}")]
     UnableToReadFile { path: PathBuf, message: String },
 
-    #[error("Unable to create schema.\nReason: {0}")]
+    #[error(
+        "Unable to create schema.\nReason: {}: {}",
+        .0.item.diagnostic_code(),
+        .0
+    )]
     UnableToCreateSchema(#[from] WithLocation<isograph_schema::CreateAdditionalFieldsError>),
 
-    #[error("Error when processing an entrypoint declaration.\nReason: {0}")]
+    #[error(
+        "Error when processing an entrypoint declaration.\nReason: {}: {}",
+        .0.item.diagnostic_code(),
+        .0
+    )]
     ErrorWhenProcessingEntrypointDeclaration(
         #[from] WithLocation<isograph_schema::ValidateEntrypointDeclarationError>,
     ),
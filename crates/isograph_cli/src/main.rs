@@ -2,13 +2,16 @@ mod opt;
 
 use clap::Parser;
 use colored::Colorize;
-use common_lang_types::CurrentWorkingDirectory;
+use common_lang_types::{CurrentWorkingDirectory, DiagnosticCode};
 use graphql_network_protocol::GraphQLNetworkProtocol;
 use intern::string_key::Intern;
-use isograph_compiler::{compile_and_print, handle_watch_command};
-use isograph_config::create_config;
+use isograph_compiler::{
+    check_and_print, compile_and_print, diagnostic_explanation, handle_watch_command,
+    print_config_error, OutputFormat,
+};
+use isograph_config::{create_configs, find_config_location, ConfigError};
 use opt::{Command, CompileCommand, LspCommand, Opt};
-use std::io;
+use std::{io, path::PathBuf};
 use tracing::{error, info, level_filters::LevelFilter};
 use tracing_subscriber::fmt::format::FmtSpan;
 
@@ -24,6 +27,9 @@ async fn main() {
         Command::Lsp(lsp_command) => {
             start_language_server(lsp_command, current_working_directory()).await;
         }
+        Command::Explain(explain_command) => {
+            explain(explain_command.code);
+        }
     }
 }
 
@@ -32,14 +38,20 @@ async fn start_compiler(
     current_working_directory: CurrentWorkingDirectory,
 ) {
     configure_logger(compile_command.log_level);
-    let config_location = compile_command
-        .config
-        .unwrap_or("./isograph.config.json".into());
+    let output_format = compile_command.output_format.into();
+    let config_location = match compile_command.config {
+        Some(config) => config,
+        None => discover_config_location().unwrap_or_else(|err| {
+            print_config_error(err, output_format);
+            std::process::exit(1);
+        }),
+    };
 
     if compile_command.watch {
         match handle_watch_command::<GraphQLNetworkProtocol>(
             config_location,
             current_working_directory,
+            output_format,
         )
         .await
         {
@@ -51,9 +63,20 @@ async fn start_compiler(
                 std::process::exit(1);
             }
         };
+    } else if compile_command.check {
+        if check_and_print::<GraphQLNetworkProtocol>(
+            config_location,
+            current_working_directory,
+            output_format,
+        )
+        .is_err()
+        {
+            std::process::exit(1);
+        }
     } else if compile_and_print::<GraphQLNetworkProtocol>(
         config_location,
         current_working_directory,
+        output_format,
     )
     .is_err()
     {
@@ -65,14 +88,26 @@ async fn start_language_server(
     lsp_command: LspCommand,
     current_working_directory: CurrentWorkingDirectory,
 ) {
-    let config = create_config(
-        lsp_command
-            .config
-            .unwrap_or("./isograph.config.json".into()),
-        current_working_directory,
-    );
+    let config_location = match lsp_command.config {
+        Some(config) => config,
+        None => discover_config_location().unwrap_or_else(|err| {
+            print_config_error(err, OutputFormat::Human);
+            std::process::exit(1);
+        }),
+    };
+    let configs = match create_configs(config_location, current_working_directory) {
+        Ok(configs) => configs,
+        Err(e) => {
+            error!(
+                "{}\n{}",
+                "Error when reading the config file.\n".bright_red(),
+                e
+            );
+            std::process::exit(1);
+        }
+    };
     info!("Starting language server");
-    if let Err(_e) = isograph_lsp::start_language_server(config).await {
+    if let Err(_e) = isograph_lsp::start_language_server::<GraphQLNetworkProtocol>(configs).await {
         error!(
             "{}",
             "Error encountered when running language server.".bright_red(),
@@ -82,6 +117,26 @@ async fn start_language_server(
     }
 }
 
+fn explain(code: DiagnosticCode) {
+    match diagnostic_explanation(code) {
+        Some(explanation) => print!("{explanation}"),
+        None => {
+            eprintln!(
+                "{}",
+                format!("There is no diagnostic with the code {code}.").bright_red()
+            );
+            std::process::exit(1);
+        }
+    }
+}
+
+/// If no config was passed, search for one in the current working directory and its
+/// parent directories.
+fn discover_config_location() -> Result<PathBuf, ConfigError> {
+    let current_dir = std::env::current_dir().expect("Expected current working to exist");
+    find_config_location(&current_dir)
+}
+
 fn configure_logger(log_level: LevelFilter) {
     let mut collector = tracing_subscriber::fmt()
         .pretty()
@@ -1,4 +1,5 @@
-use clap::{Args, Parser, Subcommand};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use common_lang_types::DiagnosticCode;
 use std::path::PathBuf;
 use tracing::level_filters::LevelFilter;
 
@@ -15,6 +16,7 @@ pub struct Opt {
 pub enum Command {
     Compile(CompileCommand),
     Lsp(LspCommand),
+    Explain(ExplainCommand),
 }
 
 /// Compile
@@ -23,20 +25,57 @@ pub(crate) struct CompileCommand {
     #[arg(long)]
     pub watch: bool,
 
-    /// Compile using this config file. If not provided, searches for a config in
-    /// package.json under the `isograph` key.
+    /// Run the compiler without writing any artifacts. Exits with a non-zero exit code,
+    /// and lists the files that would be added, changed or deleted, if the artifacts
+    /// on disk are out of date.
+    #[arg(long, visible_alias = "validate", conflicts_with = "watch")]
+    pub check: bool,
+
+    /// Compile using this config file. If not provided, searches the current directory
+    /// and its parent directories for an isograph.config.json file, a package.json file
+    /// with an `isograph` key, or an isograph.config.toml file.
     #[arg(long)]
     pub config: Option<PathBuf>,
 
     #[arg(long, value_enum, default_value = "info")]
     pub log_level: LevelFilter,
+
+    /// How to report the result of each compilation. With json, a JSON object containing
+    /// the diagnostics (each with a severity, a message, a file path and a start and end
+    /// line and column) is printed to stdout, on a single line, per compilation.
+    #[arg(long, value_enum, default_value = "human")]
+    pub output_format: OutputFormat,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub(crate) enum OutputFormat {
+    Human,
+    Json,
+}
+
+impl From<OutputFormat> for isograph_compiler::OutputFormat {
+    fn from(output_format: OutputFormat) -> Self {
+        match output_format {
+            OutputFormat::Human => isograph_compiler::OutputFormat::Human,
+            OutputFormat::Json => isograph_compiler::OutputFormat::Json,
+        }
+    }
 }
 
 /// LSP
 #[derive(Debug, Args)]
 pub(crate) struct LspCommand {
-    /// Compile using this config file. If not provided, searches for a config in
-    /// package.json under the `isograph` key.
+    /// Compile using this config file. If not provided, searches the current directory
+    /// and its parent directories for an isograph.config.json file, a package.json file
+    /// with an `isograph` key, or an isograph.config.toml file.
     #[arg(long)]
     pub config: Option<PathBuf>,
 }
+
+/// Print a long-form explanation of a diagnostic code, with an erroneous and a fixed
+/// example
+#[derive(Debug, Args)]
+pub(crate) struct ExplainCommand {
+    /// The diagnostic code, e.g. ISO0201
+    pub code: DiagnosticCode,
+}
@@ -1,13 +1,17 @@
+use std::collections::HashSet;
+
 use common_lang_types::{
-    IsographObjectTypeName, Location, SelectableName, UnvalidatedTypeName, WithLocation, WithSpan,
+    closest_match, did_you_mean, DiagnosticCode, HasDiagnosticCode, IsographObjectTypeName,
+    Location, SelectableName, UnvalidatedTypeName, WithLocation, WithSpan,
 };
+use intern::Lookup;
 use isograph_lang_types::{
     DefinitionLocation, ObjectSelection, ScalarSelection, ScalarSelectionDirectiveSet,
     SelectionType, ServerObjectEntityId, UnvalidatedScalarFieldSelection, UnvalidatedSelection,
 };
 use isograph_schema::{
-    ClientScalarOrObjectSelectable, NetworkProtocol, ObjectSelectableId, RefetchStrategy,
-    ScalarSelectableId, Schema, ServerObjectEntity, UnprocessedClientFieldItem,
+    ClientScalarOrObjectSelectable, ClientSelectableId, NetworkProtocol, ObjectSelectableId,
+    RefetchStrategy, ScalarSelectableId, Schema, ServerObjectEntity, UnprocessedClientFieldItem,
     UnprocessedClientPointerItem, UnprocessedItem, UseRefetchFieldRefetchStrategy,
     ValidatedObjectSelection, ValidatedScalarSelection, ValidatedSelection,
 };
@@ -16,34 +20,44 @@ use thiserror::Error;
 pub type ValidateAddSelectionSetsResultWithMultipleErrors<T> =
     Result<T, Vec<WithLocation<AddSelectionSetsError>>>;
 
+/// Validate the selection sets of each client field and pointer, and add them to the
+/// schema. If a selection set is invalid, the client field or pointer is left with an
+/// empty selection set, and its id is returned alongside the errors.
+#[allow(clippy::type_complexity)]
 pub(crate) fn add_selection_sets_to_client_selectables<TNetworkProtocol: NetworkProtocol>(
     schema: &mut Schema<TNetworkProtocol>,
     unprocessed_items: Vec<UnprocessedItem>,
-) -> ValidateAddSelectionSetsResultWithMultipleErrors<()> {
+) -> (
+    HashSet<ClientSelectableId>,
+    Vec<WithLocation<AddSelectionSetsError>>,
+) {
+    let mut client_selectables_with_invalid_selection_sets = HashSet::new();
     let mut errors = vec![];
     for unprocessed_item in unprocessed_items {
         match unprocessed_item {
             SelectionType::Scalar(unprocessed_client_field_item) => {
+                let client_field_id = unprocessed_client_field_item.client_field_id;
                 if let Err(e) =
                     process_unprocessed_client_field_item(schema, unprocessed_client_field_item)
                 {
+                    client_selectables_with_invalid_selection_sets
+                        .insert(SelectionType::Scalar(client_field_id));
                     errors.extend(e)
                 }
             }
             SelectionType::Object(unprocessed_client_pointer_item) => {
+                let client_pointer_id = unprocessed_client_pointer_item.client_pointer_id;
                 if let Err(e) =
                     process_unprocessed_client_pointer_item(schema, unprocessed_client_pointer_item)
                 {
+                    client_selectables_with_invalid_selection_sets
+                        .insert(SelectionType::Object(client_pointer_id));
                     errors.extend(e)
                 }
             }
         }
     }
-    if !errors.is_empty() {
-        Err(errors)
-    } else {
-        Ok(())
-    }
+    (client_selectables_with_invalid_selection_sets, errors)
 }
 
 // TODO we should not be mutating items in the schema. Instead, we should be creating
@@ -168,7 +182,7 @@ fn get_validated_scalar_selection<TNetworkProtocol: NetworkProtocol>(
     top_level_field_or_pointer: &impl ClientScalarOrObjectSelectable,
     scalar_selection: UnvalidatedScalarFieldSelection,
 ) -> AddSelectionSetsResult<ValidatedScalarSelection> {
-    let location = schema
+    let available_selectables = &schema
         .server_entity_data
         .server_object_entity_available_selectables
         .get(&selection_parent_object_id)
@@ -176,7 +190,8 @@ fn get_validated_scalar_selection<TNetworkProtocol: NetworkProtocol>(
             "Expected selection_parent_object_id to exist \
             in server_object_entity_available_selectables",
         )
-        .0
+        .0;
+    let location = available_selectables
         .get(&scalar_selection.name.item.into())
         .ok_or_else(|| {
             WithLocation::new(
@@ -188,6 +203,10 @@ fn get_validated_scalar_selection<TNetworkProtocol: NetworkProtocol>(
                     field_parent_type_name: selection_parent_object.name,
                     field_name: scalar_selection.name.item.into(),
                     client_type: top_level_field_or_pointer.client_type().to_string(),
+                    suggestion: closest_match(
+                        scalar_selection.name.item.lookup(),
+                        available_selectables.keys().copied(),
+                    ),
                 },
                 scalar_selection.name.location,
             )
@@ -196,13 +215,17 @@ fn get_validated_scalar_selection<TNetworkProtocol: NetworkProtocol>(
     let associated_data = match *location {
         DefinitionLocation::Server(server_selectable_id) => {
             // TODO encode this in types
-            if matches!(
-                scalar_selection.scalar_selection_directive_set,
-                ScalarSelectionDirectiveSet::Loadable(_)
-            ) {
+            let loadable_directive_name = match scalar_selection.scalar_selection_directive_set {
+                ScalarSelectionDirectiveSet::Loadable(_) => Some("loadable"),
+                ScalarSelectionDirectiveSet::Connection(_) => Some("connection"),
+                ScalarSelectionDirectiveSet::Updatable(_)
+                | ScalarSelectionDirectiveSet::None(_) => None,
+            };
+            if let Some(directive_name) = loadable_directive_name {
                 return Err(WithLocation::new(
                     AddSelectionSetsError::ServerFieldCannotBeSelectedLoadably {
                         server_field_name: scalar_selection.name.item.into(),
+                        directive_name,
                     },
                     scalar_selection.name.location,
                 ));
@@ -271,7 +294,7 @@ fn get_validated_object_selection<TNetworkProtocol: NetworkProtocol>(
     top_level_field_or_pointer: &impl ClientScalarOrObjectSelectable,
     object_selection: ObjectSelection<(), ()>,
 ) -> ValidateAddSelectionSetsResultWithMultipleErrors<ValidatedObjectSelection> {
-    let location = schema
+    let available_selectables = &schema
         .server_entity_data
         .server_object_entity_available_selectables
         .get(&selection_parent_object_id)
@@ -279,7 +302,8 @@ fn get_validated_object_selection<TNetworkProtocol: NetworkProtocol>(
             "Expected selection_parent_object_id to exist \
             in server_object_entity_available_selectables",
         )
-        .0
+        .0;
+    let location = available_selectables
         .get(&object_selection.name.item.into())
         .ok_or_else(|| {
             vec![WithLocation::new(
@@ -291,6 +315,10 @@ fn get_validated_object_selection<TNetworkProtocol: NetworkProtocol>(
                     field_parent_type_name: selection_parent_object.name,
                     field_name: object_selection.name.item.into(),
                     client_type: top_level_field_or_pointer.client_type().to_string(),
+                    suggestion: closest_match(
+                        object_selection.name.item.lookup(),
+                        available_selectables.keys().copied(),
+                    ),
                 },
                 object_selection.name.location,
             )]
@@ -396,6 +424,9 @@ fn get_validated_refetch_strategy<TNetworkProtocol: NetworkProtocol>(
                 generate_refetch_query: use_refetch_field_strategy.generate_refetch_query,
             }),
         )),
+        Some(RefetchStrategy::RefetchFromRoot(refetch_from_root_strategy)) => Ok(Some(
+            RefetchStrategy::RefetchFromRoot(refetch_from_root_strategy),
+        )),
         None => Ok(None),
     }
 }
@@ -427,7 +458,8 @@ pub enum AddSelectionSetsError {
     #[error(
         "In the client {client_type} `{client_field_parent_type_name}.{client_field_name}`, \
         the field `{field_parent_type_name}.{field_name}` is selected, but that \
-        field does not exist on `{field_parent_type_name}`"
+        field does not exist on `{field_parent_type_name}`.{}",
+        did_you_mean(*suggestion)
     )]
     SelectionTypeSelectionFieldDoesNotExist {
         client_field_parent_type_name: IsographObjectTypeName,
@@ -435,6 +467,7 @@ pub enum AddSelectionSetsError {
         field_parent_type_name: IsographObjectTypeName,
         field_name: SelectableName,
         client_type: String,
+        suggestion: Option<SelectableName>,
     },
 
     #[error(
@@ -479,6 +512,42 @@ pub enum AddSelectionSetsError {
         client_type: String,
     },
 
-    #[error("`{server_field_name}` is a server field, and cannot be selected with `@loadable`")]
-    ServerFieldCannotBeSelectedLoadably { server_field_name: SelectableName },
+    #[error(
+        "`{server_field_name}` is a server field, and cannot be selected with `@{directive_name}`"
+    )]
+    ServerFieldCannotBeSelectedLoadably {
+        server_field_name: SelectableName,
+        directive_name: &'static str,
+    },
+}
+
+impl HasDiagnosticCode for AddSelectionSetsError {
+    fn diagnostic_code(&self) -> DiagnosticCode {
+        match self {
+            AddSelectionSetsError::SelectionTypeSelectionFieldDoesNotExist { .. } => {
+                DiagnosticCode(401)
+            }
+            AddSelectionSetsError::SelectionTypeSelectionFieldIsNotScalar { .. } => {
+                DiagnosticCode(402)
+            }
+            AddSelectionSetsError::SelectionTypeSelectionFieldIsScalar { .. } => {
+                DiagnosticCode(403)
+            }
+            AddSelectionSetsError::SelectionTypeSelectionClientPointerSelectedAsScalar {
+                ..
+            } => DiagnosticCode(404),
+            AddSelectionSetsError::ServerFieldCannotBeSelectedLoadably { .. } => {
+                DiagnosticCode(405)
+            }
+        }
+    }
+
+    fn suggestion(&self) -> Option<String> {
+        match self {
+            AddSelectionSetsError::SelectionTypeSelectionFieldDoesNotExist {
+                suggestion, ..
+            } => suggestion.map(|suggestion| suggestion.to_string()),
+            _ => None,
+        }
+    }
 }
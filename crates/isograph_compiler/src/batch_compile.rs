@@ -1,48 +1,180 @@
 use std::{path::PathBuf, str::Utf8Error};
 
-use crate::{compiler_state::compile, source_files::SourceFiles, with_duration::WithDuration};
+use crate::{
+    compiler_state::check,
+    diagnostics::{JsonCompilationResult, OutputFormat},
+    source_files::SourceFiles,
+    with_duration::WithDuration,
+    with_warnings::WithWarnings,
+    write_artifacts::GenerateArtifactsError,
+};
 use colored::Colorize;
-use common_lang_types::{CurrentWorkingDirectory, WithLocation};
+use common_lang_types::{
+    CurrentWorkingDirectory, DiagnosticCode, EmbeddedLocation, HasDiagnosticCode, Location,
+    Severity, WithLocation,
+};
+use intern::Lookup;
+use isograph_config::ConfigError;
 use isograph_lang_parser::IsographLiteralParseError;
 use isograph_schema::{NetworkProtocol, ProcessClientFieldDeclarationError};
+use pico::Database;
 use pretty_duration::pretty_duration;
 use thiserror::Error;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
-use crate::compiler_state::CompilerState;
+use crate::compiler_state::{ProjectState, ProjectsCompilerState};
 
 pub struct CompilationStats {
     pub client_field_count: usize,
     pub entrypoint_count: usize,
-    pub total_artifacts_written: usize,
+    /// The number of new or changed artifacts, i.e. not including unchanged artifacts.
+    pub changed_artifacts_written: usize,
 }
 
 pub fn compile_and_print<TNetworkProtocol: NetworkProtocol>(
     config_location: PathBuf,
     current_working_directory: CurrentWorkingDirectory,
+    output_format: OutputFormat,
 ) -> Result<(), Box<dyn std::error::Error>> {
     info!("{}", "Starting to compile.".cyan());
-    print_result(WithDuration::new(|| {
-        let mut state = CompilerState::new(config_location, current_working_directory);
-        let sources = SourceFiles::read_all(&mut state.db, &state.config)?;
-        compile::<TNetworkProtocol>(&state.db, &sources, &state.config)
-    }))
+    let mut state = ProjectsCompilerState::new(config_location, current_working_directory)
+        .map_err(|err| print_config_error(err, output_format))?;
+    let errors = state
+        .projects
+        .iter_mut()
+        .filter_map(|project| {
+            print_result(
+                WithDuration::new(|| {
+                    project.compile_from_scratch::<TNetworkProtocol>(&mut state.db)
+                }),
+                project.label.as_deref(),
+                output_format,
+            )
+            .err()
+        })
+        .collect();
+    combine_project_errors(errors)
 }
 
+/// Compile, but rather than writing artifacts, check whether the artifacts on disk are
+/// up to date. If they are not, print the files that would be added, changed or deleted
+/// and return an error.
+pub fn check_and_print<TNetworkProtocol: NetworkProtocol>(
+    config_location: PathBuf,
+    current_working_directory: CurrentWorkingDirectory,
+    output_format: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    info!("{}", "Checking whether artifacts are up to date.".cyan());
+    let mut state = ProjectsCompilerState::new(config_location, current_working_directory)
+        .map_err(|err| print_config_error(err, output_format))?;
+    let errors = state
+        .projects
+        .iter()
+        .filter_map(|project| {
+            check_project_and_print::<TNetworkProtocol>(&mut state.db, project, output_format).err()
+        })
+        .collect();
+    combine_project_errors(errors)
+}
+
+fn check_project_and_print<TNetworkProtocol: NetworkProtocol>(
+    db: &mut Database,
+    project: &ProjectState,
+    output_format: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let config = &project.config;
+    let result = WithDuration::new(|| {
+        let sources = match SourceFiles::read_all(db, config) {
+            Ok(sources) => sources,
+            Err(error) => return WithWarnings::without_warnings(Err(error)),
+        };
+        check::<TNetworkProtocol>(db, &sources, config).map(|result| {
+            let (stats, changes) = result?;
+            if changes.is_empty() {
+                Ok(stats)
+            } else {
+                let artifact_directory = &config.artifact_directory;
+                let relative_path = |absolute_path: &PathBuf| {
+                    absolute_path
+                        .strip_prefix(&artifact_directory.absolute_path)
+                        .map(|path| {
+                            PathBuf::from(artifact_directory.relative_path.lookup()).join(path)
+                        })
+                        .unwrap_or_else(|_| absolute_path.clone())
+                };
+                Err(Box::new(BatchCompileError::ArtifactsOutOfDate {
+                    added: changes.added.keys().map(relative_path).collect(),
+                    changed: changes.changed.keys().map(relative_path).collect(),
+                    deleted: changes.deleted.iter().map(relative_path).collect(),
+                })
+                .into())
+            }
+        })
+    });
+
+    let elapsed_time = result.elapsed_time;
+    let WithWarnings { item, warnings } = result.item;
+
+    if output_format == OutputFormat::Json {
+        return print_json_result(item.map(|_| ()), &warnings, project.label.as_deref());
+    }
+
+    print_warnings(&warnings, project.label.as_deref());
+
+    let label = format_label(project.label.as_deref());
+    match item {
+        Ok(stats) => {
+            info!(
+                "{}",
+                format!(
+                    "{label}Artifacts for {} client fields and {} entrypoints are up to date. \
+                        Checked in {}.",
+                    stats.client_field_count,
+                    stats.entrypoint_count,
+                    pretty_duration(&elapsed_time, None)
+                )
+            );
+            Ok(())
+        }
+        Err(err) => {
+            error!(
+                "{}\n{}\n{}",
+                format!("{label}Error when checking artifacts.\n").bright_red(),
+                format_error_with_code(err.as_ref()),
+                format!("Checking took {}.", pretty_duration(&elapsed_time, None)).bright_red()
+            );
+            Err(err)
+        }
+    }
+}
+
+/// Print the result of compiling a project. If the config file contains multiple
+/// projects, the messages are prefixed with the label of the project.
 pub fn print_result(
-    result: WithDuration<Result<CompilationStats, Box<dyn std::error::Error>>>,
+    result: WithDuration<WithWarnings<Result<CompilationStats, Box<dyn std::error::Error>>>>,
+    label: Option<&str>,
+    output_format: OutputFormat,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let elapsed_time = result.elapsed_time;
-    match result.item {
+    let WithWarnings { item, warnings } = result.item;
+
+    if output_format == OutputFormat::Json {
+        return print_json_result(item.map(|_| ()), &warnings, label);
+    }
+
+    print_warnings(&warnings, label);
+
+    let label = format_label(label);
+    match item {
         Ok(stats) => {
             info!(
                 "{}",
                 format!(
-                    "Successfully compiled {} client fields and {} \
-                        entrypoints, and wrote {} artifacts, in {}.",
+                    "{label}Successfully compiled {} client fields and {} \
+                        entrypoints, and wrote {} new or changed artifacts, in {}.",
                     stats.client_field_count,
                     stats.entrypoint_count,
-                    stats.total_artifacts_written,
+                    stats.changed_artifacts_written,
                     pretty_duration(&elapsed_time, None)
                 )
             );
@@ -51,8 +183,8 @@ pub fn print_result(
         Err(err) => {
             error!(
                 "{}\n{}\n{}",
-                "Error when compiling.\n".bright_red(),
-                err,
+                format!("{label}Error when compiling.\n").bright_red(),
+                format_error_with_code(err.as_ref()),
                 format!("Compilation took {}.", pretty_duration(&elapsed_time, None)).bright_red()
             );
             Err(err)
@@ -60,6 +192,78 @@ pub fn print_result(
     }
 }
 
+/// Print the warnings that were encountered when compiling a project. Warnings are
+/// printed whether or not compilation succeeded.
+fn print_warnings(warnings: &[WithLocation<ErrorWithDiagnosticCode>], label: Option<&str>) {
+    if warnings.is_empty() {
+        return;
+    }
+    warn!(
+        "{}{}",
+        format!(
+            "{}{}",
+            format_label(label),
+            if warnings.len() == 1 {
+                "Warning when compiling:"
+            } else {
+                "Warnings when compiling:"
+            }
+        )
+        .yellow(),
+        format_messages_with_codes(warnings)
+    );
+}
+
+/// Print an error that was encountered when reading the config file, and return it.
+pub fn print_config_error(
+    err: ConfigError,
+    output_format: OutputFormat,
+) -> Box<dyn std::error::Error> {
+    if output_format == OutputFormat::Json {
+        JsonCompilationResult::new(Err(&err), &[], None).print();
+        return Box::new(err);
+    }
+
+    error!(
+        "{}\n{}",
+        "Error when reading the config file.\n".bright_red(),
+        format_error_with_code(&err)
+    );
+    Box::new(err)
+}
+
+/// Print the result of compiling or checking a project as a single line of JSON to
+/// stdout, so that it can be parsed by other tools (e.g. CI or editor integrations).
+fn print_json_result(
+    result: Result<(), Box<dyn std::error::Error>>,
+    warnings: &[WithLocation<ErrorWithDiagnosticCode>],
+    label: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    JsonCompilationResult::new(
+        result.as_ref().map(|_| ()).map_err(|err| err.as_ref()),
+        warnings,
+        label,
+    )
+    .print();
+    result
+}
+
+fn format_label(label: Option<&str>) -> String {
+    label.map(|label| format!("[{label}] ")).unwrap_or_default()
+}
+
+/// The errors of each project have already been printed, so they are only combined
+/// in order to signal that compilation failed.
+fn combine_project_errors(
+    mut errors: Vec<Box<dyn std::error::Error>>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match errors.len() {
+        0 => Ok(()),
+        1 => Err(errors.remove(0)),
+        _ => Err(BatchCompileError::MultipleErrors { messages: errors }.into()),
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum BatchCompileError {
     #[error("Unable to load schema file at path {path:?}.\nReason: {message}")]
@@ -85,16 +289,17 @@ pub enum BatchCompileError {
     #[error(
         "{}{}",
         if messages.len() == 1 { "Unable to parse Isograph literal:" } else { "Unable to parse Isograph literals:" },
-        messages.iter().fold(String::new(), |mut output, x| {
-            output.push_str(&format!("\n\n{}", x));
-            output
-        })
+        format_messages_with_codes(messages)
     )]
     UnableToParseIsographLiterals {
         messages: Vec<WithLocation<IsographLiteralParseError>>,
     },
 
-    #[error("Error when doing additional schema processing.\nReason: {0}")]
+    #[error(
+        "Error when doing additional schema processing.\nReason: {}: {}",
+        .0.item.diagnostic_code(),
+        .0
+    )]
     UnableToCreateSchema(#[from] WithLocation<isograph_schema::CreateAdditionalFieldsError>),
 
     #[error(
@@ -104,10 +309,7 @@ pub enum BatchCompileError {
         } else {
             "Errors when processing client field declarations:"
         },
-        messages.iter().fold(String::new(), |mut output, x| {
-            output.push_str(&format!("\n\n{}", x));
-            output
-        })
+        format_messages_with_codes(messages)
     )]
     ErrorWhenProcessingClientFieldDeclaration {
         messages: Vec<WithLocation<isograph_schema::ProcessClientFieldDeclarationError>>,
@@ -119,13 +321,25 @@ pub enum BatchCompileError {
     #[error("Unable to convert file {path:?} to utf8.\nDetailed reason: {reason}")]
     UnableToConvertToString { path: PathBuf, reason: Utf8Error },
 
+    #[error(
+        "The generated artifacts are out of date. Run the Isograph compiler to update them.{}{}{}",
+        format_artifact_paths("Artifacts that would be added:", added),
+        format_artifact_paths("Artifacts that would be changed:", changed),
+        format_artifact_paths("Files that would be deleted:", deleted)
+    )]
+    ArtifactsOutOfDate {
+        added: Vec<PathBuf>,
+        changed: Vec<PathBuf>,
+        deleted: Vec<PathBuf>,
+    },
+
     #[error("The __refetch field was already defined. Isograph creates it automatically; you cannot create it.")]
     DuplicateRefetchField,
 
     #[error(
         "{}",
         messages.iter().fold(String::new(), |mut output, x| {
-            output.push_str(&format!("\n\n{}", x));
+            output.push_str(&format!("\n\n{}", format_error_with_code(x.as_ref())));
             output
         })
     )]
@@ -133,18 +347,258 @@ pub enum BatchCompileError {
         messages: Vec<Box<dyn std::error::Error>>,
     },
 
-    #[error(
-        "{}",
-        messages.iter().fold(String::new(), |mut output, x| {
-            output.push_str(&format!("\n\n{}", x));
-            output
-        })
-    )]
+    #[error("{}", format_messages_with_codes(messages))]
     MultipleErrorsWithLocations {
-        messages: Vec<WithLocation<Box<dyn std::error::Error>>>,
+        messages: Vec<WithLocation<ErrorWithDiagnosticCode>>,
     },
 }
 
+impl BatchCompileError {
+    pub(crate) fn multiple_errors_with_locations<
+        T: std::error::Error + HasDiagnosticCode + 'static,
+    >(
+        messages: Vec<WithLocation<T>>,
+    ) -> Self {
+        BatchCompileError::MultipleErrorsWithLocations {
+            messages: messages
+                .into_iter()
+                .map(|message| message.map(ErrorWithDiagnosticCode::new))
+                .collect(),
+        }
+    }
+
+    /// The diagnostic code of an error that does not refer to a location in a source
+    /// file. Errors that contain located errors return None, since each located error
+    /// has its own code.
+    pub fn diagnostic_code(&self) -> Option<DiagnosticCode> {
+        match self {
+            BatchCompileError::UnableToLoadSchema { .. } => Some(DiagnosticCode(1101)),
+            BatchCompileError::SchemaNotFound => Some(DiagnosticCode(1102)),
+            BatchCompileError::SchemaNotAFile { .. } => Some(DiagnosticCode(1103)),
+            BatchCompileError::ProjectRootNotADirectory { .. } => Some(DiagnosticCode(1104)),
+            BatchCompileError::UnableToReadFile { .. } => Some(DiagnosticCode(1105)),
+            BatchCompileError::UnableToTraverseDirectory { .. } => Some(DiagnosticCode(1106)),
+            BatchCompileError::UnableToConvertToString { .. } => Some(DiagnosticCode(1107)),
+            BatchCompileError::UnableToStripPrefix(_) => Some(DiagnosticCode(1108)),
+            BatchCompileError::ArtifactsOutOfDate { .. } => Some(DiagnosticCode(1206)),
+            BatchCompileError::DuplicateRefetchField => Some(DiagnosticCode(829)),
+            BatchCompileError::UnableToParseIsographLiterals { .. }
+            | BatchCompileError::UnableToCreateSchema(_)
+            | BatchCompileError::ErrorWhenProcessingClientFieldDeclaration { .. }
+            | BatchCompileError::MultipleErrors { .. }
+            | BatchCompileError::MultipleErrorsWithLocations { .. } => None,
+        }
+    }
+
+    /// Combine the errors encountered in each phase of compilation into a single
+    /// error, or return None if there are no errors.
+    pub(crate) fn combine(mut errors: Vec<BatchCompileError>) -> Option<Self> {
+        match errors.len() {
+            0 => None,
+            1 => errors.pop(),
+            _ => Some(BatchCompileError::MultipleErrors {
+                messages: errors
+                    .into_iter()
+                    .map(|error| Box::new(error) as Box<dyn std::error::Error>)
+                    .collect(),
+            }),
+        }
+    }
+}
+
+/// An error, its diagnostic code and suggestion, for errors of different types that are
+/// reported together. The diagnostic code is not part of the error message.
+#[derive(Debug)]
+pub struct ErrorWithDiagnosticCode {
+    pub code: DiagnosticCode,
+    pub suggestion: Option<String>,
+    pub reports_deprecation: bool,
+    pub error: Box<dyn std::error::Error>,
+}
+
+impl ErrorWithDiagnosticCode {
+    pub(crate) fn new<T: std::error::Error + HasDiagnosticCode + 'static>(error: T) -> Self {
+        ErrorWithDiagnosticCode {
+            code: error.diagnostic_code(),
+            suggestion: error.suggestion(),
+            reports_deprecation: error.reports_deprecation(),
+            error: Box::new(error),
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorWithDiagnosticCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl HasDiagnosticCode for ErrorWithDiagnosticCode {
+    fn diagnostic_code(&self) -> DiagnosticCode {
+        self.code
+    }
+
+    fn suggestion(&self) -> Option<String> {
+        self.suggestion.clone()
+    }
+
+    fn reports_deprecation(&self) -> bool {
+        self.reports_deprecation
+    }
+}
+
+/// Format each message, preceded by its diagnostic code, e.g. ISO0201.
+fn format_messages_with_codes<T: std::fmt::Display + HasDiagnosticCode>(
+    messages: &[WithLocation<T>],
+) -> String {
+    messages.iter().fold(String::new(), |mut output, x| {
+        output.push_str(&format!("\n\n{}: {}", x.item.diagnostic_code(), x));
+        output
+    })
+}
+
+/// The diagnostic code of an error that does not refer to a location in a source file,
+/// if it has one.
+fn diagnostic_code_without_location(
+    error: &(dyn std::error::Error + 'static),
+) -> Option<DiagnosticCode> {
+    if let Some(batch_compile_error) = error.downcast_ref::<BatchCompileError>() {
+        batch_compile_error.diagnostic_code()
+    } else if let Some(config_error) = error.downcast_ref::<ConfigError>() {
+        Some(config_error.diagnostic_code())
+    } else {
+        error
+            .downcast_ref::<GenerateArtifactsError>()
+            .map(GenerateArtifactsError::diagnostic_code)
+    }
+}
+
+/// Format an error, preceded by its diagnostic code if it does not refer to a location.
+/// Errors that contain located errors already include the code of each located error.
+fn format_error_with_code(error: &(dyn std::error::Error + 'static)) -> String {
+    match diagnostic_code_without_location(error) {
+        Some(code) => format!("{code}: {error}"),
+        None => error.to_string(),
+    }
+}
+
+fn format_artifact_paths(heading: &str, paths: &[PathBuf]) -> String {
+    if paths.is_empty() {
+        return String::new();
+    }
+    paths
+        .iter()
+        .fold(format!("\n\n{heading}"), |mut output, path| {
+            output.push_str(&format!("\n  {}", path.display()));
+            output
+        })
+}
+
+/// An error or warning message, and the location in a source file that it refers to,
+/// if any. Unlike the Display implementation of WithLocation, the message does not
+/// contain the location or the diagnostic code.
+#[derive(Debug)]
+pub struct LocatedErrorMessage {
+    pub message: String,
+    pub severity: Severity,
+    pub location: Option<EmbeddedLocation>,
+    pub code: Option<DiagnosticCode>,
+    /// A replacement for the text at the location, e.g. the name of an existing field
+    /// if the selected field does not exist.
+    pub suggestion: Option<String>,
+    pub reports_deprecation: bool,
+}
+
+impl LocatedErrorMessage {
+    fn without_location(error: &(dyn std::error::Error + 'static)) -> Self {
+        LocatedErrorMessage {
+            message: error.to_string(),
+            severity: Severity::Error,
+            location: None,
+            code: diagnostic_code_without_location(error),
+            suggestion: None,
+            reports_deprecation: false,
+        }
+    }
+}
+
+impl<T: std::fmt::Display + HasDiagnosticCode> From<&WithLocation<T>> for LocatedErrorMessage {
+    fn from(with_location: &WithLocation<T>) -> Self {
+        LocatedErrorMessage {
+            message: with_location.item.to_string(),
+            severity: Severity::Error,
+            location: embedded_location(with_location.location),
+            code: Some(with_location.item.diagnostic_code()),
+            suggestion: with_location.item.suggestion(),
+            reports_deprecation: with_location.item.reports_deprecation(),
+        }
+    }
+}
+
+fn embedded_location(location: Location) -> Option<EmbeddedLocation> {
+    match location {
+        Location::Embedded(embedded_location) => Some(embedded_location),
+        Location::Generated => None,
+    }
+}
+
+/// Break an error returned from compilation into its individual messages, so that
+/// each message can be displayed at its location (e.g. by the language server).
+pub fn located_error_messages(
+    error: &(dyn std::error::Error + 'static),
+) -> Vec<LocatedErrorMessage> {
+    if let Some(batch_compile_error) = error.downcast_ref::<BatchCompileError>() {
+        match batch_compile_error {
+            BatchCompileError::UnableToParseIsographLiterals { messages } => {
+                return messages.iter().map(LocatedErrorMessage::from).collect();
+            }
+            BatchCompileError::UnableToCreateSchema(message) => {
+                return vec![message.into()];
+            }
+            BatchCompileError::ErrorWhenProcessingClientFieldDeclaration { messages } => {
+                return messages.iter().map(LocatedErrorMessage::from).collect();
+            }
+            BatchCompileError::MultipleErrorsWithLocations { messages } => {
+                return messages.iter().map(LocatedErrorMessage::from).collect();
+            }
+            BatchCompileError::MultipleErrors { messages } => {
+                return messages
+                    .iter()
+                    .flat_map(|message| located_error_messages(message.as_ref()))
+                    .collect();
+            }
+            _ => {}
+        }
+    } else if let Some(message) =
+        error.downcast_ref::<WithLocation<isograph_schema::CreateAdditionalFieldsError>>()
+    {
+        return vec![message.into()];
+    } else if let Some(ConfigError::UnableToParseConfig { message, location }) =
+        error.downcast_ref::<ConfigError>()
+    {
+        return vec![LocatedErrorMessage {
+            message: message.clone(),
+            location: embedded_location(*location),
+            ..LocatedErrorMessage::without_location(error)
+        }];
+    }
+
+    vec![LocatedErrorMessage::without_location(error)]
+}
+
+/// The individual messages of the warnings encountered during compilation.
+pub fn located_warning_messages(
+    warnings: &[WithLocation<ErrorWithDiagnosticCode>],
+) -> Vec<LocatedErrorMessage> {
+    warnings
+        .iter()
+        .map(|warning| LocatedErrorMessage {
+            severity: Severity::Warning,
+            ..warning.into()
+        })
+        .collect()
+}
+
 impl From<Vec<WithLocation<IsographLiteralParseError>>> for BatchCompileError {
     fn from(messages: Vec<WithLocation<IsographLiteralParseError>>) -> Self {
         BatchCompileError::UnableToParseIsographLiterals { messages }
@@ -4,21 +4,29 @@ use std::{
     time::{Duration, Instant},
 };
 
-use common_lang_types::{CurrentWorkingDirectory, WithLocation};
+use common_lang_types::{
+    relative_path_from_absolute_and_working_directory, CurrentWorkingDirectory, Severity,
+};
 use generate_artifacts::get_artifact_path_and_content;
-use isograph_config::{create_config, CompilerConfig};
-use isograph_schema::{validate_use_of_arguments, NetworkProtocol};
+use isograph_config::{create_configs, CompilerConfig, ConfigError};
+use isograph_schema::{
+    validate_connection_selections, validate_use_of_arguments, validate_use_of_deprecated_fields,
+    NetworkProtocol, Schema,
+};
 use pico::Database;
 
 use crate::{
     batch_compile::{BatchCompileError, CompilationStats},
-    create_schema::create_schema,
+    create_schema::{create_schema, ContainsIsoStats, CreateSchemaOutcome},
     source_files::SourceFiles,
-    write_artifacts::write_artifacts_to_disk,
+    watch::SourceFileEvent,
+    with_warnings::WithWarnings,
+    write_artifacts::{get_artifact_changes, write_artifacts_to_disk, ArtifactChanges},
 };
 
 const GC_DURATION: u64 = 60;
 
+#[derive(Debug)]
 pub struct CompilerState {
     pub db: Database,
     pub config: CompilerConfig,
@@ -27,13 +35,10 @@ pub struct CompilerState {
 }
 
 impl CompilerState {
-    pub fn new(
-        config_location: PathBuf,
-        current_working_directory: CurrentWorkingDirectory,
-    ) -> Self {
+    pub fn new_with_config(config: CompilerConfig) -> Self {
         Self {
             db: Database::new(),
-            config: create_config(config_location, current_working_directory),
+            config,
             source_files: None,
             last_gc_run: Instant::now(),
         }
@@ -47,6 +52,96 @@ impl CompilerState {
     }
 }
 
+/// The state of the compiler when it compiles every project in a config file, as
+/// the batch compiler and watch mode do. The projects share a single database, so
+/// that files that are used by multiple projects (e.g. a schema) are only parsed once.
+#[derive(Debug)]
+pub struct ProjectsCompilerState {
+    pub db: Database,
+    pub config_location: PathBuf,
+    pub projects: Vec<ProjectState>,
+    pub last_gc_run: Instant,
+}
+
+impl ProjectsCompilerState {
+    pub fn new(
+        config_location: PathBuf,
+        current_working_directory: CurrentWorkingDirectory,
+    ) -> Result<Self, ConfigError> {
+        let configs = create_configs(config_location.clone(), current_working_directory)?;
+        let has_multiple_projects = configs.len() > 1;
+        Ok(Self {
+            db: Database::new(),
+            config_location,
+            projects: configs
+                .into_iter()
+                .map(|config| ProjectState {
+                    label: has_multiple_projects.then(|| {
+                        relative_path_from_absolute_and_working_directory(
+                            current_working_directory,
+                            &config.project_root,
+                        )
+                        .to_string()
+                    }),
+                    config,
+                    source_files: None,
+                })
+                .collect(),
+            last_gc_run: Instant::now(),
+        })
+    }
+
+    pub fn run_garbage_collection(&mut self) {
+        if self.last_gc_run.elapsed() >= Duration::from_secs(GC_DURATION) {
+            self.db.run_garbage_collection();
+            self.last_gc_run = Instant::now();
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ProjectState {
+    pub config: CompilerConfig,
+    pub source_files: Option<SourceFiles>,
+    /// If the config file contains multiple projects, messages about this project
+    /// are prefixed with this label (the path to the project root).
+    pub label: Option<String>,
+}
+
+impl ProjectState {
+    /// Read every source file of the project, and compile it.
+    pub fn compile_from_scratch<TNetworkProtocol: NetworkProtocol>(
+        &mut self,
+        db: &mut Database,
+    ) -> WithWarnings<Result<CompilationStats, Box<dyn Error>>> {
+        let source_files = match SourceFiles::read_all(db, &self.config) {
+            Ok(source_files) => source_files,
+            Err(error) => return WithWarnings::without_warnings(Err(error)),
+        };
+        let result = compile::<TNetworkProtocol>(db, &source_files, &self.config);
+        self.source_files = Some(source_files);
+        result
+    }
+
+    /// Read the source files that changed, and compile the project. If the source
+    /// files have never been read, read every source file instead.
+    pub fn compile_changes<TNetworkProtocol: NetworkProtocol>(
+        &mut self,
+        db: &mut Database,
+        changes: &[SourceFileEvent],
+    ) -> WithWarnings<Result<CompilationStats, Box<dyn Error>>> {
+        match self.source_files.as_mut() {
+            Some(source_files) => {
+                if let Err(error) = source_files.read_updates(db, &self.config, changes) {
+                    return WithWarnings::without_warnings(Err(error));
+                }
+                compile::<TNetworkProtocol>(db, source_files, &self.config)
+            }
+            None => self.compile_from_scratch::<TNetworkProtocol>(db),
+        }
+    }
+}
+
 /// This the "workhorse" command of batch compilation.
 ///
 /// ## Overall plan
@@ -65,7 +160,9 @@ impl CompilerState {
 /// - Generate an in-memory representation of all of the generated files
 ///   (called artifacts). This step should not fail. It should panic if any
 ///   invariant is violated, or represent that invariant in the type system.
-/// - Delete and recreate the artifacts on disk.
+/// - Write the artifacts to disk. Only new and changed artifacts are written. Files
+///   that no longer correspond to an artifact are removed, as are directories left
+///   empty.
 ///
 /// ## Additional things we do
 ///
@@ -82,32 +179,725 @@ pub fn compile<TNetworkProtocol: NetworkProtocol>(
     db: &Database,
     source_files: &SourceFiles,
     config: &CompilerConfig,
-) -> Result<CompilationStats, Box<dyn Error>> {
-    // Create schema
-    let (isograph_schema, stats) = create_schema::<TNetworkProtocol>(db, source_files, config)?;
+) -> WithWarnings<Result<CompilationStats, Box<dyn Error>>> {
+    create_and_validate_schema::<TNetworkProtocol>(db, source_files, config).map(|result| {
+        let (isograph_schema, stats) = result?;
 
-    validate_use_of_arguments(&isograph_schema).map_err(|messages| {
-        Box::new(BatchCompileError::MultipleErrorsWithLocations {
-            messages: messages
-                .into_iter()
-                .map(|x| {
-                    WithLocation::new(Box::new(x.item) as Box<dyn std::error::Error>, x.location)
-                })
-                .collect(),
+        // Note: we calculate all of the artifact paths and contents first, so that writing
+        // to disk can be as fast as possible and we minimize the chance that changes to the
+        // file system occur while we're writing and we get unpredictable results.
+
+        let artifacts = get_artifact_path_and_content(&isograph_schema, config);
+
+        let changed_artifacts_written =
+            write_artifacts_to_disk(artifacts, &config.artifact_directory.absolute_path)?;
+        Ok(CompilationStats {
+            client_field_count: stats.client_field_count,
+            entrypoint_count: stats.entrypoint_count,
+            changed_artifacts_written,
         })
-    })?;
+    })
+}
 
-    // Note: we calculate all of the artifact paths and contents first, so that writing to
-    // disk can be as fast as possible and we minimize the chance that changes to the file
-    // system occur while we're writing and we get unpredictable results.
+/// Run the full compilation pipeline, but instead of writing the artifacts to disk,
+/// compare them against the contents of the artifact directory.
+pub fn check<TNetworkProtocol: NetworkProtocol>(
+    db: &Database,
+    source_files: &SourceFiles,
+    config: &CompilerConfig,
+) -> WithWarnings<Result<(ContainsIsoStats, ArtifactChanges), Box<dyn Error>>> {
+    create_and_validate_schema::<TNetworkProtocol>(db, source_files, config).map(|result| {
+        let (isograph_schema, stats) = result?;
 
-    let artifacts = get_artifact_path_and_content(&isograph_schema, config);
+        let artifacts = get_artifact_path_and_content(&isograph_schema, config);
 
-    let total_artifacts_written =
-        write_artifacts_to_disk(artifacts, &config.artifact_directory.absolute_path)?;
-    Ok(CompilationStats {
-        client_field_count: stats.client_field_count,
-        entrypoint_count: stats.entrypoint_count,
-        total_artifacts_written,
+        let changes = get_artifact_changes(artifacts, &config.artifact_directory.absolute_path)?;
+        Ok((stats, changes))
     })
 }
+
+pub type CreateAndValidateSchemaResult<TNetworkProtocol> =
+    Result<(Schema<TNetworkProtocol>, ContainsIsoStats), Box<dyn Error>>;
+
+/// Create the schema and validate it, without generating artifacts. Every error
+/// that the compiler can report is encountered here, so this is also used by the
+/// language server, which reports errors but does not write artifacts.
+///
+/// Errors are accumulated across phases, so that e.g. an iso literal that cannot be
+/// parsed does not prevent errors in other iso literals from being reported. Warnings
+/// are returned whether or not the schema is valid.
+pub fn create_and_validate_schema<TNetworkProtocol: NetworkProtocol>(
+    db: &Database,
+    source_files: &SourceFiles,
+    config: &CompilerConfig,
+) -> WithWarnings<CreateAndValidateSchemaResult<TNetworkProtocol>> {
+    let CreateSchemaOutcome {
+        schema: isograph_schema,
+        contains_iso_stats,
+        mut errors,
+        client_selectables_with_invalid_selection_sets,
+    } = match create_schema::<TNetworkProtocol>(db, source_files, config) {
+        Ok(outcome) => outcome,
+        Err(error) => return WithWarnings::without_warnings(Err(error)),
+    };
+
+    if let Err(e) = validate_use_of_arguments(
+        &isograph_schema,
+        &client_selectables_with_invalid_selection_sets,
+    ) {
+        errors.push(BatchCompileError::multiple_errors_with_locations(e));
+    }
+
+    if let Err(e) = validate_connection_selections(
+        &isograph_schema,
+        &client_selectables_with_invalid_selection_sets,
+    ) {
+        errors.push(BatchCompileError::multiple_errors_with_locations(e));
+    }
+
+    let mut deprecated_field_warnings = vec![];
+    if let Some(severity) = config.options.on_deprecated_field.severity() {
+        let deprecated_field_uses = validate_use_of_deprecated_fields(&isograph_schema);
+        if !deprecated_field_uses.is_empty() {
+            match severity {
+                Severity::Error => errors.push(BatchCompileError::multiple_errors_with_locations(
+                    deprecated_field_uses,
+                )),
+                Severity::Warning => deprecated_field_warnings = deprecated_field_uses,
+            }
+        }
+    }
+
+    let mut result = WithWarnings::without_warnings(match BatchCompileError::combine(errors) {
+        Some(error) => Err(error.into()),
+        None => Ok((isograph_schema, contains_iso_stats)),
+    });
+    result.add_warnings(deprecated_field_warnings);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, fs, path::Path};
+
+    use common_lang_types::ArtifactPathAndContent;
+    use graphql_network_protocol::GraphQLNetworkProtocol;
+    use intern::string_key::{Intern, Lookup};
+    use isograph_test_utils::TestProjectDirectory;
+
+    use super::*;
+    use crate::{
+        batch_compile::{
+            check_and_print, compile_and_print, located_error_messages, LocatedErrorMessage,
+        },
+        diagnostics::OutputFormat,
+    };
+
+    /// Create a project in a temporary directory and generate its artifacts. Returns
+    /// the error messages if the project does not compile, each preceded by its code
+    /// and the text at its location, and any warnings.
+    fn generate_test_project_artifacts(
+        test_name: &str,
+        options: &str,
+        schema: &str,
+        source: &str,
+    ) -> WithWarnings<Result<Vec<ArtifactPathAndContent>, String>> {
+        let project_directory = TestProjectDirectory::new(
+            test_name,
+            &[
+                (
+                    "isograph.config.json",
+                    &format!(
+                        r#"{{ "project_root": "./src", "schema": "./schema.graphql", "options": {options} }}"#
+                    ),
+                ),
+                ("schema.graphql", schema),
+                ("src/Home.tsx", source),
+            ],
+        );
+        let current_working_directory = project_directory.path().to_str().unwrap().intern().into();
+        let config = create_configs(
+            project_directory.join("isograph.config.json"),
+            current_working_directory,
+        )
+        .unwrap()
+        .pop()
+        .unwrap();
+        let mut db = Database::new();
+        let source_files = SourceFiles::read_all(&mut db, &config).unwrap();
+        create_and_validate_schema::<GraphQLNetworkProtocol>(&db, &source_files, &config).map(
+            |result| {
+                result
+                    .map(|(schema, _)| get_artifact_path_and_content(&schema, &config))
+                    .map_err(|error| {
+                        located_error_messages(error.as_ref())
+                            .into_iter()
+                            .map(format_located_error_message)
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                    })
+            },
+        )
+    }
+
+    /// Format a message as e.g. "ISO0814 at `Viewr` in isograph.config.json: ...".
+    fn format_located_error_message(message: LocatedErrorMessage) -> String {
+        let code = message
+            .code
+            .map(|code| code.to_string())
+            .unwrap_or_default();
+        let location = message
+            .location
+            .map(|location| {
+                let text_source = location.text_source;
+                let file_path = Path::new(text_source.current_working_directory.lookup())
+                    .join(text_source.relative_path_to_source_file.lookup());
+                let file_contents = fs::read_to_string(file_path).unwrap();
+                let text = match text_source.span {
+                    Some(span) => &file_contents[span.as_usize_range()],
+                    None => &file_contents,
+                };
+                format!(
+                    " at `{}` in {}",
+                    &text[location.span.as_usize_range()],
+                    text_source.relative_path_to_source_file
+                )
+            })
+            .unwrap_or_default();
+        format!("{code}{location}: {}", message.message)
+    }
+
+    /// The content of the artifact with the given file name, of the given field.
+    fn artifact_content<'a>(
+        artifacts: &'a [ArtifactPathAndContent],
+        type_name: &'static str,
+        field_name: &'static str,
+        file_name: &'static str,
+    ) -> &'a str {
+        &artifacts
+            .iter()
+            .find(|artifact| {
+                artifact.type_and_field.is_some_and(|type_and_field| {
+                    type_and_field.type_name == type_name && type_and_field.field_name == field_name
+                }) && artifact.file_name == file_name
+            })
+            .unwrap_or_else(|| {
+                panic!("Expected {type_name}/{field_name}/{file_name} to be generated")
+            })
+            .file_content
+    }
+
+    #[test]
+    fn refetch_reader_ast_reads_custom_id_field_from_id() {
+        let artifacts = generate_test_project_artifacts(
+            "custom_id_field",
+            r#"{ "id_fields": { "User": "uuid" } }"#,
+            "type Query { me: User! node(id: ID!): Node }\n\
+            interface Node { id: ID! }\n\
+            type User { uuid: ID! name: String! }\n",
+            "export const Name = iso(`field User.Name { name }`)(function Name() {});\n\
+            export const Home = iso(`field Query.Home { me { uuid, Name @loadable } }`)(\n\
+              function Home() {},\n\
+            );\n\
+            iso(`entrypoint Query.Home`);\n",
+        )
+        .item
+        .unwrap();
+
+        let reader_ast: String =
+            artifact_content(&artifacts, "Query", "Home", "resolver_reader.ts")
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .collect();
+        // Both the user-written uuid selection and the id selected by the refetch
+        // reader must be read from id, which is where the id is normalized.
+        assert!(
+            reader_ast.contains(r#"fieldName:"id",alias:"uuid","#),
+            "{reader_ast}"
+        );
+        assert!(
+            reader_ast.contains(r#"fieldName:"id",alias:"id","#),
+            "{reader_ast}"
+        );
+        assert!(!reader_ast.contains(r#"fieldName:"uuid""#), "{reader_ast}");
+    }
+
+    #[test]
+    fn refetch_from_root_types_are_refetched_via_the_root_path() {
+        let artifacts = generate_test_project_artifacts(
+            "refetch_from_root",
+            r#"{ "refetch_from_root": { "Settings": "viewer.settings" } }"#,
+            "type Query { viewer: Viewer! }\n\
+            type Viewer { settings: Settings! }\n\
+            type Settings { theme: String! }\n",
+            "export const Home = iso(`field Query.Home { viewer { settings { theme, __refetch } } }`)(\n\
+              function Home() {},\n\
+            );\n\
+            iso(`entrypoint Query.Home`);\n",
+        )
+        .item
+        .unwrap();
+
+        // The query text is compared without whitespace and line continuations
+        let refetch_query_text: String =
+            artifact_content(&artifacts, "Query", "Home", "__refetch__query_text__0.ts")
+                .chars()
+                .filter(|c| !c.is_whitespace() && *c != '\\')
+                .collect();
+        assert_eq!(
+            refetch_query_text,
+            "exportdefault'querySettings__refetch{\
+            viewer{settings{...onSettings{__typename,theme,},},},}';"
+        );
+    }
+
+    #[test]
+    fn subscription_entrypoints_are_fetched_as_subscriptions() {
+        let artifacts = generate_test_project_artifacts(
+            "subscription_entrypoint",
+            "{}",
+            "type Query { counter: Counter! }\n\
+            type Subscription { counter: Counter! }\n\
+            type Counter { count: Int! }\n",
+            "export const CounterUpdates = iso(`field Subscription.CounterUpdates { counter { count } }`)(\n\
+              function CounterUpdates() {},\n\
+            );\n\
+            iso(`entrypoint Subscription.CounterUpdates`);\n",
+        )
+        .item
+        .unwrap();
+
+        let query_text = artifact_content(
+            &artifacts,
+            "Subscription",
+            "CounterUpdates",
+            "query_text.ts",
+        );
+        assert!(
+            query_text.starts_with("export default 'subscription CounterUpdates "),
+            "{query_text}"
+        );
+        let entrypoint = artifact_content(
+            &artifacts,
+            "Subscription",
+            "CounterUpdates",
+            "entrypoint.ts",
+        );
+        assert!(
+            entrypoint.contains(r#"operationKind: "Subscription","#),
+            "{entrypoint}"
+        );
+    }
+
+    #[test]
+    fn deferred_loadable_fields_are_selected_in_the_parent_query() {
+        let artifacts = generate_test_project_artifacts(
+            "deferred_loadable_field",
+            "{}",
+            "type Query { me: User! node(id: ID!): Node }\n\
+            interface Node { id: ID! }\n\
+            type User implements Node { id: ID! name: String! bio: String! }\n",
+            "export const Bio = iso(`field User.Bio { bio }`)(function Bio() {});\n\
+            export const Home = iso(`field Query.Home { me { name, Bio @loadable(defer: true) } }`)(\n\
+              function Home() {},\n\
+            );\n\
+            iso(`entrypoint Query.Home`);\n",
+        )
+        .item
+        .unwrap();
+
+        let query_text: String = artifact_content(&artifacts, "Query", "Home", "query_text.ts")
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '\\')
+            .collect();
+        assert!(
+            query_text.contains(r#"...@defer(label:"User__Bio"){id,bio,}"#),
+            "{query_text}"
+        );
+
+        let normalization_ast: String =
+            artifact_content(&artifacts, "Query", "Home", "normalization_ast.ts")
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .collect();
+        assert!(
+            normalization_ast.contains(
+                r#"{kind:"Defer",label:"User__Bio",selections:[{kind:"Scalar",fieldName:"id",arguments:null,},{kind:"Scalar",fieldName:"bio",arguments:null,},],},"#
+            ),
+            "{normalization_ast}"
+        );
+
+        // The reader of the deferred field is loaded lazily, when its payload arrives
+        let entrypoint = artifact_content(&artifacts, "Query", "Home", "entrypoint.ts");
+        assert!(
+            entrypoint.contains(
+                r#""User__Bio": () => import("../../User/Bio/resolver_reader").then(module => module.default),"#
+            ),
+            "{entrypoint}"
+        );
+        assert!(!entrypoint.contains("import Bio"), "{entrypoint}");
+    }
+
+    #[test]
+    fn invalid_refetch_from_root_paths_are_reported_in_the_config_file() {
+        let Err(error) = generate_test_project_artifacts(
+            "refetch_from_root_errors",
+            r#"{ "refetch_from_root": {
+                "Viewr": "viewer",
+                "Viewer": "viewer",
+                "Account": "acount",
+                "User": "users",
+                "Team": "team",
+                "Profile": "viewer"
+            } }"#,
+            "type Query { viewer: Viewer! users: [User!]! team(id: ID!): Team account: Account }\n\
+            type Viewer @refetchFromRoot(path: \"viewer\") { name: String }\n\
+            type User { name: String }\n\
+            type Team { name: String }\n\
+            type Account { name: String }\n\
+            type Profile { name: String }\n",
+            "export const Home = iso(`field Query.Home { viewer { name } }`)(function Home() {});\n",
+        )
+        .item
+        else {
+            panic!("Expected refetch_from_root to be invalid");
+        };
+
+        for expected_error in [
+            "ISO0814 at `Viewr` in isograph.config.json: The refetch_from_root config option \
+            contains the type `Viewr`",
+            "ISO0815 at `Account` in isograph.config.json:",
+            "ISO0816 at `User` in isograph.config.json:",
+            "ISO0817 at `Team` in isograph.config.json:",
+            "ISO0818 at `Profile` in isograph.config.json:",
+            "ISO0819 at `Viewer` in isograph.config.json:",
+        ] {
+            assert!(
+                error.contains(expected_error),
+                "{expected_error}\n\n{error}"
+            );
+        }
+    }
+
+    const CONNECTION_SCHEMA: &str = "type Query { me: User! node(id: ID!): Node }\n\
+        interface Node { id: ID! }\n\
+        type User implements Node {\n\
+          id: ID!\n\
+          repositories(first: Int, after: String): RepositoryConnection!\n\
+          starredRepositories(first: Int, after: String): RepositoryConnection!\n\
+        }\n\
+        type RepositoryConnection { edges: [RepositoryEdge!]! pageInfo: PageInfo! }\n\
+        type RepositoryEdge { node: Repository! }\n\
+        type Repository { id: ID! name: String! }\n\
+        type PageInfo { endCursor: String hasNextPage: Boolean! }\n";
+
+    /// Generate the artifacts of a project in which User.RepositoryList, whose
+    /// selection set is given, is selected with @connection.
+    fn generate_connection_artifacts(
+        test_name: &str,
+        repository_list_selection_set: &str,
+    ) -> Result<Vec<ArtifactPathAndContent>, String> {
+        generate_test_project_artifacts(
+            test_name,
+            "{}",
+            CONNECTION_SCHEMA,
+            &format!(
+                "export const RepositoryList = iso(`\n\
+                  field User.RepositoryList($first: Int, $after: String) \
+                  {repository_list_selection_set}\n\
+                `)(function RepositoryList({{ data }}) {{ return data.repositories; }});\n\
+                export const Home = iso(`field Query.Home {{ me {{ RepositoryList @connection }} }}`)(\n\
+                  function Home() {{}},\n\
+                );\n\
+                iso(`entrypoint Query.Home`);\n"
+            ),
+        )
+        .item
+    }
+
+    #[test]
+    fn connection_refetch_query_is_keyed_on_the_cursor() {
+        let artifacts = generate_connection_artifacts(
+            "connection",
+            "{ repositories(first: $first, after: $after) {\n\
+              edges { node { name } }\n\
+              pageInfo { endCursor, hasNextPage }\n\
+            } }",
+        )
+        .unwrap();
+
+        let query_text = artifact_content(&artifacts, "User", "RepositoryList", "query_text.ts");
+        assert!(
+            query_text.contains("query RepositoryList ($first: Int, $after: String, $id: ID!)"),
+            "{query_text}"
+        );
+        assert!(
+            query_text.contains("repositories(first: $first, after: $after)"),
+            "{query_text}"
+        );
+
+        let normalization_ast: String =
+            artifact_content(&artifacts, "User", "RepositoryList", "normalization_ast.ts")
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .collect();
+        assert!(
+            normalization_ast.contains(
+                r#"fieldName:"repositories",arguments:[["first",{kind:"Variable",name:"first"},],["after",{kind:"Variable",name:"after"},],],concreteType:"RepositoryConnection","#
+            ),
+            "{normalization_ast}"
+        );
+    }
+
+    #[test]
+    fn invalid_connection_selections_are_reported() {
+        let Err(error) = generate_test_project_artifacts(
+            "connection_not_user_written",
+            "{}",
+            CONNECTION_SCHEMA,
+            "export const Home = iso(`field Query.Home { me { __refetch @connection } }`)(\n\
+              function Home() {},\n\
+            );\n",
+        )
+        .item
+        else {
+            panic!("Expected __refetch not to be selectable with @connection");
+        };
+        assert!(
+            error.contains(
+                "ISO0901 at `__refetch` in src/Home.tsx: `User.__refetch` cannot be selected \
+                with `@connection`."
+            ),
+            "{error}"
+        );
+
+        for (test_name, selection_set, expected_error) in [
+            (
+                "connection_no_connection_field",
+                "{ id }",
+                "ISO0902 at `RepositoryList` in src/Home.tsx: `User.RepositoryList` is selected \
+                with `@connection`, but it does not select a connection field.",
+            ),
+            (
+                "connection_two_connection_fields",
+                "{\n\
+                  repositories(first: $first, after: $after) {\n\
+                    edges { node { name } }\n\
+                    pageInfo { endCursor, hasNextPage }\n\
+                  }\n\
+                  starredRepositories(first: $first, after: $after) {\n\
+                    edges { node { name } }\n\
+                    pageInfo { endCursor, hasNextPage }\n\
+                  }\n\
+                }",
+                "ISO0903 at `starredRepositories` in src/Home.tsx: `User.RepositoryList` is \
+                selected with `@connection`, but it selects more than one connection field: \
+                `repositories` and `starredRepositories`.",
+            ),
+            (
+                "connection_literal_count",
+                "{ repositories(first: 10, after: $after) {\n\
+                  edges { node { name } }\n\
+                  pageInfo { endCursor, hasNextPage }\n\
+                } }",
+                "ISO0904 at `repositories` in src/Home.tsx: `User.RepositoryList` is selected \
+                with `@connection`, so the `first` argument of `repositories` must be passed \
+                a variable",
+            ),
+            (
+                "connection_missing_cursor_argument",
+                "{ repositories(first: $first) {\n\
+                  edges { node { name } }\n\
+                  pageInfo { endCursor, hasNextPage }\n\
+                } }",
+                "ISO0904 at `repositories` in src/Home.tsx: `User.RepositoryList` is selected \
+                with `@connection`, so the `after` argument of `repositories` must be passed \
+                a variable",
+            ),
+            (
+                "connection_missing_end_cursor",
+                "{ repositories(first: $first, after: $after) {\n\
+                  edges { node { name } }\n\
+                  pageInfo { hasNextPage }\n\
+                } }",
+                "ISO0905 at `repositories` in src/Home.tsx: `User.RepositoryList` is selected \
+                with `@connection`, so `repositories.pageInfo.endCursor` must be selected",
+            ),
+            (
+                "connection_missing_has_next_page",
+                "{ repositories(first: $first, after: $after) {\n\
+                  edges { node { name } }\n\
+                  pageInfo { endCursor }\n\
+                } }",
+                "ISO0905 at `repositories` in src/Home.tsx: `User.RepositoryList` is selected \
+                with `@connection`, so `repositories.pageInfo.hasNextPage` must be selected",
+            ),
+            (
+                "connection_missing_node",
+                "{ repositories(first: $first, after: $after) {\n\
+                  edges { __typename }\n\
+                  pageInfo { endCursor, hasNextPage }\n\
+                } }",
+                "ISO0905 at `repositories` in src/Home.tsx: `User.RepositoryList` is selected \
+                with `@connection`, so `repositories.edges.node` must be selected",
+            ),
+            // The pagination hook reads the end cursor by name, so it cannot be aliased
+            (
+                "connection_aliased_end_cursor",
+                "{ repositories(first: $first, after: $after) {\n\
+                  edges { node { name } }\n\
+                  pageInfo { cursor: endCursor, hasNextPage }\n\
+                } }",
+                "ISO0905 at `repositories` in src/Home.tsx: `User.RepositoryList` is selected \
+                with `@connection`, so `repositories.pageInfo.endCursor` must be selected, \
+                without an alias.",
+            ),
+        ] {
+            let Err(error) = generate_connection_artifacts(test_name, selection_set) else {
+                panic!("Expected {test_name} to be invalid");
+            };
+            assert!(
+                error.contains(expected_error),
+                "{expected_error}\n\n{error}"
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_custom_scalar_types_are_reported() {
+        let Err(error) = generate_test_project_artifacts(
+            "custom_scalar_types",
+            r#"{ "custom_scalar_types": { "DateTme": "string", "Role": "string" } }"#,
+            "type Query { createdAt: DateTime! role: Role! }\n\
+            scalar DateTime\n\
+            enum Role { ADMIN MEMBER }\n",
+            "export const Home = iso(`field Query.Home { createdAt }`)(function Home() {});\n",
+        )
+        .item
+        else {
+            panic!("Expected custom_scalar_types to be invalid");
+        };
+
+        assert!(
+            error.contains(
+                "The custom_scalar_types config option contains the scalar `DateTme`, \
+                but no scalar with that name exists. Did you mean `DateTime`?"
+            ),
+            "{error}"
+        );
+        assert!(
+            error.contains(
+                "The custom_scalar_types config option contains `Role`, which is an enum."
+            ),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn deprecated_field_selections_are_warnings_by_default() {
+        let schema = "type Query { name: String @deprecated(reason: \"Use fullName\") }\n";
+        let source = "export const Home = iso(`field Query.Home { name }`)(function Home() {});\n";
+
+        let result = generate_test_project_artifacts("deprecated_warning", "{}", schema, source);
+        assert!(result.item.is_ok());
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.warnings[0].item.code.to_string(), "ISO0701");
+        assert!(result.warnings[0].item.reports_deprecation);
+
+        let result = generate_test_project_artifacts(
+            "deprecated_error",
+            r#"{ "on_deprecated_field": "error" }"#,
+            schema,
+            source,
+        );
+        assert!(result.warnings.is_empty());
+        let Err(error) = result.item else {
+            panic!("Expected the deprecated field selection to be an error");
+        };
+        assert!(
+            error.contains("The field `Query.name` is deprecated. Reason: Use fullName"),
+            "{error}"
+        );
+    }
+
+    /// The paths (relative to the directory) and contents of the files in the directory.
+    fn read_files(directory: &Path) -> BTreeMap<PathBuf, String> {
+        let mut files = BTreeMap::new();
+        let mut directories = vec![directory.to_path_buf()];
+        while let Some(current_directory) = directories.pop() {
+            for entry in fs::read_dir(current_directory).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    directories.push(path);
+                } else {
+                    let content = fs::read_to_string(&path).unwrap();
+                    files.insert(path.strip_prefix(directory).unwrap().to_path_buf(), content);
+                }
+            }
+        }
+        files
+    }
+
+    #[test]
+    fn check_lists_out_of_date_artifacts_without_writing_them() {
+        let project_directory = TestProjectDirectory::new(
+            "check",
+            &[
+                (
+                    "isograph.config.json",
+                    r#"{ "project_root": "./src", "schema": "./schema.graphql" }"#,
+                ),
+                ("schema.graphql", "type Query { name: String }\n"),
+                (
+                    "src/Home.tsx",
+                    "export const Home = iso(`field Query.Home { name }`)(function Home() {});\n\
+                    iso(`entrypoint Query.Home`);\n",
+                ),
+            ],
+        );
+        let config_location = project_directory.join("isograph.config.json");
+        let current_working_directory: CurrentWorkingDirectory =
+            project_directory.path().to_str().unwrap().intern().into();
+        let artifact_directory = project_directory.join("src/__isograph");
+
+        compile_and_print::<GraphQLNetworkProtocol>(
+            config_location.clone(),
+            current_working_directory,
+            OutputFormat::Human,
+        )
+        .unwrap();
+        check_and_print::<GraphQLNetworkProtocol>(
+            config_location.clone(),
+            current_working_directory,
+            OutputFormat::Human,
+        )
+        .expect("Expected freshly generated artifacts to be up to date");
+
+        fs::remove_file(artifact_directory.join("Query/Home/entrypoint.ts")).unwrap();
+        fs::write(
+            artifact_directory.join("Query/Home/resolver_reader.ts"),
+            "// edited by hand\n",
+        )
+        .unwrap();
+        fs::write(artifact_directory.join("Query/Home/stale.ts"), "").unwrap();
+        let files_before_check = read_files(&artifact_directory);
+
+        // The CLI exits with a non-zero exit code if check_and_print returns an error.
+        let error = check_and_print::<GraphQLNetworkProtocol>(
+            config_location,
+            current_working_directory,
+            OutputFormat::Human,
+        )
+        .expect_err("Expected the artifacts to be out of date")
+        .to_string();
+
+        assert_eq!(
+            error,
+            "The generated artifacts are out of date. Run the Isograph compiler to update them.\n\n\
+            Artifacts that would be added:\n  src/__isograph/Query/Home/entrypoint.ts\n\n\
+            Artifacts that would be changed:\n  src/__isograph/Query/Home/resolver_reader.ts\n\n\
+            Files that would be deleted:\n  src/__isograph/Query/Home/stale.ts"
+        );
+        assert_eq!(read_files(&artifact_directory), files_before_check);
+    }
+}
@@ -1,16 +1,19 @@
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap, HashSet},
     error::Error,
     ops::{Deref, DerefMut},
 };
 
 use common_lang_types::{
-    CurrentWorkingDirectory, IsographObjectTypeName, Location, RelativePathToSourceFile,
-    SelectableName, TextSource, UnvalidatedTypeName, VariableName, WithLocation,
+    CurrentWorkingDirectory, DescriptionValue, DirectiveArgumentName, DirectiveName,
+    IsographObjectTypeName, Location, RelativePathToSourceFile, SelectableName, TextSource,
+    UnvalidatedTypeName, VariableName, WithLocation,
 };
 use graphql_lang_types::{
-    GraphQLFieldDefinition, GraphQLInputValueDefinition, NameValuePair, RootOperationKind,
+    GraphQLConstantValue, GraphQLDirective, GraphQLFieldDefinition, GraphQLInputValueDefinition,
+    NameValuePair, RootOperationKind,
 };
+use intern::string_key::Intern;
 use isograph_config::{CompilerConfig, CompilerConfigOptions};
 use isograph_lang_parser::IsoLiteralExtractionResult;
 use isograph_lang_types::{
@@ -18,11 +21,12 @@ use isograph_lang_types::{
     VariableDefinition,
 };
 use isograph_schema::{
-    validate_entrypoints, CreateAdditionalFieldsError, NetworkProtocol,
+    validate_entrypoints, ClientSelectableId, CreateAdditionalFieldsError, NetworkProtocol,
     ProcessObjectTypeDefinitionOutcome, ProcessTypeSystemDocumentOutcome, RootOperationName,
     Schema, SchemaServerObjectSelectableVariant, ServerObjectSelectable, ServerScalarSelectable,
     TypeRefinementMaps, UnprocessedItem,
 };
+use lazy_static::lazy_static;
 use pico::{Database, SourceId};
 
 use crate::{
@@ -33,11 +37,32 @@ use crate::{
     source_files::SourceFiles,
 };
 
+lazy_static! {
+    static ref DEPRECATED_DIRECTIVE: DirectiveName = "deprecated".intern().into();
+    static ref REASON_ARGUMENT: DirectiveArgumentName = "reason".intern().into();
+    static ref DEFAULT_DEPRECATION_REASON: DescriptionValue = "No longer supported".intern().into();
+}
+
+/// The schema, and the errors that were encountered when adding iso literals to it.
+/// Invalid iso literals are left out of the schema, so that later phases can still
+/// validate the rest of the schema and report their errors too.
+pub struct CreateSchemaOutcome<TNetworkProtocol: NetworkProtocol> {
+    pub schema: Schema<TNetworkProtocol>,
+    pub contains_iso_stats: ContainsIsoStats,
+    pub errors: Vec<BatchCompileError>,
+    /// Client fields and pointers whose selection sets are invalid, and which
+    /// therefore have empty selection sets in the schema.
+    pub client_selectables_with_invalid_selection_sets: HashSet<ClientSelectableId>,
+}
+
+/// Create the schema. Errors in the GraphQL schema prevent the schema from being
+/// created, and are returned as an Err. Errors in iso literals are returned in the
+/// outcome.
 pub fn create_schema<TNetworkProtocol: NetworkProtocol>(
     db: &Database,
     source_files: &SourceFiles,
     config: &CompilerConfig,
-) -> Result<(Schema<TNetworkProtocol>, ContainsIsoStats), Box<dyn Error>> {
+) -> Result<CreateSchemaOutcome<TNetworkProtocol>, Box<dyn Error>> {
     let ProcessTypeSystemDocumentOutcome {
         scalars,
         objects,
@@ -52,9 +77,9 @@ pub fn create_schema<TNetworkProtocol: NetworkProtocol>(
     for (server_scalar_entity, name_location) in scalars {
         unvalidated_isograph_schema
             .server_entity_data
-            .insert_server_scalar_entity(server_scalar_entity, name_location)?;
+            .insert_server_scalar_entity(server_scalar_entity, name_location)
+            .map_err(BatchCompileError::from)?;
     }
-
     let mut field_queue = HashMap::new();
     for (
         ProcessObjectTypeDefinitionOutcome {
@@ -68,7 +93,8 @@ pub fn create_schema<TNetworkProtocol: NetworkProtocol>(
     {
         let new_object_id = unvalidated_isograph_schema
             .server_entity_data
-            .insert_server_object_entity(server_object_entity, name_location)?;
+            .insert_server_object_entity(server_object_entity, name_location)
+            .map_err(BatchCompileError::from)?;
         field_queue.insert(new_object_id, fields_to_insert);
 
         unvalidated_isograph_schema
@@ -90,8 +116,12 @@ pub fn create_schema<TNetworkProtocol: NetworkProtocol>(
                     .fetchable_types
                     .insert(new_object_id, RootOperationName("mutation".to_string()));
             }
-            // TODO handle Subscription
-            _ => {}
+            Some(RootOperationKind::Subscription) => {
+                unvalidated_isograph_schema
+                    .fetchable_types
+                    .insert(new_object_id, RootOperationName("subscription".to_string()));
+            }
+            None => {}
         }
     }
 
@@ -99,18 +129,48 @@ pub fn create_schema<TNetworkProtocol: NetworkProtocol>(
         &mut unvalidated_isograph_schema,
         field_queue,
         &config.options,
-    )?;
+    )
+    .map_err(BatchCompileError::from)?;
 
     let type_refinement_map = get_type_refinement_map(
         &unvalidated_isograph_schema,
         unvalidated_supertype_to_subtype_map,
-    )?;
+    )
+    .map_err(BatchCompileError::from)?;
+
+    let mut errors = vec![];
+
+    if let Err(custom_scalar_type_errors) =
+        unvalidated_isograph_schema.add_custom_scalar_types(&config.options.custom_scalar_types)
+    {
+        errors.push(BatchCompileError::multiple_errors_with_locations(
+            custom_scalar_type_errors,
+        ));
+    }
+
+    // Client fields are refetched via the refetch field or via root paths, so we must
+    // know these before processing iso literals.
+    if let Err(refetch_field_errors) =
+        unvalidated_isograph_schema.add_refetch_field_options(&config.options)
+    {
+        errors.push(BatchCompileError::multiple_errors_with_locations(
+            refetch_field_errors,
+        ));
+    }
+    if let Err(refetch_root_path_errors) =
+        unvalidated_isograph_schema.add_refetch_root_paths(&config.options.refetch_from_root)
+    {
+        errors.push(BatchCompileError::multiple_errors_with_locations(
+            refetch_root_path_errors,
+        ));
+    }
 
     let contains_iso = parse_iso_literals(
         db,
         &source_files.iso_literals,
         config.current_working_directory,
-    )?;
+        &mut errors,
+    );
     let contains_iso_stats = contains_iso.stats();
 
     // Step one: we can create client selectables. However, we must create all
@@ -121,29 +181,35 @@ pub fn create_schema<TNetworkProtocol: NetworkProtocol>(
     let mut unprocessed_items = vec![];
 
     let (unprocessed_client_types, unprocessed_entrypoints) =
-        process_iso_literals(&mut unvalidated_isograph_schema, contains_iso)?;
+        process_iso_literals(&mut unvalidated_isograph_schema, contains_iso, &mut errors);
     unprocessed_items.extend(unprocessed_client_types);
 
-    unprocessed_items.extend(process_exposed_fields(&mut unvalidated_isograph_schema)?);
+    match process_exposed_fields(&mut unvalidated_isograph_schema) {
+        Ok(exposed_fields) => unprocessed_items.extend(exposed_fields),
+        Err(e) => errors.push(e),
+    }
 
-    unvalidated_isograph_schema.transfer_supertype_client_selectables_to_subtypes(
+    if let Err(e) = unvalidated_isograph_schema.transfer_supertype_client_selectables_to_subtypes(
         &type_refinement_map.supertype_to_subtype_map,
-    )?;
-    unvalidated_isograph_schema.add_link_fields()?;
-    unprocessed_items.extend(add_refetch_fields_to_objects(
-        &mut unvalidated_isograph_schema,
-    )?);
+    ) {
+        errors.push(e.into());
+    }
+    if let Err(e) = unvalidated_isograph_schema.add_link_fields() {
+        errors.push(e.into());
+    }
+    match add_refetch_fields_to_objects(&mut unvalidated_isograph_schema) {
+        Ok(refetch_fields) => unprocessed_items.extend(refetch_fields),
+        Err(e) => errors.push(e),
+    }
 
-    unvalidated_isograph_schema.entrypoints = validate_entrypoints(
-        &unvalidated_isograph_schema,
-        unprocessed_entrypoints,
-    )
-    .map_err(|e| BatchCompileError::MultipleErrorsWithLocations {
-        messages: e
-            .into_iter()
-            .map(|x| WithLocation::new(Box::new(x.item) as Box<dyn std::error::Error>, x.location))
-            .collect(),
-    })?;
+    let (entrypoints, entrypoint_errors) =
+        validate_entrypoints(&unvalidated_isograph_schema, unprocessed_entrypoints);
+    unvalidated_isograph_schema.entrypoints = entrypoints;
+    if !entrypoint_errors.is_empty() {
+        errors.push(BatchCompileError::multiple_errors_with_locations(
+            entrypoint_errors,
+        ));
+    }
 
     // Step two: now, we can create the selection sets. Creating a selection set involves
     // looking up client selectables, to:
@@ -152,43 +218,48 @@ pub fn create_schema<TNetworkProtocol: NetworkProtocol>(
     // - to validate arguments (e.g. no missing arguments, etc.)
     // - validate loadability/updatability, and
     // - to store the selectable id,
-    add_selection_sets_to_client_selectables(&mut unvalidated_isograph_schema, unprocessed_items)
-        .map_err(|messages| BatchCompileError::MultipleErrorsWithLocations {
-        messages: messages
-            .into_iter()
-            .map(|x| WithLocation::new(Box::new(x.item) as Box<dyn std::error::Error>, x.location))
-            .collect(),
-    })?;
+    let (client_selectables_with_invalid_selection_sets, selection_set_errors) =
+        add_selection_sets_to_client_selectables(
+            &mut unvalidated_isograph_schema,
+            unprocessed_items,
+        );
+    if !selection_set_errors.is_empty() {
+        errors.push(BatchCompileError::multiple_errors_with_locations(
+            selection_set_errors,
+        ));
+    }
 
-    Ok((unvalidated_isograph_schema, contains_iso_stats))
+    Ok(CreateSchemaOutcome {
+        schema: unvalidated_isograph_schema,
+        contains_iso_stats,
+        errors,
+        client_selectables_with_invalid_selection_sets,
+    })
 }
 
+/// Parse the iso literals in each file. Iso literals that cannot be parsed are
+/// skipped, and the errors are pushed onto `errors`.
 fn parse_iso_literals(
     db: &Database,
     iso_literals_sources: &HashMap<RelativePathToSourceFile, SourceId<IsoLiteralsSource>>,
     current_working_directory: CurrentWorkingDirectory,
-) -> Result<ContainsIso, BatchCompileError> {
+    errors: &mut Vec<BatchCompileError>,
+) -> ContainsIso {
     let mut contains_iso = ContainsIso::default();
     let mut iso_literal_parse_errors = vec![];
     for (relative_path, iso_literals_source_id) in iso_literals_sources.iter() {
-        match parse_iso_literal_in_source(db, *iso_literals_source_id, current_working_directory)
-            .to_owned()
-        {
-            Ok(iso_literals) => {
-                if !iso_literals.is_empty() {
-                    contains_iso.insert(*relative_path, iso_literals);
-                }
-            }
-            Err(e) => {
-                iso_literal_parse_errors.extend(e);
-            }
-        };
+        let (iso_literals, parse_errors) =
+            parse_iso_literal_in_source(db, *iso_literals_source_id, current_working_directory)
+                .to_owned();
+        if !iso_literals.is_empty() {
+            contains_iso.insert(*relative_path, iso_literals);
+        }
+        iso_literal_parse_errors.extend(parse_errors);
     }
-    if iso_literal_parse_errors.is_empty() {
-        Ok(contains_iso)
-    } else {
-        Err(iso_literal_parse_errors.into())
+    if !iso_literal_parse_errors.is_empty() {
+        errors.push(iso_literal_parse_errors.into());
     }
+    contains_iso
 }
 
 /// Here, we are processing exposeAs fields. Note that we only process these
@@ -313,6 +384,18 @@ fn process_field_queue<TNetworkProtocol: NetworkProtocol>(
                 })
                 .collect::<Result<Vec<_>, _>>()?;
             let description = field_definition.item.description.map(|d| d.item);
+            let argument_deprecation_reasons = field_definition
+                .item
+                .arguments
+                .iter()
+                .filter_map(|argument| {
+                    Some((
+                        argument.item.name.item.into(),
+                        deprecation_reason(&argument.item.directives)?,
+                    ))
+                })
+                .collect::<BTreeMap<_, _>>();
+            let deprecation_reason = deprecation_reason(&field_definition.item.directives);
 
             match selection_type {
                 SelectionType::Scalar(scalar_entity_id) => {
@@ -327,6 +410,8 @@ fn process_field_queue<TNetworkProtocol: NetworkProtocol>(
                                 .map(&mut |_| *scalar_entity_id),
                                 parent_object_entity_id,
                                 arguments,
+                                deprecation_reason,
+                                argument_deprecation_reasons,
                                 phantom_data: std::marker::PhantomData,
                             },
                             options,
@@ -345,6 +430,8 @@ fn process_field_queue<TNetworkProtocol: NetworkProtocol>(
                             .map(&mut |_| *object_entity_id),
                             parent_object_entity_id,
                             arguments,
+                            deprecation_reason,
+                            argument_deprecation_reasons,
                             phantom_data: std::marker::PhantomData,
                             object_selectable_variant:
                                 // TODO this is hacky
@@ -363,6 +450,25 @@ fn process_field_queue<TNetworkProtocol: NetworkProtocol>(
     Ok(())
 }
 
+/// The reason given by the @deprecated directive, if present. Per the GraphQL spec,
+/// the reason defaults to "No longer supported".
+fn deprecation_reason(
+    directives: &[GraphQLDirective<GraphQLConstantValue>],
+) -> Option<DescriptionValue> {
+    let deprecated_directive = directives
+        .iter()
+        .find(|directive| directive.name.item == *DEPRECATED_DIRECTIVE)?;
+    Some(
+        deprecated_directive
+            .arguments
+            .iter()
+            .find(|argument| argument.name.item == *REASON_ARGUMENT)
+            .and_then(|argument| argument.value.item.as_string())
+            .map(|reason| reason.unchecked_conversion())
+            .unwrap_or(*DEFAULT_DEPRECATION_REASON),
+    )
+}
+
 pub fn graphql_input_value_definition_to_variable_definition(
     defined_types: &HashMap<UnvalidatedTypeName, ServerEntityId>,
     input_value_definition: WithLocation<GraphQLInputValueDefinition>,
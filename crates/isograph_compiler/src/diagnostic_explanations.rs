@@ -0,0 +1,105 @@
+use common_lang_types::DiagnosticCode;
+
+macro_rules! diagnostic_explanations {
+    ($($code:ident),* $(,)?) => {
+        /// The long-form explanation of each diagnostic code, with an erroneous and a
+        /// fixed example. Each explanation is a markdown file in the
+        /// diagnostic_explanations folder.
+        const DIAGNOSTIC_EXPLANATIONS: &[(&str, &str)] = &[
+            $((
+                stringify!($code),
+                include_str!(concat!("diagnostic_explanations/", stringify!($code), ".md")),
+            )),*
+        ];
+    };
+}
+
+diagnostic_explanations! {
+    // Errors when parsing iso literals
+    ISO0101, ISO0102, ISO0103, ISO0104, ISO0105, ISO0106, ISO0107, ISO0108, ISO0109, ISO0110,
+    ISO0111, ISO0112, ISO0113, ISO0114, ISO0115, ISO0116,
+    // Errors when processing client field and client pointer declarations
+    ISO0201, ISO0202, ISO0203, ISO0204, ISO0205, ISO0206, ISO0207, ISO0208,
+    // Errors when validating entrypoints
+    ISO0301, ISO0302, ISO0303, ISO0304, ISO0305,
+    // Errors when processing selections
+    ISO0401, ISO0402, ISO0403, ISO0404, ISO0405,
+    // Errors when validating the use of arguments
+    ISO0501, ISO0502, ISO0503,
+    // Errors when validating the types of arguments
+    ISO0601, ISO0602, ISO0603, ISO0604, ISO0605, ISO0606, ISO0607, ISO0608, ISO0609, ISO0610,
+    // Uses of deprecated fields and arguments
+    ISO0701, ISO0702,
+    // Errors when processing the schema
+    ISO0801, ISO0802, ISO0803, ISO0804, ISO0805, ISO0806, ISO0807, ISO0808, ISO0809, ISO0810,
+    ISO0811, ISO0812, ISO0813, ISO0814, ISO0815, ISO0816, ISO0817, ISO0818, ISO0819, ISO0820,
+    ISO0821, ISO0822, ISO0823, ISO0824, ISO0825, ISO0826, ISO0827, ISO0828, ISO0829,
+    // Errors when validating fields selected with @connection
+    ISO0901, ISO0902, ISO0903, ISO0904, ISO0905,
+    // Errors when reading the config file
+    ISO1001, ISO1002, ISO1003, ISO1004, ISO1005, ISO1006, ISO1007, ISO1008,
+    // Errors when reading the schema and source files
+    ISO1101, ISO1102, ISO1103, ISO1104, ISO1105, ISO1106, ISO1107, ISO1108,
+    // Errors when writing artifacts
+    ISO1201, ISO1202, ISO1203, ISO1204, ISO1205, ISO1206,
+}
+
+/// Returns the long-form explanation of a diagnostic code, if there is a diagnostic
+/// with that code.
+pub fn diagnostic_explanation(code: DiagnosticCode) -> Option<&'static str> {
+    let code = code.to_string();
+    DIAGNOSTIC_EXPLANATIONS
+        .iter()
+        .find(|(explained_code, _)| *explained_code == code)
+        .map(|(_, explanation)| *explanation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_explanation_file_is_listed_once_and_has_examples() {
+        let listed_codes = DIAGNOSTIC_EXPLANATIONS
+            .iter()
+            .map(|(code, _)| code.to_string())
+            .collect::<Vec<_>>();
+        let mut sorted_codes = listed_codes.clone();
+        sorted_codes.sort();
+        sorted_codes.dedup();
+        assert_eq!(listed_codes, sorted_codes);
+
+        let explanations_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src")
+            .join("diagnostic_explanations");
+        let mut file_codes = std::fs::read_dir(explanations_dir)
+            .expect("Expected explanations directory to exist")
+            .map(|entry| {
+                entry
+                    .expect("Expected entry to be readable")
+                    .path()
+                    .file_stem()
+                    .expect("Expected file to have a name")
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+        file_codes.sort();
+        assert_eq!(listed_codes, file_codes);
+
+        for (code, explanation) in DIAGNOSTIC_EXPLANATIONS {
+            let parsed_code: DiagnosticCode = code.parse().expect("Expected a valid code");
+            assert_eq!(parsed_code.to_string(), *code);
+            assert!(explanation.contains("Erroneous example:"), "{code}");
+            assert!(explanation.contains("Fixed example:"), "{code}");
+        }
+    }
+
+    #[test]
+    fn explanations_are_found_by_code() {
+        assert!(diagnostic_explanation(DiagnosticCode(201))
+            .expect("Expected ISO0201 to be explained")
+            .contains("not defined in the\nschema"));
+        assert_eq!(diagnostic_explanation(DiagnosticCode(9999)), None);
+    }
+}
@@ -0,0 +1,240 @@
+use std::path::PathBuf;
+
+use common_lang_types::{EmbeddedLocation, Severity, Span, WithLocation};
+use intern::Lookup;
+use serde::Serialize;
+
+use crate::batch_compile::{
+    located_error_messages, located_warning_messages, ErrorWithDiagnosticCode, LocatedErrorMessage,
+};
+
+/// How the compiler reports the result of a compilation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Colored, human-readable messages, which are logged to stderr.
+    #[default]
+    Human,
+    /// A JSON object per compilation, on a single line, which is printed to stdout.
+    Json,
+}
+
+/// The result of compiling (or checking) a project, or of reading the config file,
+/// as printed when the output format is json.
+#[derive(Debug, Serialize)]
+pub struct JsonCompilationResult {
+    /// The label of the project, if the config file contains multiple projects.
+    pub project: Option<String>,
+    pub success: bool,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DiagnosticSeverity {
+    Error,
+    Warning,
+}
+
+impl From<Severity> for DiagnosticSeverity {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::Error => DiagnosticSeverity::Error,
+            Severity::Warning => DiagnosticSeverity::Warning,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: DiagnosticSeverity,
+    /// The diagnostic code, e.g. ISO0201, if the error has one.
+    pub code: Option<String>,
+    pub message: String,
+    /// The path of the file, relative to the current working directory. None if the
+    /// error does not refer to a location in a file.
+    pub file: Option<String>,
+    pub start: Option<LineAndColumn>,
+    /// The position immediately after the end of the location.
+    pub end: Option<LineAndColumn>,
+    /// A replacement for the text between start and end, e.g. the name of an existing
+    /// field if the selected field does not exist.
+    pub suggestion: Option<String>,
+}
+
+/// A position in a file. Both the line and the column are 1-based, and the column
+/// is counted in characters.
+#[derive(Debug, Serialize, PartialEq, Eq, Clone, Copy)]
+pub struct LineAndColumn {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl JsonCompilationResult {
+    /// The diagnostics contain the errors, if compilation failed, followed by the
+    /// warnings. Warnings do not cause compilation to fail.
+    pub fn new(
+        result: Result<(), &(dyn std::error::Error + 'static)>,
+        warnings: &[WithLocation<ErrorWithDiagnosticCode>],
+        label: Option<&str>,
+    ) -> Self {
+        let errors = match result {
+            Ok(()) => vec![],
+            Err(err) => located_error_messages(err),
+        };
+        JsonCompilationResult {
+            project: label.map(str::to_string),
+            success: result.is_ok(),
+            diagnostics: errors
+                .into_iter()
+                .chain(located_warning_messages(warnings))
+                .map(Diagnostic::from)
+                .collect(),
+        }
+    }
+
+    pub fn print(&self) {
+        println!(
+            "{}",
+            serde_json::to_string(self).expect("Expected diagnostics to be serializable")
+        );
+    }
+}
+
+impl From<LocatedErrorMessage> for Diagnostic {
+    fn from(located_error_message: LocatedErrorMessage) -> Self {
+        let LocatedErrorMessage {
+            message,
+            severity,
+            location,
+            code,
+            suggestion,
+            reports_deprecation: _,
+        } = located_error_message;
+        let (file, start, end) = match location {
+            Some(location) => {
+                let (file, start, end) = file_and_range(location);
+                (Some(file), start, end)
+            }
+            None => (None, None, None),
+        };
+        Diagnostic {
+            severity: severity.into(),
+            code: code.map(|code| code.to_string()),
+            message,
+            file,
+            start,
+            end,
+            suggestion,
+        }
+    }
+}
+
+/// Returns the path of the file containing the location, and the start and end of the
+/// location within it. The start and end are None if the file cannot be read.
+fn file_and_range(
+    location: EmbeddedLocation,
+) -> (String, Option<LineAndColumn>, Option<LineAndColumn>) {
+    let text_source = location.text_source;
+    let relative_path = text_source.relative_path_to_source_file.lookup();
+    let absolute_path =
+        PathBuf::from(text_source.current_working_directory.lookup()).join(relative_path);
+
+    // The span of the location is relative to the span of the text source (e.g. the
+    // iso literal), not to the start of the file.
+    let offset = text_source.span.map(|span| span.start).unwrap_or(0);
+    let span = Span::new(location.span.start + offset, location.span.end + offset);
+
+    match std::fs::read_to_string(absolute_path) {
+        Ok(file_contents) => (
+            relative_path.to_string(),
+            Some(line_and_column(&file_contents, span.start as usize)),
+            Some(line_and_column(&file_contents, span.end as usize)),
+        ),
+        Err(_) => (relative_path.to_string(), None, None),
+    }
+}
+
+fn line_and_column(text: &str, index: usize) -> LineAndColumn {
+    let mut index = index.min(text.len());
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    let before = &text[..index];
+    let line_start = before.rfind('\n').map(|x| x + 1).unwrap_or(0);
+    LineAndColumn {
+        line: before.matches('\n').count() + 1,
+        column: before[line_start..].chars().count() + 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_lang_types::Location;
+    use intern::string_key::Intern;
+    use isograph_config::ConfigError;
+
+    use super::*;
+    use crate::batch_compile::BatchCompileError;
+
+    #[test]
+    fn line_and_column_are_one_based_and_count_characters() {
+        let text = "first\nsé|cond\n";
+        assert_eq!(
+            line_and_column(text, 0),
+            LineAndColumn { line: 1, column: 1 }
+        );
+        assert_eq!(
+            line_and_column(text, 6),
+            LineAndColumn { line: 2, column: 1 }
+        );
+        assert_eq!(
+            line_and_column(text, text.find('|').unwrap()),
+            LineAndColumn { line: 2, column: 3 }
+        );
+        assert_eq!(
+            line_and_column(text, text.len()),
+            LineAndColumn { line: 3, column: 1 }
+        );
+    }
+
+    #[test]
+    fn warnings_are_reported_without_failing() {
+        let warning = WithLocation::new(
+            ErrorWithDiagnosticCode::new(
+                isograph_schema::ValidateUseOfDeprecatedFieldsError::DeprecatedFieldSelected {
+                    parent_type: "Query".intern().into(),
+                    field_name: "name".intern().into(),
+                    reason: "Use fullName".intern().into(),
+                },
+            ),
+            Location::generated(),
+        );
+        let result = JsonCompilationResult::new(Ok(()), &[warning], None);
+        let json = serde_json::to_value(&result).unwrap();
+
+        assert_eq!(json["success"], true);
+        assert_eq!(json["diagnostics"][0]["severity"], "warning");
+        assert_eq!(json["diagnostics"][0]["code"], "ISO0701");
+    }
+
+    #[test]
+    fn errors_without_a_location_have_a_code() {
+        let error = BatchCompileError::MultipleErrors {
+            messages: vec![
+                Box::new(BatchCompileError::SchemaNotFound),
+                Box::new(ConfigError::NoProjects),
+            ],
+        };
+        let result = JsonCompilationResult::new(Err(&error), &[], None);
+        let json = serde_json::to_value(&result).unwrap();
+
+        assert_eq!(json["success"], false);
+        assert_eq!(json["diagnostics"][0]["code"], "ISO1102");
+        assert_eq!(json["diagnostics"][1]["code"], "ISO1004");
+        assert_eq!(
+            error.to_string(),
+            "\n\nISO1102: Schema file not found. Cannot proceed without a schema.\
+            \n\nISO1004: Expected config.projects to contain at least one project."
+        );
+    }
+}
@@ -91,17 +91,17 @@ fn visit_dirs_skipping_isograph(dir: &Path, cb: &mut dyn FnMut(&DirEntry)) -> io
     Ok(())
 }
 
-// TODO this should return a Vec of Results, since a file can contain
-// both valid and invalid iso literals.
+/// Parse each iso literal in the file. A file can contain both valid and invalid iso
+/// literals, so the iso literals that were parsed are returned alongside the errors.
 #[allow(clippy::type_complexity)]
-pub fn parse_iso_literals_in_file_content(
+pub fn parse_each_iso_literal_in_file_content(
     relative_path_to_source_file: RelativePathToSourceFile,
     file_content: &str,
     current_working_directory: CurrentWorkingDirectory,
-) -> Result<
+) -> (
     Vec<(IsoLiteralExtractionResult, TextSource)>,
     Vec<WithLocation<IsographLiteralParseError>>,
-> {
+) {
     let mut extraction_results = vec![];
     let mut isograph_literal_parse_errors = vec![];
 
@@ -116,6 +116,25 @@ pub fn parse_iso_literals_in_file_content(
         }
     }
 
+    (extraction_results, isograph_literal_parse_errors)
+}
+
+#[allow(clippy::type_complexity)]
+pub fn parse_iso_literals_in_file_content(
+    relative_path_to_source_file: RelativePathToSourceFile,
+    file_content: &str,
+    current_working_directory: CurrentWorkingDirectory,
+) -> Result<
+    Vec<(IsoLiteralExtractionResult, TextSource)>,
+    Vec<WithLocation<IsographLiteralParseError>>,
+> {
+    let (extraction_results, isograph_literal_parse_errors) =
+        parse_each_iso_literal_in_file_content(
+            relative_path_to_source_file,
+            file_content,
+            current_working_directory,
+        );
+
     if isograph_literal_parse_errors.is_empty() {
         Ok(extraction_results)
     } else {
@@ -129,29 +148,30 @@ pub fn parse_iso_literal_in_source(
     db: &Database,
     iso_literals_source_id: SourceId<IsoLiteralsSource>,
     current_working_directory: CurrentWorkingDirectory,
-) -> Result<
+) -> (
     Vec<(IsoLiteralExtractionResult, TextSource)>,
     Vec<WithLocation<IsographLiteralParseError>>,
-> {
+) {
     let IsoLiteralsSource {
         relative_path,
         content,
     } = db.get(iso_literals_source_id);
-    parse_iso_literals_in_file_content(*relative_path, content, current_working_directory)
+    parse_each_iso_literal_in_file_content(*relative_path, content, current_working_directory)
 }
 
+/// Add the client fields and client pointers declared in iso literals to the schema.
+/// Client fields and pointers whose declarations are invalid are skipped, and the
+/// errors are pushed onto `errors`.
 #[allow(clippy::type_complexity)]
 pub(crate) fn process_iso_literals<TNetworkProtocol: NetworkProtocol>(
     schema: &mut Schema<TNetworkProtocol>,
     contains_iso: ContainsIso,
-) -> Result<
-    (
-        Vec<UnprocessedItem>,
-        Vec<(TextSource, WithSpan<EntrypointDeclaration>)>,
-    ),
-    BatchCompileError,
-> {
-    let mut errors = vec![];
+    errors: &mut Vec<BatchCompileError>,
+) -> (
+    Vec<UnprocessedItem>,
+    Vec<(TextSource, WithSpan<EntrypointDeclaration>)>,
+) {
+    let mut declaration_errors = vec![];
     let mut unprocess_client_field_items = vec![];
     let mut unprocessed_entrypoints = vec![];
     for iso_literals in contains_iso.files.into_values() {
@@ -164,7 +184,7 @@ pub(crate) fn process_iso_literals<TNetworkProtocol: NetworkProtocol>(
                         Ok(unprocessed_client_field_items) => unprocess_client_field_items
                             .push(SelectionType::Scalar(unprocessed_client_field_items)),
                         Err(e) => {
-                            errors.push(e);
+                            declaration_errors.push(e);
                         }
                     }
                 }
@@ -177,7 +197,7 @@ pub(crate) fn process_iso_literals<TNetworkProtocol: NetworkProtocol>(
                         Ok(unprocessed_client_pointer_item) => unprocess_client_field_items
                             .push(SelectionType::Object(unprocessed_client_pointer_item)),
                         Err(e) => {
-                            errors.push(e);
+                            declaration_errors.push(e);
                         }
                     }
                 }
@@ -188,11 +208,10 @@ pub(crate) fn process_iso_literals<TNetworkProtocol: NetworkProtocol>(
             }
         }
     }
-    if errors.is_empty() {
-        Ok((unprocess_client_field_items, unprocessed_entrypoints))
-    } else {
-        Err(errors.into())
+    if !declaration_errors.is_empty() {
+        errors.push(declaration_errors.into());
     }
+    (unprocess_client_field_items, unprocessed_entrypoints)
 }
 
 pub fn process_iso_literal_extraction(
@@ -266,7 +285,7 @@ pub struct IsoLiteralExtraction<'a> {
 
 pub fn extract_iso_literals_from_file_content(
     content: &str,
-) -> impl Iterator<Item = IsoLiteralExtraction> + '_ {
+) -> impl Iterator<Item = IsoLiteralExtraction<'_>> + '_ {
     EXTRACT_ISO_LITERAL
         .captures_iter(content)
         .flat_map(|captures| {
@@ -285,3 +304,25 @@ pub fn extract_iso_literals_from_file_content(
             })
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use intern::string_key::Intern;
+
+    use super::*;
+
+    #[test]
+    fn valid_iso_literals_are_parsed_alongside_invalid_ones() {
+        let file_content = "
+            export const Broken = iso(`field Query.Broken { pets { id }`)(function A() {});
+            export const Valid = iso(`field Pet.Valid { name }`)(function B() {});
+        ";
+        let (extraction_results, errors) = parse_each_iso_literal_in_file_content(
+            "src/components/Example.tsx".intern().into(),
+            file_content,
+            "/project".intern().into(),
+        );
+        assert_eq!(extraction_results.len(), 1);
+        assert_eq!(errors.len(), 1);
+    }
+}
@@ -2,16 +2,34 @@ mod add_selection_sets;
 pub mod batch_compile;
 mod compiler_state;
 mod create_schema;
+mod diagnostic_explanations;
+mod diagnostics;
 mod isograph_literals;
 mod refetch_fields;
 mod source_files;
 pub mod watch;
 mod with_duration;
+mod with_warnings;
 mod write_artifacts;
 
-pub use batch_compile::compile_and_print;
+pub use batch_compile::{
+    check_and_print, compile_and_print, located_error_messages, located_warning_messages,
+    print_config_error, ErrorWithDiagnosticCode, LocatedErrorMessage,
+};
+pub use compiler_state::{
+    create_and_validate_schema, CompilerState, CreateAndValidateSchemaResult, ProjectState,
+    ProjectsCompilerState,
+};
+pub use create_schema::{create_schema, CreateSchemaOutcome};
+pub use diagnostic_explanations::diagnostic_explanation;
+pub use diagnostics::{
+    Diagnostic, DiagnosticSeverity, JsonCompilationResult, LineAndColumn, OutputFormat,
+};
 pub use isograph_literals::{
-    extract_iso_literals_from_file_content, parse_iso_literals_in_file_content,
-    IsoLiteralExtraction,
+    extract_iso_literals_from_file_content, parse_each_iso_literal_in_file_content,
+    parse_iso_literal_in_source, parse_iso_literals_in_file_content,
+    process_iso_literal_extraction, IsoLiteralExtraction,
 };
+pub use source_files::SourceFiles;
 pub use watch::handle_watch_command;
+pub use with_warnings::WithWarnings;
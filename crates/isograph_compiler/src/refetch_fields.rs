@@ -4,11 +4,9 @@ use common_lang_types::ObjectTypeAndFieldName;
 use intern::string_key::Intern;
 use isograph_lang_types::{DefinitionLocation, SelectionType, WithId};
 use isograph_schema::{
-    generate_refetch_field_strategy, id_arguments, id_selection, id_top_level_arguments,
-    imperative_field_subfields_or_inline_fragments, ClientFieldVariant, ClientScalarSelectable,
-    ImperativelyLoadedFieldVariant, NetworkProtocol, RefetchStrategy, Schema,
-    UnprocessedClientFieldItem, UnprocessedItem, WrappedSelectionMapSelection, NODE_FIELD_NAME,
-    REFETCH_FIELD_NAME,
+    generate_refetch_field_strategy, id_arguments, id_selection, ClientFieldVariant,
+    ClientScalarSelectable, ImperativelyLoadedFieldVariant, NetworkProtocol, RefetchStrategy,
+    Schema, UnprocessedClientFieldItem, UnprocessedItem, REFETCH_FIELD_NAME,
 };
 
 use crate::batch_compile::BatchCompileError;
@@ -40,8 +38,18 @@ pub fn add_refetch_fields_to_objects<TNetworkProtocol: NetworkProtocol>(
                         in server_object_entity_available_selectables",
                     )
                     .1;
-                if id_field.is_some() {
-                    Some((object_entity_id, object.name))
+                // Objects with a root path are refetched via that path, even if
+                // they have an id field.
+                let refetch_root_path = schema.refetch_root_paths.get(&object_entity_id);
+                if refetch_root_path.is_some() || id_field.is_some() {
+                    Some((
+                        object_entity_id,
+                        object.name,
+                        refetch_root_path.cloned(),
+                        id_field.map(|id_field| {
+                            schema.server_scalar_selectable(id_field.into()).name.item
+                        }),
+                    ))
                 } else {
                     None
                 }
@@ -49,7 +57,7 @@ pub fn add_refetch_fields_to_objects<TNetworkProtocol: NetworkProtocol>(
         )
         .collect::<Vec<_>>();
 
-    for (object_entity_id, object_name) in items {
+    for (object_entity_id, object_name, refetch_root_path, id_field_name) in items {
         let schema: &mut Schema<TNetworkProtocol> = schema;
         let result = match schema
             .server_entity_data
@@ -67,12 +75,37 @@ pub fn add_refetch_fields_to_objects<TNetworkProtocol: NetworkProtocol>(
                     next_client_field_id,
                 )));
 
-                let subfields_or_inline_fragments = imperative_field_subfields_or_inline_fragments(
-                    *NODE_FIELD_NAME,
-                    &id_arguments(id_type_id),
-                    None,
-                    &None,
-                );
+                let (variant, refetch_strategy) = match refetch_root_path {
+                    Some(refetch_root_path) => (
+                        ImperativelyLoadedFieldVariant {
+                            top_level_schema_field_arguments: vec![],
+                            primary_field_info: None,
+
+                            root_object_entity_id: refetch_root_path.root_fetchable_type,
+                            subfields_or_inline_fragments: refetch_root_path.path.clone(),
+                        },
+                        RefetchStrategy::RefetchFromRoot(refetch_root_path),
+                    ),
+                    None => (
+                        ImperativelyLoadedFieldVariant {
+                            top_level_schema_field_arguments: id_arguments(id_type_id),
+                            primary_field_info: None,
+
+                            root_object_entity_id: query_id,
+                            subfields_or_inline_fragments: vec![
+                                schema.refetch_field_selection(object_name)
+                            ],
+                        },
+                        RefetchStrategy::UseRefetchField(generate_refetch_field_strategy(
+                            vec![id_selection(id_field_name.expect(
+                                "Expected id field to exist. \
+                                This is indicative of a bug in Isograph.",
+                            ))],
+                            query_id,
+                            schema.refetch_field_subfields(object_name),
+                        )),
+                    ),
+                };
 
                 schema
                     .client_scalar_selectables
@@ -84,15 +117,7 @@ pub fn add_refetch_fields_to_objects<TNetworkProtocol: NetworkProtocol>(
                         ),
                         name: *REFETCH_FIELD_NAME,
                         reader_selection_set: vec![],
-                        variant: ClientFieldVariant::ImperativelyLoadedField(
-                            ImperativelyLoadedFieldVariant {
-                                top_level_schema_field_arguments: id_arguments(id_type_id),
-                                primary_field_info: None,
-
-                                root_object_entity_id: query_id,
-                                subfields_or_inline_fragments,
-                            },
-                        ),
+                        variant: ClientFieldVariant::ImperativelyLoadedField(variant),
                         variable_definitions: vec![],
                         type_and_field: ObjectTypeAndFieldName {
                             type_name: object_name,
@@ -103,20 +128,6 @@ pub fn add_refetch_fields_to_objects<TNetworkProtocol: NetworkProtocol>(
                         output_format: std::marker::PhantomData,
                     });
 
-                let refetch_strategy =
-                    RefetchStrategy::UseRefetchField(generate_refetch_field_strategy(
-                        vec![id_selection()],
-                        query_id,
-                        vec![
-                            WrappedSelectionMapSelection::InlineFragment(object_name),
-                            WrappedSelectionMapSelection::LinkedField {
-                                server_object_selectable_name: *NODE_FIELD_NAME,
-                                arguments: id_top_level_arguments(),
-                                concrete_type: None,
-                            },
-                        ],
-                    ));
-
                 Ok(UnprocessedClientFieldItem {
                     client_field_id: next_client_field_id,
                     reader_selection_set: vec![],
@@ -47,11 +47,6 @@ impl SourceFiles {
         let errors = changes
             .iter()
             .filter_map(|(event, change_kind)| match change_kind {
-                ChangedFileKind::Config => {
-                    panic!(
-                        "Unexpected config file change. This is indicative of a bug in Isograph."
-                    );
-                }
                 ChangedFileKind::Schema => self.handle_update_schema(db, config, event).err(),
                 ChangedFileKind::SchemaExtension => self
                     .handle_update_schema_extensions(db, config, event)
@@ -187,6 +182,47 @@ impl SourceFiles {
         Ok(())
     }
 
+    /// Replace the contents of a source file that may contain iso literals. This is
+    /// used by the language server, so that the unsaved contents of open documents
+    /// are compiled.
+    pub fn set_iso_literals_content(
+        &mut self,
+        db: &mut Database,
+        relative_path: RelativePathToSourceFile,
+        content: String,
+    ) {
+        let source_id = db.set(IsoLiteralsSource {
+            relative_path,
+            content,
+        });
+        self.iso_literals.insert(relative_path, source_id);
+    }
+
+    /// Replace the contents of the schema, or of a schema extension. This is used by
+    /// the language server, so that the unsaved contents of open documents are compiled.
+    pub fn set_schema_content(
+        &mut self,
+        db: &mut Database,
+        config: &CompilerConfig,
+        relative_path: RelativePathToSourceFile,
+        content: String,
+    ) {
+        let source_id = db.set(SchemaSource {
+            relative_path,
+            content,
+            text_source: TextSource {
+                relative_path_to_source_file: relative_path,
+                span: None,
+                current_working_directory: config.current_working_directory,
+            },
+        });
+        if relative_path == config.schema.relative_path {
+            self.schema = source_id;
+        } else {
+            self.schema_extensions.insert(relative_path, source_id);
+        }
+    }
+
     fn handle_update_source_folder(
         &mut self,
         db: &mut Database,
@@ -9,14 +9,18 @@ use notify::{
 use notify_debouncer_full::{
     new_debouncer, DebounceEventResult, DebouncedEvent, Debouncer, RecommendedCache,
 };
-use std::{path::PathBuf, time::Duration};
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    time::Duration,
+};
 use tokio::{runtime::Handle, sync::mpsc::Receiver};
 use tracing::info;
 
 use crate::{
-    batch_compile::print_result,
-    compiler_state::{compile, CompilerState},
-    source_files::SourceFiles,
+    batch_compile::{print_config_error, print_result},
+    compiler_state::ProjectsCompilerState,
+    diagnostics::OutputFormat,
     with_duration::WithDuration,
 };
 
@@ -25,84 +29,72 @@ const MAX_CHANGED_FILES: usize = 100;
 pub async fn handle_watch_command<TNetworkProtocol: NetworkProtocol>(
     config_location: PathBuf,
     current_working_directory: CurrentWorkingDirectory,
+    output_format: OutputFormat,
 ) -> Result<(), Vec<Error>> {
-    let mut state = CompilerState::new(config_location, current_working_directory);
-    let (mut rx, mut watcher) = create_debounced_file_watcher(&state.config);
+    let config_location = canonicalize_config_location(config_location);
 
     info!("{}", "Starting to compile.".cyan());
-    let _ = print_result(WithDuration::new(|| {
-        let source_files = SourceFiles::read_all(&mut state.db, &state.config)?;
-        let result = compile::<TNetworkProtocol>(&state.db, &source_files, &state.config);
-        state.source_files = Some(source_files);
-        result
-    }));
+    // If the config is invalid, we report the error and wait for the config to be fixed,
+    // instead of exiting.
+    let mut state = create_state_and_compile::<TNetworkProtocol>(
+        &config_location,
+        current_working_directory,
+        output_format,
+    );
+    let (mut rx, mut watcher) = create_debounced_file_watcher(&config_location, state.as_ref());
 
     while let Some(res) = rx.recv().await {
         match res {
             Ok(events) => {
-                if let Some(changes) = categorize_and_filter_events(&events, &state.config) {
-                    let result = if has_config_changes(&changes) {
-                        info!(
-                            "{}",
-                            "Config change detected. Starting a full compilation.".cyan()
-                        );
-                        state = CompilerState::new(
-                            state.config.config_location,
-                            current_working_directory,
-                        );
-                        watcher.stop();
-                        (rx, watcher) = create_debounced_file_watcher(&state.config);
-                        WithDuration::new(|| {
-                            let source_files = SourceFiles::read_all(&mut state.db, &state.config)?;
-                            let result = compile::<TNetworkProtocol>(
-                                &state.db,
-                                &source_files,
-                                &state.config,
-                            );
-                            state.source_files = Some(source_files);
-                            result
-                        })
-                    } else if changes.len() < MAX_CHANGED_FILES {
-                        info!("{}", "File changes detected. Starting to compile.".cyan());
-                        WithDuration::new(|| {
-                            if let Some(source_files) = state.source_files.as_mut() {
-                                source_files.read_updates(
-                                    &mut state.db,
-                                    &state.config,
-                                    &changes,
-                                )?;
-                                compile::<TNetworkProtocol>(&state.db, source_files, &state.config)
-                            } else {
-                                let source_files =
-                                    SourceFiles::read_all(&mut state.db, &state.config)?;
-                                let result = compile::<TNetworkProtocol>(
-                                    &state.db,
-                                    &source_files,
-                                    &state.config,
-                                );
-                                state.source_files = Some(source_files);
-                                result
-                            }
-                        })
-                    } else {
-                        info!(
-                            "{}",
-                            "Too many changes. Starting a full compilation.".cyan()
-                        );
-                        WithDuration::new(|| {
-                            let source_files = SourceFiles::read_all(&mut state.db, &state.config)?;
-                            let result = compile::<TNetworkProtocol>(
-                                &state.db,
-                                &source_files,
-                                &state.config,
+                if has_config_changes(&events, &config_location) {
+                    info!(
+                        "{}",
+                        "Config change detected. Starting a full compilation.".cyan()
+                    );
+                    state = create_state_and_compile::<TNetworkProtocol>(
+                        &config_location,
+                        current_working_directory,
+                        output_format,
+                    );
+                    watcher.stop();
+                    (rx, watcher) = create_debounced_file_watcher(&config_location, state.as_ref());
+                    continue;
+                }
+
+                let state = match state.as_mut() {
+                    Some(state) => state,
+                    None => continue,
+                };
+
+                let artifact_directories = state
+                    .projects
+                    .iter()
+                    .map(|project| project.config.artifact_directory.absolute_path.clone())
+                    .collect::<Vec<_>>();
+                for project in state.projects.iter_mut() {
+                    if let Some(changes) = categorize_and_filter_events(
+                        &events,
+                        &project.config,
+                        &artifact_directories,
+                    ) {
+                        let result = if changes.len() < MAX_CHANGED_FILES {
+                            info!("{}", "File changes detected. Starting to compile.".cyan());
+                            WithDuration::new(|| {
+                                project.compile_changes::<TNetworkProtocol>(&mut state.db, &changes)
+                            })
+                        } else {
+                            info!(
+                                "{}",
+                                "Too many changes. Starting a full compilation.".cyan()
                             );
-                            state.source_files = Some(source_files);
-                            result
-                        })
-                    };
-                    let _ = print_result(result);
-                    state.run_garbage_collection();
+                            WithDuration::new(|| {
+                                project.compile_from_scratch::<TNetworkProtocol>(&mut state.db)
+                            })
+                        };
+                        let _ = print_result(result, project.label.as_deref(), output_format);
+                    }
                 }
+                state.run_garbage_collection();
             }
             Err(errors) => return Err(errors),
         }
@@ -110,18 +102,74 @@ pub async fn handle_watch_command<TNetworkProtocol: NetworkProtocol>(
     Ok(())
 }
 
-fn has_config_changes(changes: &[SourceFileEvent]) -> bool {
-    changes
-        .iter()
-        .any(|(_, changed_file_kind)| matches!(changed_file_kind, ChangedFileKind::Config))
+/// The config file is identified by its canonical path, since that is the path of
+/// the events that the file watcher emits. If the config file does not exist, its
+/// directory is canonicalized instead, so that it is found once it is created.
+fn canonicalize_config_location(config_location: PathBuf) -> PathBuf {
+    if let Ok(canonical_config_location) = config_location.canonicalize() {
+        return canonical_config_location;
+    }
+    match (config_location.parent(), config_location.file_name()) {
+        (Some(config_dir), Some(file_name)) => {
+            let config_dir = if config_dir.as_os_str().is_empty() {
+                Path::new(".")
+            } else {
+                config_dir
+            };
+            config_dir
+                .canonicalize()
+                .map(|config_dir| config_dir.join(file_name))
+                .unwrap_or(config_location)
+        }
+        _ => config_location,
+    }
+}
+
+fn create_state_and_compile<TNetworkProtocol: NetworkProtocol>(
+    config_location: &Path,
+    current_working_directory: CurrentWorkingDirectory,
+    output_format: OutputFormat,
+) -> Option<ProjectsCompilerState> {
+    let mut state =
+        ProjectsCompilerState::new(config_location.to_path_buf(), current_working_directory)
+            .map_err(|err| print_config_error(err, output_format))
+            .ok()?;
+    for project in state.projects.iter_mut() {
+        let _ = print_result(
+            WithDuration::new(|| project.compile_from_scratch::<TNetworkProtocol>(&mut state.db)),
+            project.label.as_deref(),
+            output_format,
+        );
+    }
+    Some(state)
+}
+
+fn has_config_changes(events: &[DebouncedEvent], config_location: &Path) -> bool {
+    events.iter().any(|event| {
+        matches!(
+            event.kind,
+            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+        ) && event.paths.iter().any(|path| path == config_location)
+    })
 }
 
+/// Categorize the events that are relevant to a project. Events in the artifact
+/// directory of any project are ignored, since a project's artifacts may be written
+/// to a folder within the project root of another project.
 fn categorize_and_filter_events(
     events: &[DebouncedEvent],
     config: &CompilerConfig,
+    artifact_directories: &[PathBuf],
 ) -> Option<Vec<SourceFileEvent>> {
     let mut source_file_events = vec![];
     for event in events {
+        if event.paths.iter().any(|path| {
+            artifact_directories
+                .iter()
+                .any(|artifact_directory| path.starts_with(artifact_directory))
+        }) {
+            continue;
+        }
         if let Some(source_file_event) = match event.kind {
             EventKind::Create(create_kind) => {
                 process_create_event(config, create_kind, &event.paths)
@@ -259,8 +307,6 @@ fn categorize_changed_file_and_filter_changes_in_artifact_directory(
             .any(|x| x.absolute_path == *path)
         {
             return Some(ChangedFileKind::SchemaExtension);
-        } else if path == &config.config_location {
-            return Some(ChangedFileKind::Config);
         }
     }
     None
@@ -268,7 +314,8 @@ fn categorize_changed_file_and_filter_changes_in_artifact_directory(
 
 #[allow(clippy::complexity)]
 fn create_debounced_file_watcher(
-    config: &CompilerConfig,
+    config_location: &Path,
+    state: Option<&ProjectsCompilerState>,
 ) -> (
     Receiver<Result<Vec<DebouncedEvent>, Vec<Error>>>,
     Debouncer<RecommendedWatcher, RecommendedCache>,
@@ -292,19 +339,41 @@ fn create_debounced_file_watcher(
     )
     .expect("Expected to be able to create debouncer");
 
-    watcher
-        .watch(&config.config_location, RecursiveMode::NonRecursive)
-        .expect("Failure when watching project root");
-    watcher
-        .watch(&config.project_root, RecursiveMode::Recursive)
-        .expect("Failure when watching project root");
-    watcher
-        .watch(&config.schema.absolute_path, RecursiveMode::NonRecursive)
-        .expect("Failing when watching schema");
-    for extension in &config.schema_extensions {
+    // We watch the folder containing the config, rather than the config itself, so that
+    // we are notified if the config is created, or replaced by an editor.
+    if let Some(config_dir) = config_location.parent() {
         watcher
-            .watch(&extension.absolute_path, RecursiveMode::NonRecursive)
-            .expect("Failing when watching schema extension");
+            .watch(config_dir, RecursiveMode::NonRecursive)
+            .expect("Failure when watching the folder containing the config");
+    }
+
+    let projects = match state {
+        Some(state) => &state.projects[..],
+        None => &[],
+    };
+
+    // Projects may share a project root, schema or schema extensions, each of
+    // which only needs to be watched once.
+    let mut watched_paths = HashSet::new();
+    for project in projects {
+        let config = &project.config;
+        if watched_paths.insert(&config.project_root) {
+            watcher
+                .watch(&config.project_root, RecursiveMode::Recursive)
+                .expect("Failure when watching project root");
+        }
+        if watched_paths.insert(&config.schema.absolute_path) {
+            watcher
+                .watch(&config.schema.absolute_path, RecursiveMode::NonRecursive)
+                .expect("Failing when watching schema");
+        }
+        for extension in &config.schema_extensions {
+            if watched_paths.insert(&extension.absolute_path) {
+                watcher
+                    .watch(&extension.absolute_path, RecursiveMode::NonRecursive)
+                    .expect("Failing when watching schema extension");
+            }
+        }
     }
 
     (rx, watcher)
@@ -318,7 +387,6 @@ pub enum SourceEventKind {
 }
 
 pub enum ChangedFileKind {
-    Config,
     Schema,
     SchemaExtension,
     JavaScriptSourceFile,
@@ -0,0 +1,38 @@
+use common_lang_types::{HasDiagnosticCode, WithLocation};
+
+use crate::batch_compile::ErrorWithDiagnosticCode;
+
+/// An item (usually the result of compiling), and the warnings that were encountered
+/// while computing it. Warnings do not cause compilation to fail, so they are reported
+/// whether or not compilation succeeded.
+pub struct WithWarnings<T> {
+    pub item: T,
+    pub warnings: Vec<WithLocation<ErrorWithDiagnosticCode>>,
+}
+
+impl<T> WithWarnings<T> {
+    pub fn without_warnings(item: T) -> Self {
+        WithWarnings {
+            item,
+            warnings: vec![],
+        }
+    }
+
+    pub(crate) fn add_warnings<W: std::error::Error + HasDiagnosticCode + 'static>(
+        &mut self,
+        warnings: Vec<WithLocation<W>>,
+    ) {
+        self.warnings.extend(
+            warnings
+                .into_iter()
+                .map(|warning| warning.map(ErrorWithDiagnosticCode::new)),
+        );
+    }
+
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> WithWarnings<U> {
+        WithWarnings {
+            item: f(self.item),
+            warnings: self.warnings,
+        }
+    }
+}
@@ -1,65 +1,176 @@
 use std::{
-    fs::{self, File},
-    io::Write,
-    path::PathBuf,
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
 };
 
-use common_lang_types::ArtifactPathAndContent;
+use common_lang_types::{ArtifactPathAndContent, DiagnosticCode, HasDiagnosticCode};
 use intern::string_key::Lookup;
 use thiserror::Error;
 
-pub(crate) fn write_artifacts_to_disk(
+/// The differences between the artifacts generated by the compiler and the files in
+/// the artifact directory. Paths are absolute.
+#[derive(Debug, Default)]
+pub struct ArtifactChanges {
+    /// Artifacts that do not exist on disk.
+    pub added: BTreeMap<PathBuf, String>,
+    /// Artifacts whose content differs from the file on disk.
+    pub changed: BTreeMap<PathBuf, String>,
+    /// Files in the artifact directory that do not correspond to an artifact.
+    pub deleted: BTreeSet<PathBuf>,
+}
+
+impl ArtifactChanges {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.changed.is_empty() && self.deleted.is_empty()
+    }
+}
+
+/// Compare the artifacts against the contents of the artifact directory, without
+/// modifying the file system.
+pub(crate) fn get_artifact_changes(
     paths_and_contents: impl IntoIterator<Item = ArtifactPathAndContent>,
-    artifact_directory: &PathBuf,
-) -> Result<usize, GenerateArtifactsError> {
+    artifact_directory: &Path,
+) -> Result<ArtifactChanges, GenerateArtifactsError> {
+    let mut changes = ArtifactChanges::default();
     if artifact_directory.exists() {
-        fs::remove_dir_all(artifact_directory).map_err(|e| {
-            GenerateArtifactsError::UnableToDeleteDirectory {
-                path: artifact_directory.clone(),
-                message: e.to_string(),
-            }
-        })?;
+        collect_files(artifact_directory, &mut changes.deleted)?;
     }
-    fs::create_dir_all(artifact_directory).map_err(|e| {
-        GenerateArtifactsError::UnableToCreateDirectory {
-            path: artifact_directory.clone(),
-            message: e.to_string(),
-        }
-    })?;
 
-    let mut count = 0;
     for path_and_content in paths_and_contents {
-        // Is this better than materializing paths_and_contents sooner?
-        count += 1;
-
         let absolute_directory = match path_and_content.type_and_field {
             Some(type_and_field) => artifact_directory
                 .join(type_and_field.type_name.lookup())
                 .join(type_and_field.field_name.lookup()),
-            None => artifact_directory.clone(),
+            None => artifact_directory.to_path_buf(),
         };
-        fs::create_dir_all(&absolute_directory).map_err(|e| {
-            GenerateArtifactsError::UnableToCreateDirectory {
-                path: absolute_directory.clone(),
-                message: e.to_string(),
+        let absolute_file_path = absolute_directory.join(path_and_content.file_name.lookup());
+
+        if changes.deleted.remove(&absolute_file_path) {
+            let is_unchanged = fs::read(&absolute_file_path)
+                .map(|existing_content| {
+                    existing_content == path_and_content.file_content.as_bytes()
+                })
+                .unwrap_or(false);
+            if !is_unchanged {
+                changes
+                    .changed
+                    .insert(absolute_file_path, path_and_content.file_content);
             }
-        })?;
+        } else {
+            changes
+                .added
+                .insert(absolute_file_path, path_and_content.file_content);
+        }
+    }
 
-        let absolute_file_path = absolute_directory.join(path_and_content.file_name.lookup());
-        let mut file = File::create(&absolute_file_path).map_err(|e| {
+    Ok(changes)
+}
+
+/// Write the artifacts to the artifact directory, returning the number of artifacts
+/// that were written.
+///
+/// Only artifacts that are new, or whose content has changed, are written. Files in the
+/// artifact directory that do not correspond to an artifact are deleted, as are any
+/// directories left empty. This avoids needlessly triggering file watchers (e.g. of
+/// bundlers), and means that there is never a moment in which no artifacts exist.
+pub(crate) fn write_artifacts_to_disk(
+    paths_and_contents: impl IntoIterator<Item = ArtifactPathAndContent>,
+    artifact_directory: &PathBuf,
+) -> Result<usize, GenerateArtifactsError> {
+    let changes = get_artifact_changes(paths_and_contents, artifact_directory)?;
+
+    fs::create_dir_all(artifact_directory).map_err(|e| {
+        GenerateArtifactsError::UnableToCreateDirectory {
+            path: artifact_directory.clone(),
+            message: e.to_string(),
+        }
+    })?;
+
+    let mut count = 0;
+    for (absolute_file_path, file_content) in changes.added.iter().chain(changes.changed.iter()) {
+        if let Some(absolute_directory) = absolute_file_path.parent() {
+            fs::create_dir_all(absolute_directory).map_err(|e| {
+                GenerateArtifactsError::UnableToCreateDirectory {
+                    path: absolute_directory.to_path_buf(),
+                    message: e.to_string(),
+                }
+            })?;
+        }
+        fs::write(absolute_file_path, file_content.as_bytes()).map_err(|e| {
             GenerateArtifactsError::UnableToWriteToArtifactFile {
                 path: absolute_file_path.clone(),
                 message: e.to_string(),
             }
         })?;
+        count += 1;
+    }
 
-        file.write(path_and_content.file_content.as_bytes())
-            .map_err(|e| GenerateArtifactsError::UnableToWriteToArtifactFile {
-                path: absolute_file_path.clone(),
+    for stale_file in changes.deleted {
+        fs::remove_file(&stale_file).map_err(|e| GenerateArtifactsError::UnableToDeleteFile {
+            path: stale_file.clone(),
+            message: e.to_string(),
+        })?;
+    }
+    remove_empty_directories(artifact_directory)?;
+
+    Ok(count)
+}
+
+/// Collect the paths of all files within the directory, recursively.
+fn collect_files(
+    directory: &Path,
+    files: &mut BTreeSet<PathBuf>,
+) -> Result<(), GenerateArtifactsError> {
+    let unable_to_read_directory =
+        |e: std::io::Error| GenerateArtifactsError::UnableToReadDirectory {
+            path: directory.to_path_buf(),
+            message: e.to_string(),
+        };
+    for entry in fs::read_dir(directory).map_err(unable_to_read_directory)? {
+        let entry = entry.map_err(unable_to_read_directory)?;
+        let path = entry.path();
+        if entry
+            .file_type()
+            .map_err(unable_to_read_directory)?
+            .is_dir()
+        {
+            collect_files(&path, files)?;
+        } else {
+            files.insert(path);
+        }
+    }
+    Ok(())
+}
+
+/// Remove the directories within the directory that contain no files, recursively.
+/// Returns whether the directory itself is now empty. The directory itself is not
+/// removed.
+fn remove_empty_directories(directory: &Path) -> Result<bool, GenerateArtifactsError> {
+    let unable_to_read_directory =
+        |e: std::io::Error| GenerateArtifactsError::UnableToReadDirectory {
+            path: directory.to_path_buf(),
+            message: e.to_string(),
+        };
+    let mut is_empty = true;
+    for entry in fs::read_dir(directory).map_err(unable_to_read_directory)? {
+        let entry = entry.map_err(unable_to_read_directory)?;
+        let path = entry.path();
+        if entry
+            .file_type()
+            .map_err(unable_to_read_directory)?
+            .is_dir()
+            && remove_empty_directories(&path)?
+        {
+            fs::remove_dir(&path).map_err(|e| GenerateArtifactsError::UnableToDeleteDirectory {
+                path: path.clone(),
                 message: e.to_string(),
             })?;
+        } else {
+            is_empty = false;
+        }
     }
-    Ok(count)
+    Ok(is_empty)
 }
 
 #[allow(clippy::enum_variant_names)]
@@ -79,10 +190,84 @@ pub enum GenerateArtifactsError {
     )]
     UnableToCreateDirectory { path: PathBuf, message: String },
 
+    #[error(
+        "Unable to read directory at path {path:?}. \
+        Is there another instance of the Isograph compiler running?\
+        \nReason: {message:?}"
+    )]
+    UnableToReadDirectory { path: PathBuf, message: String },
+
     #[error(
         "Unable to delete directory at path {path:?}. \
         Is there another instance of the Isograph compiler running?\
         \nReason: {message:?}"
     )]
     UnableToDeleteDirectory { path: PathBuf, message: String },
+
+    #[error(
+        "Unable to delete stale artifact file at path {path:?}. \
+        Is there another instance of the Isograph compiler running?\
+        \nReason: {message:?}"
+    )]
+    UnableToDeleteFile { path: PathBuf, message: String },
+}
+
+impl HasDiagnosticCode for GenerateArtifactsError {
+    fn diagnostic_code(&self) -> DiagnosticCode {
+        match self {
+            GenerateArtifactsError::UnableToWriteToArtifactFile { .. } => DiagnosticCode(1201),
+            GenerateArtifactsError::UnableToCreateDirectory { .. } => DiagnosticCode(1202),
+            GenerateArtifactsError::UnableToReadDirectory { .. } => DiagnosticCode(1203),
+            GenerateArtifactsError::UnableToDeleteDirectory { .. } => DiagnosticCode(1204),
+            GenerateArtifactsError::UnableToDeleteFile { .. } => DiagnosticCode(1205),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use common_lang_types::{ArtifactPathAndContent, ObjectTypeAndFieldName};
+    use intern::string_key::Intern;
+    use isograph_test_utils::TestProjectDirectory;
+
+    use super::write_artifacts_to_disk;
+
+    fn artifact(field_name: &str, content: &str) -> ArtifactPathAndContent {
+        ArtifactPathAndContent {
+            type_and_field: Some(ObjectTypeAndFieldName {
+                type_name: "User".intern().into(),
+                field_name: field_name.intern().into(),
+            }),
+            file_name: "reader.ts".intern().into(),
+            file_content: content.to_string(),
+        }
+    }
+
+    #[test]
+    fn writes_only_changed_artifacts_and_removes_stale_ones() {
+        let project_directory = TestProjectDirectory::new("write_artifacts", &[]);
+        let artifact_directory = project_directory.join("__isograph");
+
+        let written = write_artifacts_to_disk(
+            vec![artifact("Avatar", "a"), artifact("Name", "b")],
+            &artifact_directory,
+        )
+        .unwrap();
+        assert_eq!(written, 2);
+
+        let written = write_artifacts_to_disk(
+            vec![artifact("Avatar", "a"), artifact("Email", "c")],
+            &artifact_directory,
+        )
+        .unwrap();
+        assert_eq!(written, 1);
+        assert_eq!(
+            fs::read_to_string(artifact_directory.join("User/Email/reader.ts")).unwrap(),
+            "c"
+        );
+        assert!(artifact_directory.join("User/Avatar/reader.ts").exists());
+        assert!(!artifact_directory.join("User/Name").exists());
+    }
 }
@@ -1,11 +1,18 @@
 use common_lang_types::{
     relative_path_from_absolute_and_working_directory, AbsolutePathAndRelativePath,
-    CurrentWorkingDirectory, GeneratedFileHeader,
+    CurrentWorkingDirectory, DiagnosticCode, FieldArgumentName, GeneratedFileHeader,
+    GraphQLScalarTypeName, HasDiagnosticCode, IsographObjectTypeName, JavascriptName, Location,
+    ServerObjectSelectableName, ServerScalarSelectableName, Severity, Span, StringLiteralValue,
+    TextSource, WithLocation,
 };
 use intern::string_key::Intern;
 use schemars::JsonSchema;
 use serde::Deserialize;
-use std::path::PathBuf;
+use std::{
+    collections::BTreeMap,
+    path::{Component, Path, PathBuf},
+};
+use thiserror::Error;
 use tracing::warn;
 
 pub static ISOGRAPH_FOLDER: &str = "__isograph";
@@ -36,10 +43,53 @@ pub struct CompilerConfig {
 #[derive(Default, Debug, Clone)]
 pub struct CompilerConfigOptions {
     pub on_invalid_id_type: OptionalValidationLevel,
+    pub on_deprecated_field: OptionalValidationLevel,
     pub no_babel_transform: bool,
     pub include_file_extensions_in_import_statements: GenerateFileExtensionsOption,
     pub module: JavascriptModule,
     pub generated_file_header: Option<GeneratedFileHeader>,
+    pub persisted_queries: bool,
+    pub query_text_fragments: QueryTextFragmentsOption,
+    /// A map from the name of a scalar to the TypeScript type that is generated
+    /// for it. Imported types are referenced via import('...') types, so that the
+    /// generated files need no additional import statements.
+    pub custom_scalar_types: BTreeMap<GraphQLScalarTypeName, JavascriptName>,
+    /// A map from the name of an object type to the path of fields (e.g. viewer.settings)
+    /// from Query through which that type is refetched. The location of each path is
+    /// the name of the type in the config file.
+    pub refetch_from_root: BTreeMap<IsographObjectTypeName, WithLocation<StringLiteralValue>>,
+    /// The field on Query through which objects are refetched. If None, objects are
+    /// refetched via node(id: $id).
+    pub refetch_field: Option<RefetchFieldOptions>,
+    /// A map from the name of an object type to the name of its id field, for types
+    /// whose id field is not named id.
+    pub id_fields: BTreeMap<IsographObjectTypeName, ServerScalarSelectableName>,
+}
+
+/// A field on Query that returns the object with a given id, e.g. node(id: $id)
+/// or entity(kind: "Pet", id: $id).
+#[derive(Debug, Clone)]
+pub struct RefetchFieldOptions {
+    pub name: ServerObjectSelectableName,
+    /// The arguments of the refetch field, and the values that are passed to them.
+    pub arguments: BTreeMap<FieldArgumentName, RefetchFieldArgumentValue>,
+}
+
+impl Default for RefetchFieldOptions {
+    fn default() -> Self {
+        RefetchFieldOptions {
+            name: "node".intern().into(),
+            arguments: BTreeMap::from([("id".intern().into(), RefetchFieldArgumentValue::Id)]),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefetchFieldArgumentValue {
+    /// The id of the refetched object, passed as the $id variable
+    Id,
+    /// The name of the type of the refetched object, e.g. "Pet"
+    Typename,
 }
 
 #[derive(Default, Debug, Clone, Copy)]
@@ -58,9 +108,19 @@ impl GenerateFileExtensionsOption {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone, Copy)]
+pub enum QueryTextFragmentsOption {
+    /// Selection sets that appear multiple times in a query are extracted into
+    /// named fragments, which are spread wherever the selection set appears.
+    ExtractRepeatedSelectionSets,
+    #[default]
+    InlineAllSelectionSets,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
 pub enum OptionalValidationLevel {
     /// If this validation error is encountered, it will be ignored
+    #[default]
     Ignore,
     /// If this validation error is encountered, a warning will be issued
     Warn,
@@ -83,11 +143,15 @@ impl OptionalValidationLevel {
             OptionalValidationLevel::Error => Err(on_error()),
         }
     }
-}
 
-impl Default for OptionalValidationLevel {
-    fn default() -> Self {
-        Self::Ignore
+    /// The severity with which failures of this validation are reported, or None if
+    /// they are ignored.
+    pub fn severity(self) -> Option<Severity> {
+        match self {
+            OptionalValidationLevel::Ignore => None,
+            OptionalValidationLevel::Warn => Some(Severity::Warning),
+            OptionalValidationLevel::Error => Some(Severity::Error),
+        }
     }
 }
 
@@ -98,7 +162,8 @@ pub enum JavascriptModule {
     EsModule,
 }
 
-/// This struct is deserialized from an isograph.config.json file.
+/// This struct is deserialized from an isograph.config.json file, or from an
+/// element of the projects array of a multi-project config.
 #[derive(Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct IsographProjectConfig {
@@ -122,28 +187,347 @@ pub struct IsographProjectConfig {
     pub options: ConfigFileOptions,
 }
 
-pub fn create_config(
+/// This struct is deserialized from an isograph.config.json file that contains
+/// multiple projects.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct IsographMultiProjectConfig {
+    /// The user may hard-code the JSON Schema for their version of the config.
+    #[serde(rename = "$schema")]
+    #[allow(dead_code)]
+    pub json_schema: Option<String>,
+    /// The projects to compile. Each project has its own project root, schema,
+    /// artifact directory and options. Paths are relative to the config file.
+    pub projects: Vec<IsographProjectConfig>,
+}
+
+/// An isograph.config.json file, which contains either a single project or an
+/// array of projects.
+#[derive(Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum IsographConfigFile {
+    SingleProject(Box<IsographProjectConfig>),
+    MultiProject(IsographMultiProjectConfig),
+}
+
+/// Read the config file and create a CompilerConfig for each project in it.
+pub fn create_configs(
     config_location: PathBuf,
     current_working_directory: CurrentWorkingDirectory,
-) -> CompilerConfig {
-    let config_contents = match std::fs::read_to_string(&config_location) {
-        Ok(contents) => contents,
-        Err(_) => match config_location.to_str() {
-            Some(loc) => {
-                panic!("Expected config to be found at {}", loc)
+) -> Result<Vec<CompilerConfig>, ConfigError> {
+    let config_contents =
+        std::fs::read_to_string(&config_location).map_err(|e| ConfigError::UnableToReadConfig {
+            path: config_location.clone(),
+            message: e.to_string(),
+        })?;
+
+    let config_location =
+        config_location
+            .canonicalize()
+            .map_err(|e| ConfigError::UnableToReadConfig {
+                path: config_location.clone(),
+                message: e.to_string(),
+            })?;
+
+    let config_text_source = TextSource {
+        current_working_directory,
+        relative_path_to_source_file: relative_path_from_absolute_and_working_directory(
+            current_working_directory,
+            &config_location,
+        ),
+        span: None,
+    };
+
+    let config_file = parse_config_file(
+        &config_contents,
+        ConfigFileFormat::from_path(&config_location),
+    )
+    .map_err(|e| ConfigError::UnableToParseConfig {
+        message: e.message,
+        location: Location::new(config_text_source, e.span),
+    })?;
+
+    let config_file_contents = ConfigFileContents {
+        text_source: config_text_source,
+        contents: &config_contents,
+    };
+
+    let configs = match config_file {
+        IsographConfigFile::SingleProject(config_parsed) => vec![create_project_config(
+            *config_parsed,
+            config_location,
+            config_file_contents,
+            current_working_directory,
+        )?],
+        IsographConfigFile::MultiProject(multi_project_config) => {
+            if multi_project_config.projects.is_empty() {
+                return Err(ConfigError::NoProjects);
+            }
+            multi_project_config
+                .projects
+                .into_iter()
+                .map(|config_parsed| {
+                    create_project_config(
+                        config_parsed,
+                        config_location.clone(),
+                        config_file_contents,
+                        current_working_directory,
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        }
+    };
+
+    for (index, config) in configs.iter().enumerate() {
+        if let Some(other_config) = configs[..index].iter().find(|other_config| {
+            other_config.artifact_directory.absolute_path == config.artifact_directory.absolute_path
+        }) {
+            return Err(ConfigError::SharedArtifactDirectory {
+                first_project_root: other_config.project_root.clone(),
+                second_project_root: config.project_root.clone(),
+            });
+        }
+    }
+
+    Ok(configs)
+}
+
+/// The names of the files that may contain the config. When searching for the config,
+/// each folder is searched for these files, in this order.
+const CONFIG_FILE_NAMES: [&str; 3] = [
+    "isograph.config.json",
+    "package.json",
+    "isograph.config.toml",
+];
+
+/// Search the current directory, and then each of its parent directories, for an
+/// isograph.config.json file, a package.json file with an "isograph" key, or an
+/// isograph.config.toml file. Returns the location of the first one found.
+pub fn find_config_location(current_dir: &Path) -> Result<PathBuf, ConfigError> {
+    for directory in current_dir.ancestors() {
+        for file_name in CONFIG_FILE_NAMES {
+            let config_location = directory.join(file_name);
+            if !config_location.is_file() {
+                continue;
             }
-            None => {
-                panic!("Expected config to be found.")
+            if ConfigFileFormat::from_path(&config_location) == ConfigFileFormat::PackageJson
+                && !package_json_contains_config(&config_location)
+            {
+                continue;
             }
-        },
+            return Ok(config_location);
+        }
+    }
+    Err(ConfigError::ConfigNotFound {
+        current_dir: current_dir.to_path_buf(),
+    })
+}
+
+fn package_json_contains_config(package_json_location: &Path) -> bool {
+    std::fs::read_to_string(package_json_location)
+        .ok()
+        .and_then(|contents| serde_json::from_str::<serde_json::Value>(&contents).ok())
+        .is_some_and(|package_json| package_json.get("isograph").is_some())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConfigFileFormat {
+    Json,
+    /// The config is located under the "isograph" key of a package.json file
+    PackageJson,
+    Toml,
+}
+
+impl ConfigFileFormat {
+    fn from_path(config_location: &Path) -> Self {
+        if config_location
+            .file_name()
+            .is_some_and(|x| x == "package.json")
+        {
+            ConfigFileFormat::PackageJson
+        } else if config_location.extension().is_some_and(|x| x == "toml") {
+            ConfigFileFormat::Toml
+        } else {
+            ConfigFileFormat::Json
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct PackageJson<T> {
+    isograph: T,
+}
+
+#[derive(Debug)]
+struct ConfigParseError {
+    message: String,
+    span: Span,
+}
+
+fn parse_config_file(
+    config_contents: &str,
+    format: ConfigFileFormat,
+) -> Result<IsographConfigFile, ConfigParseError> {
+    match format {
+        ConfigFileFormat::Json | ConfigFileFormat::PackageJson => {
+            parse_json_config_file(config_contents, format).map_err(|e| ConfigParseError {
+                message: serde_json_error_message(&e),
+                span: serde_json_error_span(config_contents, &e),
+            })
+        }
+        ConfigFileFormat::Toml => {
+            parse_toml_config_file(config_contents).map_err(|e| ConfigParseError {
+                message: e.message().to_string(),
+                span: e
+                    .span()
+                    .map(|span| Span::new(span.start as u32, span.end as u32))
+                    .unwrap_or_else(|| Span::new(0, 0)),
+            })
+        }
+    }
+}
+
+/// Whether the config file contains multiple projects is determined by the presence
+/// of the projects key, so that parse errors refer to the fields of the intended
+/// struct, instead of failing to match any variant of IsographConfigFile.
+fn parse_json_config_file(
+    config_contents: &str,
+    format: ConfigFileFormat,
+) -> Result<IsographConfigFile, serde_json::Error> {
+    let value: serde_json::Value = serde_json::from_str(config_contents)?;
+    if format == ConfigFileFormat::PackageJson {
+        // The entire package.json file is deserialized, so that the locations of errors
+        // are relative to the start of the file.
+        if value
+            .get("isograph")
+            .and_then(|config| config.get("projects"))
+            .is_some()
+        {
+            serde_json::from_str::<PackageJson<_>>(config_contents)
+                .map(|package_json| IsographConfigFile::MultiProject(package_json.isograph))
+        } else {
+            serde_json::from_str::<PackageJson<_>>(config_contents)
+                .map(|package_json| IsographConfigFile::SingleProject(package_json.isograph))
+        }
+    } else if value.get("projects").is_some() {
+        serde_json::from_str(config_contents).map(IsographConfigFile::MultiProject)
+    } else {
+        serde_json::from_str(config_contents).map(IsographConfigFile::SingleProject)
+    }
+}
+
+fn parse_toml_config_file(config_contents: &str) -> Result<IsographConfigFile, toml::de::Error> {
+    let table: toml::Table = toml::from_str(config_contents)?;
+    if table.contains_key("projects") {
+        toml::from_str(config_contents).map(IsographConfigFile::MultiProject)
+    } else {
+        toml::from_str(config_contents).map(IsographConfigFile::SingleProject)
+    }
+}
+
+/// The message of a serde_json error, without the line and column, which are
+/// instead displayed by underlining the location in the config file.
+fn serde_json_error_message(error: &serde_json::Error) -> String {
+    let message = error.to_string();
+    message
+        .strip_suffix(&format!(
+            " at line {} column {}",
+            error.line(),
+            error.column()
+        ))
+        .map(str::to_string)
+        .unwrap_or(message)
+}
+
+/// serde_json reports the (one-based) line and column of the last character that it
+/// read before encountering an error, which is usually the last character of the
+/// offending key or value. The span covers the entire key or value.
+fn serde_json_error_span(config_contents: &str, error: &serde_json::Error) -> Span {
+    let start_of_line = config_contents
+        .split_inclusive('\n')
+        .take(error.line().saturating_sub(1))
+        .map(str::len)
+        .sum::<usize>();
+    if error.column() == 0 {
+        return Span::new(start_of_line as u32, start_of_line as u32);
+    }
+    let bytes = config_contents.as_bytes();
+    let end = std::cmp::min(start_of_line + error.column(), bytes.len());
+    let last_character = match end.checked_sub(1) {
+        Some(index) => bytes[index],
+        None => return Span::new(0, 0),
     };
 
-    let config_parsed: IsographProjectConfig = serde_json::from_str(&config_contents)
-        .unwrap_or_else(|e| panic!("Error parsing config. Error: {}", e));
+    let start = if last_character == b'"' {
+        // Find the opening quote of the string, skipping escaped quotes
+        let mut index = end - 1;
+        while let Some(previous_quote) = bytes[..index].iter().rposition(|byte| *byte == b'"') {
+            index = previous_quote;
+            if previous_quote == 0 || bytes[previous_quote - 1] != b'\\' {
+                break;
+            }
+        }
+        index
+    } else if last_character.is_ascii_alphanumeric() {
+        bytes[..end]
+            .iter()
+            .rposition(|byte| !(byte.is_ascii_alphanumeric() || b"-+.".contains(byte)))
+            .map(|index| index + 1)
+            .unwrap_or(0)
+    } else {
+        end - 1
+    };
+
+    Span::new(start as u32, end as u32)
+}
+
+/// The text of the config file, so that options whose errors are only detected after
+/// the config file is parsed (e.g. refetch_from_root) can be given a location in it.
+#[derive(Clone, Copy)]
+struct ConfigFileContents<'a> {
+    text_source: TextSource,
+    contents: &'a str,
+}
 
-    let mut config = config_location.clone();
-    config.pop();
-    let config_dir = config;
+impl ConfigFileContents<'_> {
+    /// The location of a key of an option whose value is a map, e.g. the Viewer key of
+    /// refetch_from_root. The config file is not parsed again; instead, the first
+    /// occurrence of the key after the name of the option is found. If there is none,
+    /// the location is the start of the config file.
+    fn map_option_key_location(&self, option_name: &str, key: &str) -> Location {
+        let is_delimiter = |c: Option<char>| !c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+        let span = self
+            .contents
+            .match_indices(option_name)
+            .find_map(|(option_index, _)| {
+                self.contents[option_index..]
+                    .match_indices(key)
+                    .map(|(key_index, _)| option_index + key_index)
+                    .find(|start| {
+                        let end = start + key.len();
+                        is_delimiter(self.contents[..*start].chars().next_back())
+                            && is_delimiter(self.contents[end..].chars().next())
+                            && self.contents[end..]
+                                .trim_start_matches(['"', '\'', ' ', '\t'])
+                                .starts_with([':', '='])
+                    })
+            })
+            .map(|start| Span::new(start as u32, (start + key.len()) as u32))
+            .unwrap_or_else(|| Span::new(0, 0));
+        Location::new(self.text_source, span)
+    }
+}
+
+fn create_project_config(
+    config_parsed: IsographProjectConfig,
+    config_location: PathBuf,
+    config_file_contents: ConfigFileContents,
+    current_working_directory: CurrentWorkingDirectory,
+) -> Result<CompilerConfig, ConfigError> {
+    let config_dir = config_location
+        .parent()
+        .expect("Expected config file to be located in a directory")
+        .to_path_buf();
 
     let artifact_dir = config_dir
         .join(
@@ -153,67 +537,70 @@ pub fn create_config(
                 .unwrap_or(&config_parsed.project_root),
         )
         .join(ISOGRAPH_FOLDER);
-    std::fs::create_dir_all(&artifact_dir).expect("Unable to create artifact directory");
+    let artifact_directory = absolute_and_relative_paths(
+        current_working_directory,
+        create_and_canonicalize_directory(&artifact_dir, "artifact directory")?,
+    );
 
-    let project_root_dir = config_dir.join(&config_parsed.project_root);
-    std::fs::create_dir_all(&project_root_dir).expect("Unable to create project root directory");
+    let options = create_options(
+        config_parsed.options,
+        config_file_contents,
+        &config_dir,
+        &artifact_directory.absolute_path,
+    )?;
 
-    CompilerConfig {
-        config_location: config_location.canonicalize().unwrap_or_else(|_| {
-            panic!(
-                "Unable to canonicalize config_file at {:?}.",
-                config_location
-            )
-        }),
-        project_root: project_root_dir.canonicalize().unwrap_or_else(|_| {
-            panic!(
-                "Unable to canonicalize project root at {:?}.",
-                config_parsed.project_root
-            )
-        }),
-        artifact_directory: absolute_and_relative_paths(
-            current_working_directory,
-            artifact_dir.canonicalize().unwrap_or_else(|_| {
-                panic!(
-                    "Unable to canonicalize artifact directory at {:?}.",
-                    config_parsed.artifact_directory
-                )
-            }),
-        ),
-        schema: absolute_and_relative_paths(
-            current_working_directory,
-            config_dir
-                .join(&config_parsed.schema)
-                .canonicalize()
-                .unwrap_or_else(|_| {
-                    panic!(
-                        "Unable to canonicalize schema path. Does {:?} exist?",
-                        config_parsed.schema
-                    )
-                }),
-        ),
-        schema_extensions: config_parsed
-            .schema_extensions
-            .into_iter()
-            .map(|schema_extension| {
-                absolute_and_relative_paths(
-                    current_working_directory,
-                    config_dir
-                        .join(&schema_extension)
-                        .canonicalize()
-                        .unwrap_or_else(|_| {
-                            panic!(
-                                "Unable to canonicalize schema extension path. Does {:?} exist?",
-                                schema_extension
-                            )
-                        }),
-                )
-            })
-            .collect(),
-        options: create_options(config_parsed.options),
+    let project_root = create_and_canonicalize_directory(
+        &config_dir.join(&config_parsed.project_root),
+        "project root",
+    )?;
 
+    let schema = absolute_and_relative_paths(
         current_working_directory,
-    }
+        canonicalize_file(&config_dir.join(&config_parsed.schema), "schema")?,
+    );
+
+    let schema_extensions = config_parsed
+        .schema_extensions
+        .into_iter()
+        .map(|schema_extension| {
+            Ok(absolute_and_relative_paths(
+                current_working_directory,
+                canonicalize_file(&config_dir.join(&schema_extension), "schema extension")?,
+            ))
+        })
+        .collect::<Result<Vec<_>, ConfigError>>()?;
+
+    Ok(CompilerConfig {
+        config_location,
+        project_root,
+        artifact_directory,
+        schema,
+        schema_extensions,
+        options,
+
+        current_working_directory,
+    })
+}
+
+fn create_and_canonicalize_directory(
+    path: &Path,
+    kind: &'static str,
+) -> Result<PathBuf, ConfigError> {
+    std::fs::create_dir_all(path)
+        .and_then(|_| path.canonicalize())
+        .map_err(|e| ConfigError::UnableToCreateDirectory {
+            kind,
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })
+}
+
+fn canonicalize_file(path: &Path, kind: &'static str) -> Result<PathBuf, ConfigError> {
+    path.canonicalize().map_err(|e| ConfigError::FileNotFound {
+        kind,
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })
 }
 
 #[derive(Deserialize, Default, JsonSchema)]
@@ -222,6 +609,9 @@ pub struct ConfigFileOptions {
     /// What the compiler should do if it encounters an id field whose
     /// type is not ID! or ID.
     on_invalid_id_type: ConfigFileOptionalValidationLevel,
+    /// What the compiler should do if an iso literal selects a field, or passes an
+    /// argument, that is marked @deprecated in the schema. Defaults to warn.
+    on_deprecated_field: Option<ConfigFileOptionalValidationLevel>,
     /// Set this to true if you don't have the babel transform enabled.
     no_babel_transform: bool,
     /// Should the compiler include file extensions in import statements in
@@ -233,25 +623,94 @@ pub struct ConfigFileOptions {
     module: ConfigFileJavascriptModule,
     /// A string to generate, in a comment, at the top of every generated file.
     generated_file_header: Option<String>,
+    /// Should the compiler generate the ids of persisted queries, instead of query
+    /// text, in entrypoint and refetch query artifacts? If so, the compiler also
+    /// writes persisted_queries.json, which maps each id to its query text, to the
+    /// artifact directory. The network function is then passed a PersistedOperation,
+    /// containing the id of the query, instead of an Operation containing the query
+    /// text.
+    persisted_queries: bool,
+    /// Should the compiler extract selection sets that are repeated within a query
+    /// into named fragments (e.g. fragment Query__User__0 on User { ... }), which
+    /// are then spread (...Query__User__0) wherever that selection set appears?
+    /// This makes large queries shorter, without changing what they select.
+    fragments_in_query_text: bool,
+    /// A map from the name of a scalar (e.g. DateTime) to the TypeScript type that
+    /// should be generated for it (e.g. "string"). By default, custom scalars are
+    /// typed as string. Instead of a type, an object such as
+    /// {"type": "DateTime", "import_path": "./src/scalars"} can be provided, in which
+    /// case the type is imported from that module. Import paths starting with . are
+    /// relative to the config file; all other import paths are used as is.
+    custom_scalar_types: BTreeMap<String, ConfigFileCustomScalarType>,
+    /// A map from the name of an object type (e.g. Viewer) to a dot-separated path of
+    /// fields from Query (e.g. "viewer") through which that type is refetched. This
+    /// allows types that have no id field, and thus cannot be refetched via
+    /// Query.node, to be selected @loadable and to have a __refetch field. This is
+    /// equivalent to adding a @refetchFromRoot(path: "viewer") directive to the type.
+    refetch_from_root: BTreeMap<String, String>,
+    /// The field on Query through which objects are refetched, e.g. by __refetch
+    /// fields, @loadable fields and client pointers. Defaults to
+    /// {"name": "node", "arguments": {"id": "id"}}, i.e. Query.node(id: $id).
+    /// Each argument is passed either the id of the refetched object ("id") or
+    /// the name of its type ("typename"), e.g.
+    /// {"name": "entity", "arguments": {"kind": "typename", "id": "id"}}.
+    refetch_field: Option<ConfigFileRefetchField>,
+    /// A map from the name of an object type (e.g. Pet) to the name of its id field
+    /// (e.g. "uuid"), for types whose id field is not named id. The id field is
+    /// selected as id (e.g. id: uuid), so that the object can be refetched and
+    /// normalized by its id.
+    id_fields: BTreeMap<String, String>,
+}
+
+#[derive(Deserialize, Debug, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigFileRefetchField {
+    /// The name of the field on Query, e.g. "node"
+    name: String,
+    /// A map from the name of each argument of the field to the value passed to it
+    arguments: BTreeMap<String, ConfigFileRefetchFieldArgumentValue>,
 }
 
 #[derive(Deserialize, Debug, Clone, Copy, JsonSchema)]
 #[serde(rename_all = "snake_case")]
+pub enum ConfigFileRefetchFieldArgumentValue {
+    /// The id of the refetched object
+    Id,
+    /// The name of the type of the refetched object, e.g. "Pet"
+    Typename,
+}
+
+#[derive(Deserialize, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum ConfigFileCustomScalarType {
+    /// The TypeScript type, e.g. "string" or "unknown"
+    Type(String),
+    /// A type exported from a module
+    ImportedType(ConfigFileImportedScalarType),
+}
+
+#[derive(Deserialize, Debug, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigFileImportedScalarType {
+    /// The name of the exported type
+    #[serde(rename = "type")]
+    type_: String,
+    /// The module from which the type is imported
+    import_path: String,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, JsonSchema, Default)]
+#[serde(rename_all = "snake_case")]
 pub enum ConfigFileOptionalValidationLevel {
     /// If this validation error is encountered, it will be ignored
     Ignore,
     /// If this validation error is encountered, a warning will be issued
     Warn,
     /// If this validation error is encountered, the compilation will fail
+    #[default]
     Error,
 }
 
-impl Default for ConfigFileOptionalValidationLevel {
-    fn default() -> Self {
-        Self::Error
-    }
-}
-
 #[derive(Deserialize, Default, Debug, Clone, Copy, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum ConfigFileJavascriptModule {
@@ -260,27 +719,138 @@ pub enum ConfigFileJavascriptModule {
     EsModule,
 }
 
-fn create_options(options: ConfigFileOptions) -> CompilerConfigOptions {
+fn create_options(
+    options: ConfigFileOptions,
+    config_file_contents: ConfigFileContents,
+    config_dir: &Path,
+    artifact_directory: &Path,
+) -> Result<CompilerConfigOptions, ConfigError> {
     if let Some(header) = options.generated_file_header.as_ref() {
         let line_count = header.lines().count();
         if line_count > 1 {
-            panic!("config.options.generated_file_header should not be a multi-line string.")
+            return Err(ConfigError::MultiLineGeneratedFileHeader);
         }
     }
 
     let generated_file_header = options.generated_file_header.map(|x| x.intern().into());
 
-    CompilerConfigOptions {
+    Ok(CompilerConfigOptions {
         on_invalid_id_type: create_optional_validation_level(options.on_invalid_id_type),
+        on_deprecated_field: create_optional_validation_level(
+            options
+                .on_deprecated_field
+                .unwrap_or(ConfigFileOptionalValidationLevel::Warn),
+        ),
         no_babel_transform: options.no_babel_transform,
         include_file_extensions_in_import_statements: create_generate_file_extensions(
             options.include_file_extensions_in_import_statements,
         ),
         module: create_module(options.module),
         generated_file_header,
+        persisted_queries: options.persisted_queries,
+        query_text_fragments: create_query_text_fragments(options.fragments_in_query_text),
+        custom_scalar_types: options
+            .custom_scalar_types
+            .into_iter()
+            .map(|(scalar_name, custom_scalar_type)| {
+                (
+                    scalar_name.intern().into(),
+                    create_custom_scalar_type(custom_scalar_type, config_dir, artifact_directory),
+                )
+            })
+            .collect(),
+        refetch_from_root: options
+            .refetch_from_root
+            .into_iter()
+            .map(|(type_name, path)| {
+                let location =
+                    config_file_contents.map_option_key_location("refetch_from_root", &type_name);
+                (
+                    type_name.intern().into(),
+                    WithLocation::new(path.intern().into(), location),
+                )
+            })
+            .collect(),
+        refetch_field: options.refetch_field.map(create_refetch_field),
+        id_fields: options
+            .id_fields
+            .into_iter()
+            .map(|(type_name, field_name)| (type_name.intern().into(), field_name.intern().into()))
+            .collect(),
+    })
+}
+
+fn create_refetch_field(refetch_field: ConfigFileRefetchField) -> RefetchFieldOptions {
+    RefetchFieldOptions {
+        name: refetch_field.name.intern().into(),
+        arguments: refetch_field
+            .arguments
+            .into_iter()
+            .map(|(argument_name, value)| {
+                (
+                    argument_name.intern().into(),
+                    match value {
+                        ConfigFileRefetchFieldArgumentValue::Id => RefetchFieldArgumentValue::Id,
+                        ConfigFileRefetchFieldArgumentValue::Typename => {
+                            RefetchFieldArgumentValue::Typename
+                        }
+                    },
+                )
+            })
+            .collect(),
     }
 }
 
+fn create_custom_scalar_type(
+    custom_scalar_type: ConfigFileCustomScalarType,
+    config_dir: &Path,
+    artifact_directory: &Path,
+) -> JavascriptName {
+    match custom_scalar_type {
+        ConfigFileCustomScalarType::Type(type_) => type_.intern().into(),
+        ConfigFileCustomScalarType::ImportedType(ConfigFileImportedScalarType {
+            type_,
+            import_path,
+        }) => {
+            let import_path = if import_path.starts_with('.') {
+                artifact_import_path(&config_dir.join(import_path), artifact_directory)
+            } else {
+                import_path
+            };
+            format!("import('{import_path}').{type_}").intern().into()
+        }
+    }
+}
+
+/// The import path of a module, relative to the generated files that reference
+/// types. These are all located in the artifact directory at {Type}/{field}/.
+fn artifact_import_path(module_path: &Path, artifact_directory: &Path) -> String {
+    let mut normalized_module_path = PathBuf::new();
+    for component in module_path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized_module_path.pop();
+            }
+            component => normalized_module_path.push(component),
+        }
+    }
+
+    let relative_path = pathdiff::diff_paths(normalized_module_path, artifact_directory)
+        .expect("Expected path to be diffable");
+    let relative_path = relative_path
+        .components()
+        .map(|component| {
+            component
+                .as_os_str()
+                .to_str()
+                .expect("Expected path to be able to be stringified")
+        })
+        .collect::<Vec<_>>()
+        .join("/");
+    format!("../../{relative_path}")
+}
+
 fn create_optional_validation_level(
     optional_validation_level: ConfigFileOptionalValidationLevel,
 ) -> OptionalValidationLevel {
@@ -300,6 +870,13 @@ fn create_generate_file_extensions(
     }
 }
 
+fn create_query_text_fragments(fragments_in_query_text: bool) -> QueryTextFragmentsOption {
+    match fragments_in_query_text {
+        true => QueryTextFragmentsOption::ExtractRepeatedSelectionSets,
+        false => QueryTextFragmentsOption::InlineAllSelectionSets,
+    }
+}
+
 fn create_module(module: ConfigFileJavascriptModule) -> JavascriptModule {
     match module {
         ConfigFileJavascriptModule::CommonJs => JavascriptModule::CommonJs,
@@ -321,3 +898,325 @@ pub fn absolute_and_relative_paths(
         relative_path,
     }
 }
+
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("Unable to read the config file at {path:?}.\nReason: {message}")]
+    UnableToReadConfig { path: PathBuf, message: String },
+
+    #[error("Unable to parse the config file: {message}\n{location}")]
+    UnableToParseConfig { message: String, location: Location },
+
+    #[error(
+        "Unable to find an Isograph config. Searched {current_dir:?} and its parent \
+        directories for an isograph.config.json file, a package.json file with an \
+        \"isograph\" key, or an isograph.config.toml file."
+    )]
+    ConfigNotFound { current_dir: PathBuf },
+
+    #[error("Expected config.projects to contain at least one project.")]
+    NoProjects,
+
+    #[error(
+        "The projects at {first_project_root:?} and {second_project_root:?} have the \
+        same artifact directory. Each project must have its own artifact directory."
+    )]
+    SharedArtifactDirectory {
+        first_project_root: PathBuf,
+        second_project_root: PathBuf,
+    },
+
+    #[error("Unable to create the {kind} at {path:?}.\nReason: {message}")]
+    UnableToCreateDirectory {
+        kind: &'static str,
+        path: PathBuf,
+        message: String,
+    },
+
+    #[error("Unable to find the {kind} at {path:?}. Does it exist?\nReason: {message}")]
+    FileNotFound {
+        kind: &'static str,
+        path: PathBuf,
+        message: String,
+    },
+
+    #[error("config.options.generated_file_header should not be a multi-line string.")]
+    MultiLineGeneratedFileHeader,
+}
+
+impl HasDiagnosticCode for ConfigError {
+    fn diagnostic_code(&self) -> DiagnosticCode {
+        match self {
+            ConfigError::UnableToReadConfig { .. } => DiagnosticCode(1001),
+            ConfigError::UnableToParseConfig { .. } => DiagnosticCode(1002),
+            ConfigError::ConfigNotFound { .. } => DiagnosticCode(1003),
+            ConfigError::NoProjects => DiagnosticCode(1004),
+            ConfigError::SharedArtifactDirectory { .. } => DiagnosticCode(1005),
+            ConfigError::UnableToCreateDirectory { .. } => DiagnosticCode(1006),
+            ConfigError::FileNotFound { .. } => DiagnosticCode(1007),
+            ConfigError::MultiLineGeneratedFileHeader => DiagnosticCode(1008),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use common_lang_types::{Span, TextSource};
+    use intern::string_key::Intern;
+    use isograph_test_utils::TestProjectDirectory;
+
+    use super::{
+        artifact_import_path, create_refetch_field, find_config_location, parse_config_file,
+        ConfigFileContents, ConfigFileFormat, IsographConfigFile, RefetchFieldArgumentValue,
+    };
+
+    #[test]
+    fn artifact_import_path_is_relative_to_generated_files() {
+        assert_eq!(
+            artifact_import_path(
+                &Path::new("/project").join("./src/../types/scalars"),
+                Path::new("/project/src/__isograph")
+            ),
+            "../../../../types/scalars"
+        );
+    }
+
+    #[test]
+    fn config_file_with_projects_key_is_multi_project() {
+        let config_file = parse_config_file(
+            r#"{
+                "projects": [
+                    { "project_root": "./app1", "schema": "./schema1.graphql" },
+                    { "project_root": "./app2", "schema": "./schema2.graphql" }
+                ]
+            }"#,
+            ConfigFileFormat::Json,
+        )
+        .unwrap();
+        assert!(
+            matches!(config_file, IsographConfigFile::MultiProject(config) if config.projects.len() == 2)
+        );
+
+        let config_file = parse_config_file(
+            r#"{ "project_root": "./app", "schema": "./schema.graphql" }"#,
+            ConfigFileFormat::Json,
+        )
+        .unwrap();
+        assert!(matches!(config_file, IsographConfigFile::SingleProject(_)));
+
+        let error = parse_config_file(
+            r#"{ "projects": [{ "schema": "./schema.graphql" }] }"#,
+            ConfigFileFormat::Json,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(error.message, "missing field `project_root`");
+    }
+
+    #[test]
+    fn parse_errors_underline_the_offending_key_or_value() {
+        let underlined = |config_contents: &str, format: ConfigFileFormat| {
+            let error = parse_config_file(config_contents, format).err().unwrap();
+            (
+                error.message,
+                config_contents[error.span.start as usize..error.span.end as usize].to_string(),
+            )
+        };
+
+        assert_eq!(
+            underlined("{\n  \"project_rot\": \"./src\"\n}", ConfigFileFormat::Json),
+            (
+                "unknown field `project_rot`, expected one of `$schema`, `project_root`, \
+                `artifact_directory`, `schema`, `schema_extensions`, `options`"
+                    .to_string(),
+                "\"project_rot\"".to_string()
+            )
+        );
+        assert_eq!(
+            underlined(
+                "{\n  \"project_root\": \"./src\",\n  \"options\": { \"no_babel_transform\": 1 }\n}",
+                ConfigFileFormat::Json
+            ),
+            (
+                "invalid type: integer `1`, expected a boolean".to_string(),
+                "1".to_string()
+            )
+        );
+        assert_eq!(
+            underlined(
+                "{\n  \"name\": \"app\",\n  \"isograph\": { \"project_root\": 1 }\n}",
+                ConfigFileFormat::PackageJson
+            ),
+            (
+                "invalid type: integer `1`, expected path string".to_string(),
+                "1".to_string()
+            )
+        );
+        assert_eq!(
+            underlined(
+                "project_root = \"./src\"\nschema = \"./schema.graphql\"\n\n[options]\nno_babel_transform = 1\n",
+                ConfigFileFormat::Toml
+            ),
+            (
+                "invalid type: integer `1`, expected a boolean".to_string(),
+                "1".to_string()
+            )
+        );
+        assert_eq!(
+            parse_config_file("", ConfigFileFormat::Json)
+                .err()
+                .unwrap()
+                .span,
+            Span::new(0, 0)
+        );
+    }
+
+    #[test]
+    fn map_option_keys_are_found_after_the_option_name() {
+        let key_span = |contents: &str, key: &str| {
+            let config_file_contents = ConfigFileContents {
+                text_source: TextSource {
+                    current_working_directory: "/project".intern().into(),
+                    relative_path_to_source_file: "isograph.config.json".intern().into(),
+                    span: None,
+                },
+                contents,
+            };
+            config_file_contents
+                .map_option_key_location("refetch_from_root", key)
+                .span()
+                .unwrap()
+        };
+        let span_of = |contents: &str, text: &str| {
+            let start = contents.rfind(text).unwrap();
+            Span::new(start as u32, (start + text.len()) as u32)
+        };
+
+        // Keys of other options, and keys that merely start with the key, are skipped
+        let json = r#"{
+            "options": {
+                "id_fields": { "Viewer": "uuid" },
+                "refetch_from_root": { "ViewerSettings": "viewer.settings", "Viewer": "viewer" }
+            }
+        }"#;
+        assert_eq!(key_span(json, "Viewer"), span_of(json, "Viewer"));
+        assert_eq!(key_span(json, "Account"), Span::new(0, 0));
+
+        let toml = "[options.refetch_from_root]\n\
+            ViewerSettings = \"viewer.settings\"\n\
+            Viewer = \"viewer\"\n";
+        assert_eq!(key_span(toml, "Viewer"), span_of(toml, "Viewer"));
+    }
+
+    #[test]
+    fn config_is_read_from_package_json_and_toml() {
+        let config_file = parse_config_file(
+            r#"{ "name": "app", "isograph": { "project_root": "./src", "schema": "./schema.graphql" } }"#,
+            ConfigFileFormat::PackageJson,
+        )
+        .unwrap();
+        assert!(
+            matches!(config_file, IsographConfigFile::SingleProject(config) if config.project_root == Path::new("./src"))
+        );
+
+        let config_file = parse_config_file(
+            r#"
+                [[projects]]
+                project_root = "./app1"
+                schema = "./schema1.graphql"
+
+                [[projects]]
+                project_root = "./app2"
+                schema = "./schema2.graphql"
+
+                [projects.options]
+                on_invalid_id_type = "warn"
+            "#,
+            ConfigFileFormat::Toml,
+        )
+        .unwrap();
+        assert!(
+            matches!(config_file, IsographConfigFile::MultiProject(config) if config.projects.len() == 2)
+        );
+    }
+
+    #[test]
+    fn refetch_field_arguments_are_passed_the_id_or_typename() {
+        let config_file = parse_config_file(
+            r#"{
+                "project_root": "./src",
+                "schema": "./schema.graphql",
+                "options": {
+                    "refetch_field": {
+                        "name": "entity",
+                        "arguments": { "kind": "typename", "id": "id" }
+                    }
+                }
+            }"#,
+            ConfigFileFormat::Json,
+        )
+        .unwrap();
+        let IsographConfigFile::SingleProject(config) = config_file else {
+            panic!("Expected a single project config");
+        };
+        let refetch_field = create_refetch_field(config.options.refetch_field.unwrap());
+        assert_eq!(refetch_field.name, "entity");
+        assert_eq!(
+            refetch_field
+                .arguments
+                .iter()
+                .map(|(name, value)| (name.to_string(), *value))
+                .collect::<Vec<_>>(),
+            vec![
+                ("id".to_string(), RefetchFieldArgumentValue::Id),
+                ("kind".to_string(), RefetchFieldArgumentValue::Typename),
+            ]
+        );
+
+        let error = parse_config_file(
+            r#"{
+                "project_root": "./src",
+                "schema": "./schema.graphql",
+                "options": {
+                    "refetch_field": { "name": "entity", "arguments": { "id": "uuid" } }
+                }
+            }"#,
+            ConfigFileFormat::Json,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.message,
+            "unknown variant `uuid`, expected `id` or `typename`"
+        );
+    }
+
+    #[test]
+    fn config_is_found_in_parent_directories() {
+        let directory = TestProjectDirectory::new(
+            "find_config_location",
+            // A package.json without an isograph key is skipped
+            &[("packages/app/package.json", r#"{ "name": "app" }"#)],
+        );
+        let root = directory.path();
+        let nested = root.join("packages/app/src");
+        std::fs::create_dir_all(&nested).unwrap();
+
+        directory.write("isograph.config.toml", "");
+        assert_eq!(
+            find_config_location(&nested).unwrap(),
+            root.join("isograph.config.toml")
+        );
+
+        directory.write(
+            "packages/app/package.json",
+            r#"{ "name": "app", "isograph": {} }"#,
+        );
+        assert_eq!(
+            find_config_location(&nested).unwrap(),
+            root.join("packages/app/package.json")
+        );
+    }
+}
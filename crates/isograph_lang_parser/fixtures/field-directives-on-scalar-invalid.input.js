@@ -24,3 +24,9 @@ export const BasicField4 = iso(`
     }
   }
 `)();
+
+export const BasicField5 = iso(`
+  field Type.Name {
+    scalar @loadable(lazyLoadArtifact: yes)
+  }
+`)();
@@ -16,6 +16,12 @@ export const BasicField3 = iso(`
   }
 `)();
 
+export const BasicField4 = iso(`
+  field Type.Name {
+    scalar @loadable(defer: true)
+  }
+`)();
+
 export const updatable = iso(`
   field Type.Name {
     scalar @updatable
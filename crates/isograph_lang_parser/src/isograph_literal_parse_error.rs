@@ -1,4 +1,7 @@
-use common_lang_types::{SelectableName, SelectableNameOrAlias, WithLocation, WithSpan};
+use common_lang_types::{
+    DiagnosticCode, HasDiagnosticCode, SelectableName, SelectableNameOrAlias, WithLocation,
+    WithSpan,
+};
 use isograph_lang_types::DeserializationError;
 use thiserror::Error;
 
@@ -73,9 +76,6 @@ pub enum IsographLiteralParseError {
         name_or_alias: SelectableNameOrAlias,
     },
 
-    #[error("Expected a boolean value (true or false).")]
-    ExpectedBoolean,
-
     #[error("Expected delimited `{delimiter} or `{closing_token}`")]
     ExpectedDelimiterOrClosingToken {
         closing_token: IsographLangTokenKind,
@@ -86,6 +86,31 @@ pub enum IsographLiteralParseError {
     UnableToDeserializeDirectives { message: DeserializationError },
 }
 
+impl HasDiagnosticCode for IsographLiteralParseError {
+    fn diagnostic_code(&self) -> DiagnosticCode {
+        match self {
+            IsographLiteralParseError::ParseError { .. } => DiagnosticCode(101),
+            IsographLiteralParseError::ExpectedTypeAnnotation => DiagnosticCode(102),
+            IsographLiteralParseError::LeftoverTokens => DiagnosticCode(103),
+            IsographLiteralParseError::ExpectedAssociatedJsFunction => DiagnosticCode(104),
+            IsographLiteralParseError::ExpectedFieldOrPointerOrEntrypoint => DiagnosticCode(105),
+            IsographLiteralParseError::ExpectedTo => DiagnosticCode(106),
+            IsographLiteralParseError::ExpectedLiteralToBeExported { .. } => DiagnosticCode(107),
+            IsographLiteralParseError::ExpectedNonConstantValue => DiagnosticCode(108),
+            IsographLiteralParseError::UnexpectedVariable => DiagnosticCode(109),
+            IsographLiteralParseError::DescriptionsAreDisallowed => DiagnosticCode(110),
+            IsographLiteralParseError::ExpectedCommaOrLineBreak => DiagnosticCode(111),
+            IsographLiteralParseError::ExpectedSelectionSet => DiagnosticCode(112),
+            IsographLiteralParseError::ExpectedParenthesesAroundIsoLiteral => DiagnosticCode(113),
+            IsographLiteralParseError::DuplicateNameOrAlias { .. } => DiagnosticCode(114),
+            IsographLiteralParseError::ExpectedDelimiterOrClosingToken { .. } => {
+                DiagnosticCode(115)
+            }
+            IsographLiteralParseError::UnableToDeserializeDirectives { .. } => DiagnosticCode(116),
+        }
+    }
+}
+
 impl From<LowLevelParseError> for IsographLiteralParseError {
     fn from(error: LowLevelParseError) -> Self {
         IsographLiteralParseError::ParseError { error }
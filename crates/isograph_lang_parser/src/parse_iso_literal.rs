@@ -627,22 +627,16 @@ fn parse_non_constant_value(
         })?;
 
         to_control_flow::<_, WithSpan<IsographLiteralParseError>>(|| {
-            let bool_or_null = tokens
+            let identifier = tokens
                 .parse_source_of_kind(IsographLangTokenKind::Identifier)
                 .map_err(|with_span| with_span.map(IsographLiteralParseError::from))?;
 
-            let span = bool_or_null.span;
-
-            bool_or_null.and_then(|bool_or_null| match bool_or_null {
-                "null" => Ok(NonConstantValue::Null),
-                bool => match bool.parse::<bool>() {
-                    Ok(b) => Ok(NonConstantValue::Boolean(b)),
-                    Err(_) => Err(WithSpan::new(
-                        IsographLiteralParseError::ExpectedBoolean,
-                        span,
-                    )),
-                },
-            })
+            Ok(identifier.map(|identifier| match identifier {
+                "null" => NonConstantValue::Null,
+                "true" => NonConstantValue::Boolean(true),
+                "false" => NonConstantValue::Boolean(false),
+                enum_literal => NonConstantValue::Enum(enum_literal.intern().into()),
+            }))
         })?;
 
         ControlFlow::Continue(WithSpan::new(
@@ -824,8 +818,13 @@ fn from_control_flow<T, E>(control_flow: impl FnOnce() -> ControlFlow<T, E>) ->
 
 #[cfg(test)]
 mod test {
+    use common_lang_types::TextSource;
+    use intern::string_key::Intern;
+    use isograph_lang_types::NonConstantValue;
 
-    use crate::{IsographLangTokenKind, PeekableLexer};
+    use crate::{IsographLangTokenKind, IsographLiteralParseError, PeekableLexer};
+
+    use super::{parse_iso_literal, parse_non_constant_value};
 
     #[test]
     fn parse_literal_tests() {
@@ -839,4 +838,51 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn parse_identifier_values() {
+        let text_source = TextSource {
+            current_working_directory: "".intern().into(),
+            relative_path_to_source_file: "".intern().into(),
+            span: None,
+        };
+        let parse = |source| {
+            parse_non_constant_value(&mut PeekableLexer::new(source), text_source)
+                .expect("Expected value to parse")
+                .item
+        };
+
+        assert_eq!(parse("null"), NonConstantValue::Null);
+        assert_eq!(parse("true"), NonConstantValue::Boolean(true));
+        assert_eq!(parse("false"), NonConstantValue::Boolean(false));
+        assert_eq!(
+            parse("ADMIN"),
+            NonConstantValue::Enum("ADMIN".intern().into())
+        );
+    }
+
+    #[test]
+    fn enum_values_passed_to_directives_are_reported() {
+        let text_source = TextSource {
+            current_working_directory: "".intern().into(),
+            relative_path_to_source_file: "".intern().into(),
+            span: None,
+        };
+        let error = parse_iso_literal(
+            "field Query.Foo { bar @loadable(lazyLoadArtifact: yes) }",
+            "".intern().into(),
+            Some("Foo"),
+            text_source,
+        )
+        .expect_err("Expected directive with an enum argument to fail to deserialize");
+
+        assert!(matches!(
+            error.item,
+            IsographLiteralParseError::UnableToDeserializeDirectives { .. }
+        ));
+        assert!(error
+            .item
+            .to_string()
+            .contains("invalid type: enum value yes"));
+    }
 }
@@ -61,6 +61,12 @@ pub struct LoadableDirectiveParameters {
     complete_selection_set: bool,
     #[serde(default)]
     pub lazy_load_artifact: bool,
+    /// If true, the field's selection set is also selected in the parent query, under
+    /// `... @defer(label: "Type__field")`, so that the server can stream the field's
+    /// data in the same round-trip as the parent query. All of the field's arguments
+    /// must then be provided when it is selected.
+    #[serde(default)]
+    pub defer: bool,
 }
 
 pub type SelectionTypeContainingSelections<TScalarField, TLinkedField> =
@@ -162,7 +162,10 @@ impl<'de> Deserializer<'de> for NonConstantValueDeserializer<'de> {
             NonConstantValue::String(s) => visitor.visit_str(s.lookup()),
             NonConstantValue::Float(f) => visitor.visit_f64(f.as_float()),
             NonConstantValue::Null => visitor.visit_none(),
-            NonConstantValue::Enum(_) => panic!("Enums not supported when deserializing"),
+            NonConstantValue::Enum(enum_literal_value) => Err(de::Error::invalid_type(
+                de::Unexpected::Other(&format!("enum value {enum_literal_value}")),
+                &visitor,
+            )),
             NonConstantValue::List(_) => {
                 panic!("Deserializing from lists is not yet supported here.")
             }
@@ -10,10 +10,32 @@ pub struct UpdatableDirectiveParameters {}
 #[serde(rename_all = "camelCase", untagged)]
 pub enum ScalarSelectionDirectiveSet {
     Loadable(LoadableDirectiveSet),
+    Connection(ConnectionDirectiveSet),
     Updatable(UpdatableDirectiveSet),
     None(EmptyDirectiveSet),
 }
 
+impl ScalarSelectionDirectiveSet {
+    /// The parameters of @loadable or @connection, if the selection is loadable
+    pub fn loadable_directive_parameters(&self) -> Option<&LoadableDirectiveParameters> {
+        match self {
+            ScalarSelectionDirectiveSet::Loadable(l) => Some(&l.loadable),
+            ScalarSelectionDirectiveSet::Connection(c) => Some(&c.connection),
+            ScalarSelectionDirectiveSet::Updatable(_) | ScalarSelectionDirectiveSet::None(_) => {
+                None
+            }
+        }
+    }
+
+    /// Whether arguments that are not provided in the selection are provided at
+    /// runtime, when the field is fetched. This is the case for loadable fields that
+    /// are not deferred (since deferred fields are fetched as part of the parent query.)
+    pub fn arguments_can_be_provided_at_runtime(&self) -> bool {
+        self.loadable_directive_parameters()
+            .is_some_and(|parameters| !parameters.defer)
+    }
+}
+
 #[derive(Deserialize, Debug, Clone, PartialEq, PartialOrd, Ord, Eq, Copy, Hash)]
 #[serde(rename_all = "camelCase", untagged)]
 pub enum ObjectSelectionDirectiveSet {
@@ -33,6 +55,14 @@ pub struct LoadableDirectiveSet {
     pub loadable: LoadableDirectiveParameters,
 }
 
+/// A connection field is selected loadably, and thus accepts the same parameters
+/// as @loadable.
+#[derive(Deserialize, Debug, Default, Clone, PartialEq, PartialOrd, Ord, Eq, Copy, Hash)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct ConnectionDirectiveSet {
+    pub connection: LoadableDirectiveParameters,
+}
+
 // No directives -> an EmptyStruct is parsed!
 #[derive(Deserialize, Debug, Default, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
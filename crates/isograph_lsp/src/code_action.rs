@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use lsp_types::{
+    request::{CodeActionRequest, Request},
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams, Diagnostic, TextEdit, Url,
+    WorkspaceEdit,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    diagnostics::DIAGNOSTIC_SOURCE, lsp_runtime_error::LSPRuntimeResult, lsp_state::LSPState,
+};
+
+/// The data that is attached to a published diagnostic. The client sends it back when
+/// requesting code actions for the diagnostic, so that we don't need to recompile.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub(crate) struct DiagnosticData {
+    /// A replacement for the text in the diagnostic's range.
+    pub suggestion: String,
+}
+
+pub fn on_code_action(
+    _state: &mut LSPState,
+    params: <CodeActionRequest as Request>::Params,
+) -> LSPRuntimeResult<<CodeActionRequest as Request>::Result> {
+    let CodeActionParams {
+        text_document,
+        context,
+        ..
+    } = params;
+
+    let code_actions = context
+        .diagnostics
+        .into_iter()
+        .filter_map(|diagnostic| quick_fix(&text_document.uri, diagnostic))
+        .map(CodeActionOrCommand::CodeAction)
+        .collect::<Vec<_>>();
+
+    Ok(Some(code_actions))
+}
+
+/// A quick fix that replaces the text in the diagnostic's range with the diagnostic's
+/// suggestion, if it has one.
+fn quick_fix(uri: &Url, diagnostic: Diagnostic) -> Option<CodeAction> {
+    if diagnostic.source.as_deref() != Some(DIAGNOSTIC_SOURCE) {
+        return None;
+    }
+    let DiagnosticData { suggestion } = serde_json::from_value(diagnostic.data.clone()?).ok()?;
+
+    let changes = HashMap::from([(
+        uri.clone(),
+        vec![TextEdit {
+            range: diagnostic.range,
+            new_text: suggestion.clone(),
+        }],
+    )]);
+
+    Some(CodeAction {
+        title: format!("Replace with `{suggestion}`"),
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![diagnostic]),
+        edit: Some(WorkspaceEdit {
+            changes: Some(changes),
+            ..Default::default()
+        }),
+        is_preferred: Some(true),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::{Position, Range};
+
+    use super::*;
+
+    #[test]
+    fn quick_fix_replaces_the_diagnostic_range_with_the_suggestion() {
+        let uri = Url::parse("file:///project/src/Example.tsx").unwrap();
+        let range = Range::new(Position::new(3, 4), Position::new(3, 12));
+        let diagnostic = Diagnostic {
+            range,
+            source: Some(DIAGNOSTIC_SOURCE.to_string()),
+            data: Some(
+                serde_json::to_value(DiagnosticData {
+                    suggestion: "fullName".to_string(),
+                })
+                .unwrap(),
+            ),
+            ..Default::default()
+        };
+
+        let code_action = quick_fix(&uri, diagnostic).expect("Expected a quick fix");
+        assert_eq!(code_action.title, "Replace with `fullName`");
+        assert_eq!(
+            code_action.edit.unwrap().changes.unwrap()[&uri],
+            vec![TextEdit {
+                range,
+                new_text: "fullName".to_string(),
+            }]
+        );
+
+        let diagnostic_without_suggestion = Diagnostic {
+            range,
+            source: Some(DIAGNOSTIC_SOURCE.to_string()),
+            ..Default::default()
+        };
+        assert_eq!(quick_fix(&uri, diagnostic_without_suggestion), None);
+    }
+}
@@ -0,0 +1,528 @@
+use common_lang_types::{
+    IsographDirectiveName, ObjectSelectableName, SelectableName, UnvalidatedTypeName, VariableName,
+};
+use intern::string_key::{Intern, Lookup};
+use isograph_lang_parser::IsographLangTokenKind;
+use isograph_lang_types::{DefinitionLocation, SelectionType, ServerEntityId, VariableDefinition};
+use isograph_schema::{NetworkProtocol, Schema, SelectableId};
+use logos::Logos;
+use lsp_types::{
+    request::{Completion, Request},
+    CompletionItem, CompletionItemKind, CompletionParams, CompletionResponse, Documentation,
+    MarkupContent, MarkupKind,
+};
+
+use crate::{
+    hover::{format_argument, selectable_signature},
+    location_utils::position_to_index,
+    lsp_runtime_error::{LSPRuntimeError, LSPRuntimeResult},
+    lsp_state::LSPState,
+    selection_at_position::{
+        iso_literal_text_at_index, object_entity_id_at_path, selectable_id_on_object,
+    },
+};
+
+/// The directives accepted by ClientFieldDirectiveSet
+const CLIENT_FIELD_DIRECTIVES: &[&str] = &["component"];
+/// The directives accepted by ScalarSelectionDirectiveSet
+const SCALAR_SELECTION_DIRECTIVES: &[&str] = &["loadable", "connection", "updatable"];
+/// The directives accepted by ObjectSelectionDirectiveSet
+const OBJECT_SELECTION_DIRECTIVES: &[&str] = &["updatable"];
+/// The parameters accepted by @loadable and @connection (LoadableDirectiveParameters)
+const LOADABLE_DIRECTIVE_PARAMETERS: &[&str] =
+    &["lazyLoadArtifact", "completeSelectionSet", "defer"];
+
+pub fn on_completion<TNetworkProtocol: NetworkProtocol>(
+    state: &mut LSPState,
+    params: <Completion as Request>::Params,
+) -> LSPRuntimeResult<<Completion as Request>::Result> {
+    let CompletionParams {
+        text_document_position,
+        work_done_progress_params: _,
+        partial_result_params: _,
+        context: _,
+    } = params;
+
+    let file_text = state
+        .text_for_uri_or_disk(&text_document_position.text_document.uri)
+        .ok_or(LSPRuntimeError::ExpectedError)?;
+    let index = position_to_index(&file_text, text_document_position.position)
+        .ok_or(LSPRuntimeError::ExpectedError)?;
+
+    // The iso literal is usually incomplete while the user is typing, so we cannot
+    // parse it. Instead, we determine what is being completed from the tokens that
+    // precede the cursor.
+    let (start, iso_literal_text) =
+        iso_literal_text_at_index(&file_text, index).ok_or(LSPRuntimeError::ExpectedError)?;
+    let completion_context = completion_context(&iso_literal_text[..index - start])
+        .ok_or(LSPRuntimeError::ExpectedError)?;
+
+    let schema = state.schema::<TNetworkProtocol>()?;
+    let items =
+        completion_items(&schema, completion_context).ok_or(LSPRuntimeError::ExpectedError)?;
+
+    Ok(Some(CompletionResponse::Array(items)))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct SelectionSetPath {
+    /// The parent type of the client field or pointer being declared
+    pub(crate) parent_type: UnvalidatedTypeName,
+    /// The linked fields that must be traversed, starting at the parent type,
+    /// to reach the selection set containing the cursor
+    pub(crate) path: Vec<ObjectSelectableName>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum CompletionContext {
+    /// The cursor is in a selection set, where a field can be selected.
+    Selectable(SelectionSetPath),
+    /// The cursor is within the parentheses following a selection, where an
+    /// argument name can be written.
+    Argument {
+        selection_set_path: SelectionSetPath,
+        field_name: SelectableName,
+        provided_arguments: Vec<VariableName>,
+    },
+    /// The cursor is within the parentheses following a directive, where a
+    /// parameter name can be written.
+    DirectiveArgument {
+        directive_name: IsographDirectiveName,
+        provided_arguments: Vec<VariableName>,
+    },
+    /// The cursor follows an @ after a selection.
+    SelectionDirective {
+        selection_set_path: SelectionSetPath,
+        field_name: SelectableName,
+    },
+    /// The cursor follows an @ after the name of the client field being declared.
+    ClientFieldDirective,
+}
+
+pub(crate) fn completion_context(text_before_cursor: &str) -> Option<CompletionContext> {
+    let mut tokens = IsographLangTokenKind::lexer(text_before_cursor)
+        .spanned()
+        .map(|(kind, span)| (kind, &text_before_cursor[span.clone()], span.end))
+        .collect::<Vec<_>>();
+
+    // If the cursor immediately follows an identifier, that identifier is being typed,
+    // and will be replaced by the completion. So it tells us nothing about the context.
+    if let Some((IsographLangTokenKind::Identifier, _, end)) = tokens.last() {
+        if *end == text_before_cursor.len() {
+            tokens.pop();
+        }
+    }
+
+    let mut tokens = tokens.into_iter().map(|(kind, source, _)| (kind, source));
+    let (keyword, parent_type) = match (tokens.next()?, tokens.next()?) {
+        (
+            (IsographLangTokenKind::Identifier, keyword),
+            (IsographLangTokenKind::Identifier, parent_type),
+        ) => (keyword, parent_type),
+        _ => return None,
+    };
+    if keyword != "field" && keyword != "pointer" {
+        return None;
+    }
+
+    // The first item is the name of the linked field whose selection set was opened,
+    // or None for the top-level selection set.
+    let mut open_selection_sets: Vec<Option<&str>> = vec![];
+    let mut last_field_name: Option<&str> = None;
+    let mut paren_depth = 0;
+    let mut arguments_field_name: Option<&str> = None;
+    let mut last_directive_name: Option<&str> = None;
+    let mut arguments_directive_name: Option<&str> = None;
+    let mut provided_arguments = vec![];
+    let mut in_argument_value = false;
+    let mut previous_token: Option<(IsographLangTokenKind, &str)> = None;
+
+    for (kind, source) in tokens {
+        match kind {
+            IsographLangTokenKind::OpenParen => {
+                if paren_depth == 0 {
+                    // Parentheses that follow a directive contain its parameters,
+                    // not the field's arguments.
+                    arguments_directive_name = match previous_token {
+                        Some((IsographLangTokenKind::Identifier, _)) => last_directive_name,
+                        _ => None,
+                    };
+                    arguments_field_name = last_field_name;
+                    provided_arguments.clear();
+                    in_argument_value = false;
+                }
+                paren_depth += 1;
+            }
+            IsographLangTokenKind::CloseParen => {
+                paren_depth = std::cmp::max(paren_depth - 1, 0);
+            }
+            IsographLangTokenKind::Colon if paren_depth == 1 => {
+                in_argument_value = true;
+                if let Some((IsographLangTokenKind::Identifier, argument_name)) = previous_token {
+                    provided_arguments.push(argument_name);
+                }
+            }
+            IsographLangTokenKind::Comma if paren_depth == 1 => {
+                in_argument_value = false;
+            }
+            _ if paren_depth > 0 => {}
+            IsographLangTokenKind::OpenBrace => {
+                let linked_field_name = if open_selection_sets.is_empty() {
+                    None
+                } else {
+                    Some(last_field_name?)
+                };
+                open_selection_sets.push(linked_field_name);
+                last_field_name = None;
+            }
+            IsographLangTokenKind::CloseBrace => {
+                open_selection_sets.pop();
+                last_field_name = None;
+            }
+            IsographLangTokenKind::Identifier => {
+                let is_directive = matches!(previous_token, Some((IsographLangTokenKind::At, _)));
+                last_directive_name = is_directive.then_some(source);
+                if !is_directive && !open_selection_sets.is_empty() {
+                    last_field_name = Some(source);
+                }
+            }
+            _ => {}
+        }
+        previous_token = Some((kind, source));
+    }
+
+    let selection_set_path = SelectionSetPath {
+        parent_type: parent_type.intern().into(),
+        path: open_selection_sets
+            .iter()
+            .skip(1)
+            .filter_map(|name| name.map(|name| name.intern().into()))
+            .collect(),
+    };
+
+    if paren_depth > 0 {
+        if in_argument_value {
+            return None;
+        }
+        let provided_arguments = provided_arguments
+            .into_iter()
+            .map(|name| name.intern().into())
+            .collect();
+        if let Some(directive_name) = arguments_directive_name {
+            return Some(CompletionContext::DirectiveArgument {
+                directive_name: directive_name.intern().into(),
+                provided_arguments,
+            });
+        }
+        return Some(CompletionContext::Argument {
+            selection_set_path,
+            field_name: arguments_field_name?.intern().into(),
+            provided_arguments,
+        });
+    }
+
+    if matches!(previous_token, Some((IsographLangTokenKind::At, _))) {
+        return if open_selection_sets.is_empty() {
+            Some(CompletionContext::ClientFieldDirective)
+        } else {
+            Some(CompletionContext::SelectionDirective {
+                selection_set_path,
+                field_name: last_field_name?.intern().into(),
+            })
+        };
+    }
+
+    if open_selection_sets.is_empty() {
+        return None;
+    }
+    Some(CompletionContext::Selectable(selection_set_path))
+}
+
+fn completion_items<TNetworkProtocol: NetworkProtocol>(
+    schema: &Schema<TNetworkProtocol>,
+    completion_context: CompletionContext,
+) -> Option<Vec<CompletionItem>> {
+    match completion_context {
+        CompletionContext::Selectable(selection_set_path) => {
+            let object_entity_id = object_entity_id_at_path(
+                schema,
+                selection_set_path.parent_type,
+                &selection_set_path.path,
+            )?;
+            let available_selectables = &schema
+                .server_entity_data
+                .server_object_entity_available_selectables
+                .get(&object_entity_id)?
+                .0;
+            Some(
+                available_selectables
+                    .iter()
+                    .map(|(name, selectable_id)| {
+                        selectable_completion_item(schema, *name, *selectable_id)
+                    })
+                    .collect(),
+            )
+        }
+        CompletionContext::Argument {
+            selection_set_path,
+            field_name,
+            provided_arguments,
+        } => {
+            let object_entity_id = object_entity_id_at_path(
+                schema,
+                selection_set_path.parent_type,
+                &selection_set_path.path,
+            )?;
+            let selectable_id = selectable_id_on_object(schema, object_entity_id, field_name)?;
+            Some(
+                selectable_arguments(schema, selectable_id)
+                    .into_iter()
+                    .filter(|argument| !provided_arguments.contains(&argument.name.item))
+                    .map(|argument| CompletionItem {
+                        label: argument.name.item.to_string(),
+                        kind: Some(CompletionItemKind::VARIABLE),
+                        detail: Some(format_argument(schema, argument)),
+                        insert_text: Some(format!("{}: ", argument.name.item)),
+                        ..Default::default()
+                    })
+                    .collect(),
+            )
+        }
+        CompletionContext::DirectiveArgument {
+            directive_name,
+            provided_arguments,
+        } => Some(
+            directive_parameters(directive_name)
+                .iter()
+                .filter(|parameter| !provided_arguments.contains(&parameter.intern().into()))
+                .map(|parameter| CompletionItem {
+                    label: parameter.to_string(),
+                    kind: Some(CompletionItemKind::VARIABLE),
+                    detail: Some(format!("{parameter}: Boolean")),
+                    insert_text: Some(format!("{parameter}: ")),
+                    ..Default::default()
+                })
+                .collect(),
+        ),
+        CompletionContext::SelectionDirective {
+            selection_set_path,
+            field_name,
+        } => {
+            let object_entity_id = object_entity_id_at_path(
+                schema,
+                selection_set_path.parent_type,
+                &selection_set_path.path,
+            )?;
+            let directives =
+                match selectable_id_on_object(schema, object_entity_id, field_name)?.transpose() {
+                    SelectionType::Scalar(_) => SCALAR_SELECTION_DIRECTIVES,
+                    SelectionType::Object(_) => OBJECT_SELECTION_DIRECTIVES,
+                };
+            Some(directive_completion_items(directives))
+        }
+        CompletionContext::ClientFieldDirective => {
+            Some(directive_completion_items(CLIENT_FIELD_DIRECTIVES))
+        }
+    }
+}
+
+fn selectable_completion_item<TNetworkProtocol: NetworkProtocol>(
+    schema: &Schema<TNetworkProtocol>,
+    name: SelectableName,
+    selectable_id: SelectableId,
+) -> CompletionItem {
+    let (signature, description) = selectable_signature(schema, selectable_id);
+    let kind = match selectable_id {
+        DefinitionLocation::Server(_) => CompletionItemKind::FIELD,
+        DefinitionLocation::Client(_) => CompletionItemKind::FUNCTION,
+    };
+    CompletionItem {
+        label: name.to_string(),
+        kind: Some(kind),
+        detail: Some(signature),
+        documentation: description.map(|description| {
+            Documentation::MarkupContent(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: description.to_string(),
+            })
+        }),
+        ..Default::default()
+    }
+}
+
+fn directive_completion_items(directives: &[&str]) -> Vec<CompletionItem> {
+    directives
+        .iter()
+        .map(|directive| CompletionItem {
+            label: directive.to_string(),
+            kind: Some(CompletionItemKind::KEYWORD),
+            ..Default::default()
+        })
+        .collect()
+}
+
+fn directive_parameters(directive_name: IsographDirectiveName) -> &'static [&'static str] {
+    match directive_name.lookup() {
+        "loadable" | "connection" => LOADABLE_DIRECTIVE_PARAMETERS,
+        _ => &[],
+    }
+}
+
+fn selectable_arguments<TNetworkProtocol: NetworkProtocol>(
+    schema: &Schema<TNetworkProtocol>,
+    selectable_id: SelectableId,
+) -> Vec<&VariableDefinition<ServerEntityId>> {
+    match selectable_id {
+        DefinitionLocation::Server(SelectionType::Scalar(scalar_selectable_id)) => schema
+            .server_scalar_selectable(scalar_selectable_id)
+            .arguments
+            .iter()
+            .map(|argument| &argument.item)
+            .collect(),
+        DefinitionLocation::Server(SelectionType::Object(object_selectable_id)) => schema
+            .server_object_selectable(object_selectable_id)
+            .arguments
+            .iter()
+            .map(|argument| &argument.item)
+            .collect(),
+        DefinitionLocation::Client(SelectionType::Scalar(client_field_id)) => schema
+            .client_field(client_field_id)
+            .variable_definitions
+            .iter()
+            .map(|argument| &argument.item)
+            .collect(),
+        DefinitionLocation::Client(SelectionType::Object(client_pointer_id)) => schema
+            .client_pointer(client_pointer_id)
+            .variable_definitions
+            .iter()
+            .map(|argument| &argument.item)
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use graphql_network_protocol::GraphQLNetworkProtocol;
+    use intern::string_key::Intern;
+    use lsp_types::{TextDocumentIdentifier, TextDocumentPositionParams};
+
+    use super::*;
+    use crate::{location_utils::index_to_position, lsp_state::tests::TestProject};
+
+    fn selection_set_path(parent_type: &str, path: &[&str]) -> SelectionSetPath {
+        SelectionSetPath {
+            parent_type: parent_type.intern().into(),
+            path: path.iter().map(|name| name.intern().into()).collect(),
+        }
+    }
+
+    #[test]
+    fn completes_selectables_in_nested_selection_sets() {
+        assert_eq!(
+            completion_context("field User.Foo @component {\n  name\n  ava"),
+            Some(CompletionContext::Selectable(selection_set_path(
+                "User",
+                &[]
+            )))
+        );
+        assert_eq!(
+            completion_context("field User.Foo {\n  friend: bestFriend @updatable {\n    "),
+            Some(CompletionContext::Selectable(selection_set_path(
+                "User",
+                &["bestFriend"]
+            )))
+        );
+        assert_eq!(
+            completion_context("field User.Foo {\n  bestFriend {\n    name\n  }\n  "),
+            Some(CompletionContext::Selectable(selection_set_path(
+                "User",
+                &[]
+            )))
+        );
+        assert_eq!(completion_context("field User.Fo"), None);
+        assert_eq!(completion_context("entrypoint Query.Foo"), None);
+    }
+
+    #[test]
+    fn completes_arguments_and_directives() {
+        assert_eq!(
+            completion_context("field User.Foo {\n  friends(first: 10, "),
+            Some(CompletionContext::Argument {
+                selection_set_path: selection_set_path("User", &[]),
+                field_name: "friends".intern().into(),
+                provided_arguments: vec!["first".intern().into()],
+            })
+        );
+        assert_eq!(
+            completion_context("field User.Foo {\n  friends(first: "),
+            None
+        );
+        assert_eq!(
+            completion_context("field User.Foo {\n  avatar @lo"),
+            Some(CompletionContext::SelectionDirective {
+                selection_set_path: selection_set_path("User", &[]),
+                field_name: "avatar".intern().into(),
+            })
+        );
+        assert_eq!(
+            completion_context("field User.Foo @"),
+            Some(CompletionContext::ClientFieldDirective)
+        );
+    }
+
+    #[test]
+    fn completes_directive_parameters_within_directive_parentheses() {
+        assert_eq!(
+            completion_context("field User.Foo {\n  avatar @loadable("),
+            Some(CompletionContext::DirectiveArgument {
+                directive_name: "loadable".intern().into(),
+                provided_arguments: vec![],
+            })
+        );
+        assert_eq!(
+            completion_context("field User.Foo {\n  avatar @loadable(lazyLoadArtifact: true, de"),
+            Some(CompletionContext::DirectiveArgument {
+                directive_name: "loadable".intern().into(),
+                provided_arguments: vec!["lazyLoadArtifact".intern().into()],
+            })
+        );
+        // The arguments of a field that is followed by a directive are still completed
+        assert_eq!(
+            completion_context("field User.Foo {\n  avatar @updatable\n  friends("),
+            Some(CompletionContext::Argument {
+                selection_set_path: selection_set_path("User", &[]),
+                field_name: "friends".intern().into(),
+                provided_arguments: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn completes_selectables_in_iso_literals_without_a_closing_backtick() {
+        let mut project = TestProject::new(
+            "completion_while_typing",
+            "type Query { me: User }\ntype User { id: ID! name: String }\n",
+            "",
+        );
+        let uri = project.uri("src/Home.tsx");
+        let text = "export const Home = iso(`field Query.Home { me { na";
+        project.state.document_changed(&uri, text).unwrap();
+
+        let Some(CompletionResponse::Array(items)) = on_completion::<GraphQLNetworkProtocol>(
+            &mut project.state,
+            CompletionParams {
+                text_document_position: TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier { uri },
+                    position: index_to_position(text, text.len()),
+                },
+                work_done_progress_params: Default::default(),
+                partial_result_params: Default::default(),
+                context: None,
+            },
+        )
+        .unwrap() else {
+            panic!("Expected completion items");
+        };
+        assert!(items.iter().any(|item| item.label == "name"));
+    }
+}
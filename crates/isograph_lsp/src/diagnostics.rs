@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use common_lang_types::Severity;
+use isograph_compiler::{
+    located_error_messages, located_warning_messages, LocatedErrorMessage, WithWarnings,
+};
+use isograph_schema::NetworkProtocol;
+use lsp_types::{
+    notification::{Notification, PublishDiagnostics},
+    Diagnostic, DiagnosticSeverity, DiagnosticTag, NumberOrString, PublishDiagnosticsParams, Url,
+};
+
+use crate::{
+    code_action::DiagnosticData, location_utils::embedded_location_to_lsp_location,
+    lsp_runtime_error::LSPRuntimeResult, lsp_state::LSPState,
+};
+
+pub(crate) const DIAGNOSTIC_SOURCE: &str = "isograph";
+
+/// Compile the source files of each project (including the unsaved contents of open
+/// documents), and publish the errors and warnings as diagnostics. Diagnostics are
+/// published for every document containing errors, not just for open documents.
+pub(crate) fn publish_diagnostics<TNetworkProtocol: NetworkProtocol>(
+    state: &mut LSPState,
+) -> LSPRuntimeResult<()> {
+    let mut messages = vec![];
+    for WithWarnings { item, warnings } in state.create_and_validate_schemas::<TNetworkProtocol>() {
+        if let Err(error) = item {
+            messages.extend(located_error_messages(error.as_ref()));
+        }
+        messages.extend(located_warning_messages(&warnings));
+    }
+
+    let mut diagnostics_by_uri: HashMap<Url, Vec<Diagnostic>> = HashMap::new();
+    for LocatedErrorMessage {
+        message,
+        severity,
+        location,
+        code,
+        suggestion,
+        reports_deprecation,
+    } in messages
+    {
+        let diagnostic = Diagnostic {
+            severity: Some(match severity {
+                Severity::Error => DiagnosticSeverity::ERROR,
+                Severity::Warning => DiagnosticSeverity::WARNING,
+            }),
+            code: code.map(|code| NumberOrString::String(code.to_string())),
+            source: Some(DIAGNOSTIC_SOURCE.to_string()),
+            message,
+            data: suggestion.map(|suggestion| {
+                serde_json::to_value(DiagnosticData { suggestion })
+                    .expect("Expected diagnostic data to be serializable")
+            }),
+            tags: reports_deprecation.then(|| vec![DiagnosticTag::DEPRECATED]),
+            ..Default::default()
+        };
+        match location.and_then(|location| embedded_location_to_lsp_location(state, location)) {
+            Some(location) => {
+                diagnostics_by_uri
+                    .entry(location.uri)
+                    .or_default()
+                    .push(Diagnostic {
+                        range: location.range,
+                        ..diagnostic
+                    });
+            }
+            None => {
+                // Errors without a location (e.g. a missing schema file) are reported
+                // at the start of the config file.
+                if let Ok(uri) = Url::from_file_path(&state.compiler_state().config.config_location)
+                {
+                    diagnostics_by_uri.entry(uri).or_default().push(diagnostic);
+                }
+            }
+        }
+    }
+
+    // Clear the diagnostics of documents whose errors have been fixed.
+    for uri in std::mem::take(&mut state.documents_with_diagnostics) {
+        diagnostics_by_uri.entry(uri).or_default();
+    }
+
+    for (uri, diagnostics) in diagnostics_by_uri {
+        if !diagnostics.is_empty() {
+            state.documents_with_diagnostics.insert(uri.clone());
+        }
+        state.send_message(
+            lsp_server::Notification::new(
+                PublishDiagnostics::METHOD.to_string(),
+                PublishDiagnosticsParams {
+                    uri,
+                    diagnostics,
+                    version: None,
+                },
+            )
+            .into(),
+        );
+    }
+
+    Ok(())
+}
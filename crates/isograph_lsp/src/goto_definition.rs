@@ -0,0 +1,135 @@
+use common_lang_types::{
+    EmbeddedLocation, Location, ObjectTypeAndFieldName, RelativePathToSourceFile, SelectableName,
+    UnvalidatedTypeName,
+};
+use isograph_compiler::{extract_iso_literals_from_file_content, process_iso_literal_extraction};
+use isograph_lang_parser::IsoLiteralExtractionResult;
+use isograph_lang_types::{DefinitionLocation, SelectionType};
+use isograph_schema::{ClientFieldVariant, NetworkProtocol, Schema, SelectableId};
+use lsp_types::{
+    request::{GotoDefinition, Request},
+    GotoDefinitionParams, GotoDefinitionResponse,
+};
+
+use crate::{
+    location_utils::{embedded_location_to_lsp_location, relative_path_to_uri},
+    lsp_runtime_error::{LSPRuntimeError, LSPRuntimeResult},
+    lsp_state::LSPState,
+    selection_at_position::iso_literal_at_position,
+};
+
+pub fn on_goto_definition<TNetworkProtocol: NetworkProtocol>(
+    state: &mut LSPState,
+    params: <GotoDefinition as Request>::Params,
+) -> LSPRuntimeResult<<GotoDefinition as Request>::Result> {
+    let GotoDefinitionParams {
+        text_document_position_params,
+        work_done_progress_params: _,
+        partial_result_params: _,
+    } = params;
+
+    let (_, iso_literal) = iso_literal_at_position(state, &text_document_position_params)?;
+    let selection = iso_literal
+        .selection_at_index()
+        .ok_or(LSPRuntimeError::ExpectedError)?;
+
+    let schema = state.schema::<TNetworkProtocol>()?;
+    let selectable_id = selection
+        .selectable_id(&schema)
+        .ok_or(LSPRuntimeError::ExpectedError)?;
+
+    Ok(
+        selectable_definition_location(state, &schema, selectable_id)
+            .map(GotoDefinitionResponse::Scalar),
+    )
+}
+
+/// The location of the definition of a selectable: either the field definition in
+/// the schema (for server fields), or the name of the client field or pointer in
+/// its iso literal.
+pub(crate) fn selectable_definition_location<TNetworkProtocol: NetworkProtocol>(
+    state: &LSPState,
+    schema: &Schema<TNetworkProtocol>,
+    selectable_id: SelectableId,
+) -> Option<lsp_types::Location> {
+    let embedded_location = match selectable_id {
+        DefinitionLocation::Server(server_selectable_id) => {
+            let location = match server_selectable_id {
+                SelectionType::Scalar(scalar_selectable_id) => {
+                    schema
+                        .server_scalar_selectable(scalar_selectable_id)
+                        .name
+                        .location
+                }
+                SelectionType::Object(object_selectable_id) => {
+                    schema
+                        .server_object_selectable(object_selectable_id)
+                        .name
+                        .location
+                }
+            };
+            match location {
+                Location::Embedded(embedded_location) => embedded_location,
+                Location::Generated => return None,
+            }
+        }
+        DefinitionLocation::Client(client_selectable_id) => {
+            let (file_path, type_and_field) = match schema.client_type(client_selectable_id) {
+                SelectionType::Scalar(client_field) => match client_field.variant {
+                    ClientFieldVariant::UserWritten(info) => {
+                        (info.file_path, client_field.type_and_field)
+                    }
+                    // Links and imperatively loaded fields are generated by the compiler,
+                    // and therefore have no declaration to navigate to.
+                    ClientFieldVariant::Link | ClientFieldVariant::ImperativelyLoadedField(_) => {
+                        return None
+                    }
+                },
+                SelectionType::Object(client_pointer) => {
+                    (client_pointer.info.file_path, client_pointer.type_and_field)
+                }
+            };
+            client_type_declaration_location(state, file_path, type_and_field)?
+        }
+    };
+
+    embedded_location_to_lsp_location(state, embedded_location)
+}
+
+/// Find the name of the client field or pointer declared in file_path, by re-parsing
+/// the iso literals in that file. The file may be open and contain unsaved changes.
+fn client_type_declaration_location(
+    state: &LSPState,
+    file_path: RelativePathToSourceFile,
+    type_and_field: ObjectTypeAndFieldName,
+) -> Option<EmbeddedLocation> {
+    let uri = relative_path_to_uri(state, file_path)?;
+    let file_text = state.text_for_uri_or_disk(&uri)?;
+    let parent_type: UnvalidatedTypeName = type_and_field.type_name.into();
+
+    let location = extract_iso_literals_from_file_content(&file_text).find_map(|extraction| {
+        let (extraction_result, text_source) = process_iso_literal_extraction(
+            extraction,
+            file_path,
+            state.compiler_state().config.current_working_directory,
+        )
+        .ok()?;
+        let (declaration_parent_type, name) = match extraction_result {
+            IsoLiteralExtractionResult::ClientFieldDeclaration(declaration) => (
+                declaration.item.parent_type.item,
+                declaration.item.client_field_name.map(SelectableName::from),
+            ),
+            IsoLiteralExtractionResult::ClientPointerDeclaration(declaration) => (
+                declaration.item.parent_type.item,
+                declaration
+                    .item
+                    .client_pointer_name
+                    .map(SelectableName::from),
+            ),
+            IsoLiteralExtractionResult::EntrypointDeclaration(_) => return None,
+        };
+        (declaration_parent_type == parent_type && name.item == type_and_field.field_name)
+            .then(|| EmbeddedLocation::new(text_source, name.span))
+    });
+    location
+}
@@ -0,0 +1,366 @@
+use std::fmt::Display;
+
+use common_lang_types::{DescriptionValue, EmbeddedLocation, Span};
+use intern::{string_key::Intern, Lookup};
+use isograph_lang_parser::IsographLangTokenKind;
+use isograph_lang_types::{
+    DefinitionLocation, SelectionType, ServerEntityId, TypeAnnotation, UnionVariant,
+    VariableDefinition,
+};
+use isograph_schema::{NetworkProtocol, Schema, SelectableId};
+use logos::Logos;
+use lsp_types::{
+    request::{HoverRequest, Request},
+    Hover, HoverContents, HoverParams, MarkupContent, MarkupKind,
+};
+
+use crate::{
+    completion::{completion_context, CompletionContext},
+    location_utils::{position_to_index, span_in_file, span_to_range},
+    lsp_runtime_error::{LSPRuntimeError, LSPRuntimeResult},
+    lsp_state::LSPState,
+    selection_at_position::{
+        iso_literal_at_position, iso_literal_text_at_index, object_entity_id_at_path,
+        selectable_id_on_object,
+    },
+};
+
+pub fn on_hover<TNetworkProtocol: NetworkProtocol>(
+    state: &mut LSPState,
+    params: <HoverRequest as Request>::Params,
+) -> LSPRuntimeResult<<HoverRequest as Request>::Result> {
+    let HoverParams {
+        text_document_position_params,
+        work_done_progress_params: _,
+    } = params;
+
+    let schema = state.schema::<TNetworkProtocol>()?;
+    let (file_text, selectable_id, name_span) =
+        match iso_literal_at_position(state, &text_document_position_params) {
+            Ok((file_text, iso_literal)) => {
+                let selection = iso_literal
+                    .selection_at_index()
+                    .ok_or(LSPRuntimeError::ExpectedError)?;
+                let selectable_id = selection
+                    .selectable_id(&schema)
+                    .ok_or(LSPRuntimeError::ExpectedError)?;
+                let name_span = span_in_file(EmbeddedLocation::new(
+                    iso_literal.text_source,
+                    selection.name.span,
+                ));
+                (file_text, selectable_id, name_span)
+            }
+            // The iso literal is usually incomplete while the user is typing, so it
+            // cannot be parsed. Instead, we find the selection from the tokens.
+            Err(_) => {
+                let file_text = state
+                    .text_for_uri_or_disk(&text_document_position_params.text_document.uri)
+                    .ok_or(LSPRuntimeError::ExpectedError)?;
+                let index = position_to_index(&file_text, text_document_position_params.position)
+                    .ok_or(LSPRuntimeError::ExpectedError)?;
+                let (selectable_id, name_span) =
+                    selectable_at_index_in_unparsed_iso_literal(&schema, &file_text, index)
+                        .ok_or(LSPRuntimeError::ExpectedError)?;
+                (file_text, selectable_id, name_span)
+            }
+        };
+
+    Ok(Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: selectable_hover_text(&schema, selectable_id),
+        }),
+        range: Some(span_to_range(&file_text, name_span)),
+    }))
+}
+
+/// Find the selectable whose name contains the index, and the span of that name in the
+/// file, using the tokens that precede the name. Unlike selection_at_index, this works
+/// if the iso literal cannot be parsed.
+fn selectable_at_index_in_unparsed_iso_literal<TNetworkProtocol: NetworkProtocol>(
+    schema: &Schema<TNetworkProtocol>,
+    file_text: &str,
+    index: usize,
+) -> Option<(SelectableId, Span)> {
+    let (start, iso_literal_text) = iso_literal_text_at_index(file_text, index)?;
+    let name_span = IsographLangTokenKind::lexer(iso_literal_text)
+        .spanned()
+        .find(|(kind, span)| {
+            *kind == IsographLangTokenKind::Identifier
+                && span.start <= index - start
+                && index - start <= span.end
+        })?
+        .1;
+
+    let selection_set_path = match completion_context(&iso_literal_text[..name_span.start])? {
+        CompletionContext::Selectable(selection_set_path) => selection_set_path,
+        _ => return None,
+    };
+    let object_entity_id = object_entity_id_at_path(
+        schema,
+        selection_set_path.parent_type,
+        &selection_set_path.path,
+    )?;
+    let selectable_id = selectable_id_on_object(
+        schema,
+        object_entity_id,
+        iso_literal_text[name_span.clone()].intern().into(),
+    )?;
+    Some((
+        selectable_id,
+        Span::new(
+            (start + name_span.start) as u32,
+            (start + name_span.end) as u32,
+        ),
+    ))
+}
+
+/// Markdown containing the signature of the selectable, followed by its description.
+fn selectable_hover_text<TNetworkProtocol: NetworkProtocol>(
+    schema: &Schema<TNetworkProtocol>,
+    selectable_id: SelectableId,
+) -> String {
+    let (signature, description) = selectable_signature(schema, selectable_id);
+    let mut text = format!("```graphql\n{signature}\n```");
+    if let Some(description) = description {
+        text.push_str("\n\n");
+        text.push_str(description.lookup());
+    }
+    text
+}
+
+/// The signature of the selectable (its parent type, arguments and output type), e.g.
+/// `User.avatarUrl(size: Int): String`, and its description.
+pub(crate) fn selectable_signature<TNetworkProtocol: NetworkProtocol>(
+    schema: &Schema<TNetworkProtocol>,
+    selectable_id: SelectableId,
+) -> (String, Option<DescriptionValue>) {
+    let parent_type_name = |parent_object_entity_id| {
+        schema
+            .server_entity_data
+            .server_object_entity(parent_object_entity_id)
+            .name
+    };
+
+    match selectable_id {
+        DefinitionLocation::Server(SelectionType::Scalar(scalar_selectable_id)) => {
+            let selectable = schema.server_scalar_selectable(scalar_selectable_id);
+            let output_type = selectable
+                .target_scalar_entity
+                .clone()
+                .map(&mut |id| schema.server_entity_data.server_scalar_entity(id).name.item);
+            (
+                format!(
+                    "{}.{}{}: {}",
+                    parent_type_name(selectable.parent_object_entity_id),
+                    selectable.name.item,
+                    format_arguments(schema, selectable.arguments.iter().map(|x| &x.item)),
+                    format_type_annotation(output_type)
+                ),
+                selectable.description,
+            )
+        }
+        DefinitionLocation::Server(SelectionType::Object(object_selectable_id)) => {
+            let selectable = schema.server_object_selectable(object_selectable_id);
+            let output_type = selectable
+                .target_object_entity
+                .clone()
+                .map(&mut |id| schema.server_entity_data.server_object_entity(id).name);
+            (
+                format!(
+                    "{}.{}{}: {}",
+                    parent_type_name(selectable.parent_object_entity_id),
+                    selectable.name.item,
+                    format_arguments(schema, selectable.arguments.iter().map(|x| &x.item)),
+                    format_type_annotation(output_type)
+                ),
+                selectable.description,
+            )
+        }
+        DefinitionLocation::Client(SelectionType::Scalar(client_field_id)) => {
+            let client_field = schema.client_field(client_field_id);
+            (
+                format!(
+                    "field {}.{}{}",
+                    parent_type_name(client_field.parent_object_entity_id),
+                    client_field.name,
+                    format_arguments(
+                        schema,
+                        client_field.variable_definitions.iter().map(|x| &x.item)
+                    ),
+                ),
+                client_field.description,
+            )
+        }
+        DefinitionLocation::Client(SelectionType::Object(client_pointer_id)) => {
+            let client_pointer = schema.client_pointer(client_pointer_id);
+            let output_type = client_pointer
+                .target_object_entity
+                .clone()
+                .map(&mut |id| schema.server_entity_data.server_object_entity(id).name);
+            (
+                format!(
+                    "pointer {}.{}{}: {}",
+                    parent_type_name(client_pointer.parent_object_entity_id),
+                    client_pointer.name,
+                    format_arguments(
+                        schema,
+                        client_pointer.variable_definitions.iter().map(|x| &x.item)
+                    ),
+                    format_type_annotation(output_type)
+                ),
+                client_pointer.description,
+            )
+        }
+    }
+}
+
+fn format_arguments<'a, TNetworkProtocol: NetworkProtocol>(
+    schema: &Schema<TNetworkProtocol>,
+    arguments: impl Iterator<Item = &'a VariableDefinition<ServerEntityId>>,
+) -> String {
+    let arguments = arguments
+        .map(|argument| format_argument(schema, argument))
+        .collect::<Vec<_>>();
+    if arguments.is_empty() {
+        String::new()
+    } else {
+        format!("({})", arguments.join(", "))
+    }
+}
+
+/// Formats an argument as it would appear in a GraphQL schema, e.g. `first: Int = 10`.
+pub(crate) fn format_argument<TNetworkProtocol: NetworkProtocol>(
+    schema: &Schema<TNetworkProtocol>,
+    argument: &VariableDefinition<ServerEntityId>,
+) -> String {
+    let type_ = argument
+        .type_
+        .clone()
+        .map(|entity_id| server_entity_name(schema, entity_id));
+    match &argument.default_value {
+        Some(default_value) => format!(
+            "{}: {} = {}",
+            argument.name.item,
+            type_,
+            default_value.item.print_to_string()
+        ),
+        None => format!("{}: {}", argument.name.item, type_),
+    }
+}
+
+fn server_entity_name<TNetworkProtocol: NetworkProtocol>(
+    schema: &Schema<TNetworkProtocol>,
+    entity_id: ServerEntityId,
+) -> String {
+    match entity_id {
+        SelectionType::Scalar(scalar_entity_id) => schema
+            .server_entity_data
+            .server_scalar_entity(scalar_entity_id)
+            .name
+            .item
+            .to_string(),
+        SelectionType::Object(object_entity_id) => schema
+            .server_entity_data
+            .server_object_entity(object_entity_id)
+            .name
+            .to_string(),
+    }
+}
+
+/// Formats a type annotation using GraphQL syntax, e.g. `[String!]`.
+pub(crate) fn format_type_annotation<T: Display + Ord>(
+    type_annotation: TypeAnnotation<T>,
+) -> String {
+    match type_annotation {
+        TypeAnnotation::Scalar(scalar) => format!("{scalar}!"),
+        TypeAnnotation::Plural(inner) => format!("[{}]!", format_type_annotation(*inner)),
+        TypeAnnotation::Union(union_type_annotation) => {
+            let variants = union_type_annotation
+                .variants
+                .into_iter()
+                .map(|variant| match variant {
+                    UnionVariant::Scalar(scalar) => scalar.to_string(),
+                    UnionVariant::Plural(inner) => format!("[{}]", format_type_annotation(inner)),
+                })
+                .collect::<Vec<_>>()
+                .join(" | ");
+            if union_type_annotation.nullable {
+                variants
+            } else {
+                format!("{variants}!")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use graphql_network_protocol::GraphQLNetworkProtocol;
+    use isograph_lang_types::{TypeAnnotation, UnionTypeAnnotation, UnionVariant};
+    use lsp_types::{TextDocumentIdentifier, TextDocumentPositionParams};
+
+    use super::*;
+    use crate::{location_utils::index_to_position, lsp_state::tests::TestProject};
+
+    #[test]
+    fn formats_type_annotations_using_graphql_syntax() {
+        assert_eq!(format_type_annotation(TypeAnnotation::Scalar("ID")), "ID!");
+        assert_eq!(
+            format_type_annotation(TypeAnnotation::Union(UnionTypeAnnotation::new_nullable(
+                UnionVariant::Plural(TypeAnnotation::Scalar("String"))
+            ))),
+            "[String!]"
+        );
+        assert_eq!(
+            format_type_annotation(TypeAnnotation::Plural(Box::new(TypeAnnotation::Union(
+                UnionTypeAnnotation::new_nullable(UnionVariant::Scalar("Int"))
+            )))),
+            "[Int]!"
+        );
+    }
+
+    #[test]
+    fn hovers_selections_in_iso_literals_that_cannot_be_parsed() {
+        let mut project = TestProject::new(
+            "hover_while_typing",
+            "type Query { me: User }\n\"The name of the user\"\ntype User { id: ID! name: String }\n",
+            "",
+        );
+        let uri = project.uri("src/Home.tsx");
+        let text = "export const Home = iso(`field Query.Home { me { name, na";
+        project.state.document_changed(&uri, text).unwrap();
+
+        let hover = on_hover::<GraphQLNetworkProtocol>(
+            &mut project.state,
+            HoverParams {
+                text_document_position_params: TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier { uri },
+                    position: index_to_position(text, text.find("name").unwrap() + 2),
+                },
+                work_done_progress_params: Default::default(),
+            },
+        )
+        .unwrap()
+        .unwrap();
+
+        let HoverContents::Markup(contents) = hover.contents else {
+            panic!("Expected markup hover contents");
+        };
+        assert!(
+            contents.value.contains("name: String"),
+            "{}",
+            contents.value
+        );
+        assert_eq!(
+            hover.range,
+            Some(span_to_range(
+                text,
+                Span::new(
+                    text.find("name").unwrap() as u32,
+                    text.find(", na").unwrap() as u32
+                )
+            ))
+        );
+    }
+}
@@ -1,21 +1,34 @@
 use isograph_config::CompilerConfig;
+use isograph_schema::NetworkProtocol;
 use lsp_process_error::LSPProcessResult;
 use lsp_server::Connection;
 
+mod code_action;
+mod completion;
+mod diagnostics;
+mod goto_definition;
+mod hover;
+mod location_utils;
 pub mod lsp_notification_dispatch;
 pub mod lsp_process_error;
 mod lsp_request_dispatch;
 pub mod lsp_runtime_error;
 mod lsp_state;
+mod references;
 mod row_col_offset;
+mod selection_at_position;
 mod semantic_tokens;
 pub mod server;
 pub mod text_document;
 
-pub async fn start_language_server(config: CompilerConfig) -> LSPProcessResult<()> {
+/// Start a language server for the projects in a config file. Requests about a
+/// document are answered by the project whose project root contains it.
+pub async fn start_language_server<TNetworkProtocol: NetworkProtocol>(
+    configs: Vec<CompilerConfig>,
+) -> LSPProcessResult<()> {
     let (connection, io_handles) = Connection::stdio();
     let params = server::initialize(&connection)?;
-    server::run(connection, config, params).await?;
+    server::run::<TNetworkProtocol>(connection, configs, params).await?;
     io_handles.join()?;
     Ok(())
 }
@@ -0,0 +1,131 @@
+use std::path::PathBuf;
+
+use common_lang_types::{EmbeddedLocation, RelativePathToSourceFile, Span};
+use intern::Lookup;
+use lsp_types::{Position, Range, Url};
+
+use crate::lsp_state::LSPState;
+
+/// Convert an LSP position (a line and a UTF-16 character offset) into a byte
+/// index into text. Returns None if the position is not within the text.
+pub(crate) fn position_to_index(text: &str, position: Position) -> Option<usize> {
+    let mut line_start = 0;
+    for _ in 0..position.line {
+        line_start += text[line_start..].find('\n')? + 1;
+    }
+
+    let line = text[line_start..].split('\n').next().unwrap_or_default();
+    let mut utf16_offset = 0;
+    for (byte_offset, char) in line.char_indices() {
+        if utf16_offset >= position.character {
+            return Some(line_start + byte_offset);
+        }
+        utf16_offset += char.len_utf16() as u32;
+    }
+    Some(line_start + line.len())
+}
+
+/// Convert a byte index into text into an LSP position.
+pub(crate) fn index_to_position(text: &str, index: usize) -> Position {
+    let before = &text[..index.min(text.len())];
+    let line_start = before.rfind('\n').map(|x| x + 1).unwrap_or(0);
+    Position {
+        line: before.matches('\n').count() as u32,
+        character: before[line_start..].encode_utf16().count() as u32,
+    }
+}
+
+pub(crate) fn span_to_range(text: &str, span: Span) -> Range {
+    Range {
+        start: index_to_position(text, span.start as usize),
+        end: index_to_position(text, span.end as usize),
+    }
+}
+
+/// Whether index is within span. The end of the span is included, so that a cursor
+/// placed immediately after an identifier is considered to be on that identifier.
+pub(crate) fn span_contains(span: Span, index: u32) -> bool {
+    span.start <= index && index <= span.end
+}
+
+/// Converts an embedded location (i.e. a span relative to an iso literal or schema
+/// file) into a span relative to the start of the file.
+pub(crate) fn span_in_file(location: EmbeddedLocation) -> Span {
+    let offset = location
+        .text_source
+        .span
+        .map(|span| span.start)
+        .unwrap_or(0);
+    location.span.with_offset(offset)
+}
+
+pub(crate) fn relative_path_to_uri(
+    state: &LSPState,
+    relative_path: RelativePathToSourceFile,
+) -> Option<Url> {
+    let mut path = PathBuf::from(
+        state
+            .compiler_state()
+            .config
+            .current_working_directory
+            .lookup(),
+    );
+    path.push(relative_path.lookup());
+    Url::from_file_path(path).ok()
+}
+
+pub(crate) fn embedded_location_to_lsp_location(
+    state: &LSPState,
+    location: EmbeddedLocation,
+) -> Option<lsp_types::Location> {
+    let uri = relative_path_to_uri(state, location.text_source.relative_path_to_source_file)?;
+    let text = state.text_for_uri_or_disk(&uri)?;
+    Some(lsp_types::Location {
+        range: span_to_range(&text, span_in_file(location)),
+        uri,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::Position;
+
+    use super::{index_to_position, position_to_index};
+
+    #[test]
+    fn position_and_index_round_trip() {
+        let text = "const a = 1;\nexport const é = iso(`\n  field Query.Foo {\n}`);";
+        for index in [0, 5, 13, 20, 36, 38, text.len()] {
+            if !text.is_char_boundary(index) {
+                continue;
+            }
+            let position = index_to_position(text, index);
+            assert_eq!(position_to_index(text, position), Some(index));
+        }
+    }
+
+    #[test]
+    fn position_counts_utf16_code_units() {
+        let text = "é😀x";
+        assert_eq!(
+            position_to_index(
+                text,
+                Position {
+                    line: 0,
+                    character: 3
+                }
+            ),
+            Some("é😀".len())
+        );
+        assert_eq!(
+            position_to_index(
+                text,
+                Position {
+                    line: 1,
+                    character: 0
+                }
+            ),
+            None
+        );
+    }
+}
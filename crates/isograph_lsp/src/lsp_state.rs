@@ -1,48 +1,501 @@
-use std::collections::HashMap;
+use std::{
+    any::Any,
+    collections::{HashMap, HashSet},
+    error::Error,
+    path::Path,
+    rc::Rc,
+};
 
+use common_lang_types::{
+    relative_path_from_absolute_and_working_directory, RelativePathToSourceFile,
+};
 use crossbeam::channel::Sender;
+use isograph_compiler::{
+    create_and_validate_schema, create_schema, CompilerState, CreateAndValidateSchemaResult,
+    SourceFiles, WithWarnings,
+};
 use isograph_config::CompilerConfig;
+use isograph_schema::{NetworkProtocol, Schema};
 use lsp_server::Message;
 use lsp_types::Url;
+use pico::Epoch;
 
-use crate::lsp_runtime_error::LSPRuntimeResult;
+use crate::lsp_runtime_error::{LSPRuntimeError, LSPRuntimeResult};
 
 #[derive(Debug)]
 pub struct LSPState {
     open_docs: HashMap<Url, String>,
     sender: Sender<Message>,
-    pub config: CompilerConfig,
+    /// The state of each project in the config file.
+    projects: Vec<ProjectState>,
+    /// The index of the project that requests are answered for, i.e. the project
+    /// containing the document of the current request.
+    active_project: usize,
+    /// The documents for which we last published a non-empty list of diagnostics.
+    /// We must publish an empty list for these documents once their errors are fixed.
+    pub(crate) documents_with_diagnostics: HashSet<Url>,
+}
+
+#[derive(Debug)]
+struct ProjectState {
+    compiler_state: CompilerState,
+    /// The last schema that was successfully created. While the schema cannot be
+    /// created (e.g. because the GraphQL schema is being edited), requests are
+    /// answered using this schema.
+    last_schema: Option<Rc<dyn Any>>,
+    /// The revision of the database at which the schema was last created (or failed to
+    /// be created). Until the database changes, the result is reused.
+    schema_epoch: Option<Epoch>,
 }
 
 impl LSPState {
-    pub fn new(sender: Sender<Message>, config: CompilerConfig) -> Self {
+    /// Create the state of the language server for the projects in a config file.
+    /// There must be at least one project.
+    pub fn new(sender: Sender<Message>, configs: Vec<CompilerConfig>) -> Self {
+        assert!(!configs.is_empty(), "Expected at least one project");
         LSPState {
             open_docs: HashMap::new(),
             sender,
-            config,
+            projects: configs
+                .into_iter()
+                .map(|config| ProjectState {
+                    compiler_state: CompilerState::new_with_config(config),
+                    last_schema: None,
+                    schema_epoch: None,
+                })
+                .collect(),
+            active_project: 0,
+            documents_with_diagnostics: HashSet::new(),
+        }
+    }
+
+    /// The compiler state of the project that requests are answered for.
+    pub fn compiler_state(&self) -> &CompilerState {
+        &self.projects[self.active_project].compiler_state
+    }
+
+    /// Answer requests about the document using the project that compiles it, i.e. the
+    /// project whose project root contains it, or whose schema it is. If no project
+    /// compiles the document, the previously selected project is used.
+    pub(crate) fn select_project_for_document(&mut self, uri: &Url) {
+        let Ok(path) = uri.to_file_path() else {
+            return;
+        };
+        if let Some(index) = self.projects.iter().position(|project| {
+            project.is_iso_literals_source(&path) || project.is_schema_source(&path)
+        }) {
+            self.active_project = index;
         }
     }
 
     pub fn document_opened(&mut self, uri: &Url, text: &str) -> LSPRuntimeResult<()> {
         self.open_docs.insert(uri.to_owned(), text.to_owned());
-        Ok(())
+        self.set_source_content(uri, text)
     }
 
     pub fn document_changed(&mut self, uri: &Url, text: &str) -> LSPRuntimeResult<()> {
         self.open_docs.insert(uri.to_owned(), text.to_owned());
-        Ok(())
+        self.set_source_content(uri, text)
     }
 
     pub fn document_closed(&mut self, uri: &Url) -> LSPRuntimeResult<()> {
         self.open_docs.remove(uri);
-        Ok(())
+        // The unsaved contents of the document have been discarded, so we compile
+        // the contents of the file on disk instead.
+        match uri
+            .to_file_path()
+            .ok()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+        {
+            Some(text) => self.set_source_content(uri, &text),
+            None => Ok(()),
+        }
     }
 
     pub fn text_for(&self, uri: &Url) -> Option<&str> {
         self.open_docs.get(uri).map(|s| s.as_str())
     }
 
+    /// Returns the text of the open document, or, if the document is not open,
+    /// the contents of the file on disk.
+    pub fn text_for_uri_or_disk(&self, uri: &Url) -> Option<String> {
+        match self.text_for(uri) {
+            Some(text) => Some(text.to_string()),
+            None => std::fs::read_to_string(uri.to_file_path().ok()?).ok(),
+        }
+    }
+
+    pub fn relative_path_for(&self, uri: &Url) -> LSPRuntimeResult<RelativePathToSourceFile> {
+        let path = uri
+            .to_file_path()
+            .map_err(|_| LSPRuntimeError::UnexpectedError(format!("{} is not a file path", uri)))?;
+        Ok(relative_path_from_absolute_and_working_directory(
+            self.compiler_state().config.current_working_directory,
+            &path,
+        ))
+    }
+
+    /// Create the schema of the selected project from its source files. Invalid iso
+    /// literals are left out of the schema. If the schema cannot be created (e.g.
+    /// because the GraphQL schema is invalid), the last schema that was successfully
+    /// created is returned instead. The schema is only created again once the source
+    /// files have changed.
+    pub fn schema<TNetworkProtocol: NetworkProtocol>(
+        &mut self,
+    ) -> LSPRuntimeResult<Rc<Schema<TNetworkProtocol>>> {
+        let project = &mut self.projects[self.active_project];
+        // If the source files cannot be read, this is not memoized, so that they are read
+        // again on the next request.
+        if let Ok(source_files) = project.source_files() {
+            let compiler_state = &project.compiler_state;
+            let epoch = compiler_state.db.current_epoch();
+            if project.schema_epoch != Some(epoch) {
+                project.schema_epoch = Some(epoch);
+                if let Ok(outcome) = create_schema::<TNetworkProtocol>(
+                    &compiler_state.db,
+                    &source_files,
+                    &compiler_state.config,
+                ) {
+                    project.last_schema = Some(Rc::new(outcome.schema));
+                }
+            }
+        }
+        project
+            .last_schema
+            .clone()
+            .and_then(|schema| schema.downcast().ok())
+            .ok_or(LSPRuntimeError::ExpectedError)
+    }
+
+    /// Create and validate the schema of each project from its source files, including
+    /// the unsaved contents of open documents, returning any compiler errors and
+    /// warnings.
+    pub fn create_and_validate_schemas<TNetworkProtocol: NetworkProtocol>(
+        &mut self,
+    ) -> Vec<WithWarnings<CreateAndValidateSchemaResult<TNetworkProtocol>>> {
+        self.projects
+            .iter_mut()
+            .map(|project| match project.source_files() {
+                Ok(source_files) => create_and_validate_schema::<TNetworkProtocol>(
+                    &project.compiler_state.db,
+                    &source_files,
+                    &project.compiler_state.config,
+                ),
+                Err(error) => WithWarnings::without_warnings(Err(error)),
+            })
+            .collect()
+    }
+
     pub fn send_message(&self, message: Message) {
         self.sender.send(message).unwrap();
     }
+
+    /// If the document is a source file of a project (i.e. it may contain iso literals,
+    /// or it is the schema or a schema extension), compile its text instead of the
+    /// contents of the file on disk. A schema may be shared by several projects.
+    fn set_source_content(&mut self, uri: &Url, text: &str) -> LSPRuntimeResult<()> {
+        let path = match uri.to_file_path() {
+            Ok(path) => path,
+            Err(_) => return Ok(()),
+        };
+        let relative_path = self.relative_path_for(uri)?;
+        for project in self.projects.iter_mut() {
+            project.set_source_content(&path, relative_path, text);
+        }
+        Ok(())
+    }
+}
+
+impl ProjectState {
+    /// Returns the source files, reading them from disk the first time this is called.
+    fn source_files(&mut self) -> Result<SourceFiles, Box<dyn Error>> {
+        let compiler_state = &mut self.compiler_state;
+        match &compiler_state.source_files {
+            Some(source_files) => Ok(source_files.clone()),
+            None => {
+                let source_files =
+                    SourceFiles::read_all(&mut compiler_state.db, &compiler_state.config)?;
+                compiler_state.source_files = Some(source_files.clone());
+                Ok(source_files)
+            }
+        }
+    }
+
+    fn set_source_content(
+        &mut self,
+        path: &Path,
+        relative_path: RelativePathToSourceFile,
+        text: &str,
+    ) {
+        let is_iso_literals_source = self.is_iso_literals_source(path);
+        if !is_iso_literals_source && !self.is_schema_source(path) {
+            return;
+        }
+
+        // Read the source files before updating them, so that the updated contents are
+        // not overwritten when the source files are read for the first time. If they
+        // cannot be read, the error is reported when publishing diagnostics.
+        if self.source_files().is_err() {
+            return;
+        }
+        let compiler_state = &mut self.compiler_state;
+        let source_files = compiler_state
+            .source_files
+            .as_mut()
+            .expect("Expected source files to have been read");
+        if is_iso_literals_source {
+            // Adding a source does not change the revision of the database, so the
+            // memoized schema must be discarded explicitly.
+            if !source_files.iso_literals.contains_key(&relative_path) {
+                self.schema_epoch = None;
+            }
+            source_files.set_iso_literals_content(
+                &mut compiler_state.db,
+                relative_path,
+                text.to_string(),
+            );
+        } else {
+            source_files.set_schema_content(
+                &mut compiler_state.db,
+                &compiler_state.config,
+                relative_path,
+                text.to_string(),
+            );
+        }
+    }
+
+    /// Whether the compiler reads iso literals from the file at this path.
+    fn is_iso_literals_source(&self, path: &Path) -> bool {
+        let extension = path.extension().and_then(|x| x.to_str());
+        matches!(
+            extension,
+            Some("ts") | Some("tsx") | Some("js") | Some("jsx")
+        ) && path.starts_with(&self.compiler_state.config.project_root)
+            && !path.to_string_lossy().contains("__isograph")
+    }
+
+    /// Whether the file at this path is the schema or a schema extension.
+    fn is_schema_source(&self, path: &Path) -> bool {
+        let config = &self.compiler_state.config;
+        config.schema.absolute_path == path
+            || config
+                .schema_extensions
+                .iter()
+                .any(|schema_extension| schema_extension.absolute_path == path)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::fs;
+
+    use crossbeam::channel::{unbounded, Receiver};
+    use graphql_network_protocol::GraphQLNetworkProtocol;
+    use intern::string_key::Intern;
+    use isograph_config::create_configs;
+    use isograph_test_utils::TestProjectDirectory;
+    use lsp_types::{
+        notification::{Notification, PublishDiagnostics},
+        PublishDiagnosticsParams,
+    };
+
+    use super::*;
+    use crate::diagnostics::publish_diagnostics;
+
+    /// A config file and its projects in a temporary directory, and the state of a
+    /// language server for them.
+    pub(crate) struct TestProject {
+        pub(crate) state: LSPState,
+        pub(crate) receiver: Receiver<Message>,
+        pub(crate) directory: TestProjectDirectory,
+    }
+
+    impl TestProject {
+        /// A single project, containing a schema and a single source file.
+        pub(crate) fn new(test_name: &str, schema: &str, source: &str) -> Self {
+            TestProject::with_files(
+                test_name,
+                r#"{ "project_root": "./src", "schema": "./schema.graphql" }"#,
+                &[("schema.graphql", schema), ("src/Home.tsx", source)],
+            )
+        }
+
+        /// Create the files, whose paths are relative to the config file.
+        pub(crate) fn with_files(test_name: &str, config: &str, files: &[(&str, &str)]) -> Self {
+            let directory = TestProjectDirectory::new(test_name, files);
+            directory.write("isograph.config.json", config);
+
+            let configs = create_configs(
+                directory.join("isograph.config.json"),
+                directory.path().to_str().unwrap().intern().into(),
+            )
+            .unwrap();
+            let (sender, receiver) = unbounded();
+            TestProject {
+                state: LSPState::new(sender, configs),
+                receiver,
+                directory,
+            }
+        }
+
+        pub(crate) fn uri(&self, path: &str) -> Url {
+            Url::from_file_path(self.directory.join(path)).unwrap()
+        }
+
+        /// Publish diagnostics, and return the diagnostics that were published for
+        /// each document.
+        pub(crate) fn publish_diagnostics(&mut self) -> Vec<PublishDiagnosticsParams> {
+            publish_diagnostics::<GraphQLNetworkProtocol>(&mut self.state).unwrap();
+            self.receiver
+                .try_iter()
+                .filter_map(|message| match message {
+                    Message::Notification(notification) => notification
+                        .extract::<PublishDiagnosticsParams>(PublishDiagnostics::METHOD)
+                        .ok(),
+                    _ => None,
+                })
+                .collect()
+        }
+    }
+
+    const SCHEMA: &str = "type Query { me: User }\ntype User { id: ID! name: String }\n";
+    const SOURCE: &str =
+        "export const Home = iso(`field Query.Home { me { name } }`)(function Home() {});\n";
+
+    fn has_field(schema: &Schema<GraphQLNetworkProtocol>, type_name: &str, name: &str) -> bool {
+        schema.server_entity_data.defined_entities[&type_name.intern().into()]
+            .as_object()
+            .and_then(|object_entity_id| {
+                schema
+                    .server_entity_data
+                    .server_object_entity_available_selectables
+                    .get(object_entity_id)
+            })
+            .is_some_and(|(selectables, _, _)| selectables.contains_key(&name.intern().into()))
+    }
+
+    #[test]
+    fn edits_to_the_schema_are_compiled() {
+        let mut project = TestProject::new("schema_edits", SCHEMA, SOURCE);
+        let schema_uri = project.uri("schema.graphql");
+
+        let schema = project.state.schema::<GraphQLNetworkProtocol>().unwrap();
+        assert!(!has_field(&schema, "User", "avatar"));
+
+        project
+            .state
+            .document_changed(
+                &schema_uri,
+                "type Query { me: User }\ntype User { id: ID! name: String avatar: String }\n",
+            )
+            .unwrap();
+        let schema = project.state.schema::<GraphQLNetworkProtocol>().unwrap();
+        assert!(has_field(&schema, "User", "avatar"));
+
+        // While the schema is invalid, the last valid schema is used.
+        project
+            .state
+            .document_changed(&schema_uri, "type Query { me: User }\ntype User {")
+            .unwrap();
+        let schema = project.state.schema::<GraphQLNetworkProtocol>().unwrap();
+        assert!(has_field(&schema, "User", "avatar"));
+    }
+
+    #[test]
+    fn the_schema_is_reused_until_the_source_files_change() {
+        let mut project = TestProject::new("schema_reuse", SCHEMA, SOURCE);
+        let source_uri = project.uri("src/Home.tsx");
+        let other_uri = project.uri("src/Other.tsx");
+
+        let schema = project.state.schema::<GraphQLNetworkProtocol>().unwrap();
+        project.state.document_opened(&source_uri, SOURCE).unwrap();
+        assert!(Rc::ptr_eq(
+            &schema,
+            &project.state.schema::<GraphQLNetworkProtocol>().unwrap()
+        ));
+
+        let source = SOURCE.replace("name", "id");
+        project
+            .state
+            .document_changed(&source_uri, &source)
+            .unwrap();
+        let changed_schema = project.state.schema::<GraphQLNetworkProtocol>().unwrap();
+        assert!(!Rc::ptr_eq(&schema, &changed_schema));
+        assert!(Rc::ptr_eq(
+            &changed_schema,
+            &project.state.schema::<GraphQLNetworkProtocol>().unwrap()
+        ));
+
+        // Adding a file does not change the revision of the database.
+        project
+            .state
+            .document_opened(
+                &other_uri,
+                "export const Other = iso(`field User.Other { name }`)(() => {});",
+            )
+            .unwrap();
+        let other_schema = project.state.schema::<GraphQLNetworkProtocol>().unwrap();
+        assert!(!Rc::ptr_eq(&changed_schema, &other_schema));
+        assert!(has_field(&other_schema, "User", "Other"));
+    }
+
+    #[test]
+    fn errors_without_a_location_are_reported_on_the_config_file() {
+        let mut project = TestProject::new("errors_without_location", SCHEMA, SOURCE);
+        fs::remove_file(project.directory.join("schema.graphql")).unwrap();
+
+        let published = project.publish_diagnostics();
+        assert_eq!(published.len(), 1);
+        assert_eq!(published[0].uri, project.uri("isograph.config.json"));
+        assert_eq!(published[0].diagnostics.len(), 1);
+        assert!(
+            published[0].diagnostics[0]
+                .message
+                .contains("Unable to load schema file"),
+            "{}",
+            published[0].diagnostics[0].message
+        );
+    }
+
+    #[test]
+    fn requests_are_answered_by_the_project_containing_the_document() {
+        let mut project = TestProject::with_files(
+            "multiple_projects",
+            r#"{ "projects": [
+                { "project_root": "./a", "schema": "./a.graphql" },
+                { "project_root": "./b", "schema": "./b.graphql" }
+            ] }"#,
+            &[
+                ("a.graphql", "type Query { a: String }\n"),
+                ("b.graphql", "type Query { b: String }\n"),
+                (
+                    "a/A.tsx",
+                    "export const A = iso(`field Query.A { a }`)(function A() {});\n",
+                ),
+                (
+                    "b/B.tsx",
+                    "export const B = iso(`field Query.B { a }`)(function B() {});\n",
+                ),
+            ],
+        );
+
+        project
+            .state
+            .select_project_for_document(&project.uri("b/B.tsx"));
+        let schema = project.state.schema::<GraphQLNetworkProtocol>().unwrap();
+        assert!(has_field(&schema, "Query", "b"));
+        assert!(!has_field(&schema, "Query", "a"));
+
+        project
+            .state
+            .select_project_for_document(&project.uri("a/A.tsx"));
+        let schema = project.state.schema::<GraphQLNetworkProtocol>().unwrap();
+        assert!(has_field(&schema, "Query", "a"));
+
+        // Only project b selects a field that does not exist.
+        let published = project.publish_diagnostics();
+        assert_eq!(published.len(), 1);
+        assert_eq!(published[0].uri, project.uri("b/B.tsx"));
+        assert_eq!(published[0].diagnostics.len(), 1);
+    }
 }
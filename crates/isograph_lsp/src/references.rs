@@ -0,0 +1,412 @@
+use std::collections::HashMap;
+
+use common_lang_types::{
+    EmbeddedLocation, Location, ObjectTypeAndFieldName, SelectableName, UnvalidatedTypeName,
+    WithSpan,
+};
+use intern::string_key::Intern;
+use isograph_compiler::{extract_iso_literals_from_file_content, process_iso_literal_extraction};
+use isograph_lang_parser::IsoLiteralExtractionResult;
+use isograph_lang_types::{DefinitionLocation, SelectionType};
+use isograph_schema::{
+    visit_selection_set, ClientFieldVariant, NetworkProtocol, Schema, SelectableId,
+    ValidatedSelection,
+};
+use lsp_types::{
+    request::{PrepareRenameRequest, References, Rename, Request},
+    PrepareRenameResponse, ReferenceParams, RenameParams, TextEdit, WorkspaceEdit,
+};
+
+use crate::{
+    goto_definition::selectable_definition_location,
+    location_utils::{
+        embedded_location_to_lsp_location, relative_path_to_uri, span_in_file, span_to_range,
+    },
+    lsp_runtime_error::{LSPRuntimeError, LSPRuntimeResult},
+    lsp_state::LSPState,
+    selection_at_position::iso_literal_at_position,
+};
+
+pub fn on_references<TNetworkProtocol: NetworkProtocol>(
+    state: &mut LSPState,
+    params: <References as Request>::Params,
+) -> LSPRuntimeResult<<References as Request>::Result> {
+    let ReferenceParams {
+        text_document_position,
+        context,
+        work_done_progress_params: _,
+        partial_result_params: _,
+    } = params;
+
+    let (_, iso_literal) = iso_literal_at_position(state, &text_document_position)?;
+    let selection = iso_literal
+        .selection_at_index()
+        .ok_or(LSPRuntimeError::ExpectedError)?;
+
+    let schema = state.schema::<TNetworkProtocol>()?;
+    let selectable_id = selection
+        .selectable_id(&schema)
+        .ok_or(LSPRuntimeError::ExpectedError)?;
+
+    let mut locations = vec![];
+    if context.include_declaration {
+        locations.extend(selectable_definition_location(
+            state,
+            &schema,
+            selectable_id,
+        ));
+    }
+    locations.extend(
+        selection_locations(state, &schema, selectable_id)
+            .into_iter()
+            .filter_map(|location| embedded_location_to_lsp_location(state, location.name)),
+    );
+
+    Ok(Some(locations))
+}
+
+pub fn on_prepare_rename<TNetworkProtocol: NetworkProtocol>(
+    state: &mut LSPState,
+    params: <PrepareRenameRequest as Request>::Params,
+) -> LSPRuntimeResult<<PrepareRenameRequest as Request>::Result> {
+    let (file_text, iso_literal) = iso_literal_at_position(state, &params)?;
+    let selection = iso_literal
+        .selection_at_index()
+        .ok_or(LSPRuntimeError::ExpectedError)?;
+
+    let schema = state.schema::<TNetworkProtocol>()?;
+    let selectable_id = selection
+        .selectable_id(&schema)
+        .ok_or(LSPRuntimeError::ExpectedError)?;
+    validate_can_rename(&schema, selectable_id)?;
+
+    let name_location = EmbeddedLocation::new(iso_literal.text_source, selection.name.span);
+    Ok(Some(PrepareRenameResponse::Range(span_to_range(
+        &file_text,
+        span_in_file(name_location),
+    ))))
+}
+
+pub fn on_rename<TNetworkProtocol: NetworkProtocol>(
+    state: &mut LSPState,
+    params: <Rename as Request>::Params,
+) -> LSPRuntimeResult<<Rename as Request>::Result> {
+    let RenameParams {
+        text_document_position,
+        new_name,
+        work_done_progress_params: _,
+    } = params;
+
+    if !is_valid_name(&new_name) {
+        return Err(LSPRuntimeError::UnexpectedError(format!(
+            "{new_name} is not a valid field name."
+        )));
+    }
+
+    let (_, iso_literal) = iso_literal_at_position(state, &text_document_position)?;
+    let selection = iso_literal
+        .selection_at_index()
+        .ok_or(LSPRuntimeError::ExpectedError)?;
+
+    let schema = state.schema::<TNetworkProtocol>()?;
+    let selectable_id = selection
+        .selectable_id(&schema)
+        .ok_or(LSPRuntimeError::ExpectedError)?;
+    validate_can_rename(&schema, selectable_id)?;
+
+    let parent_object_entity_id = selection
+        .selection_parent_object_entity_id(&schema)
+        .ok_or(LSPRuntimeError::ExpectedError)?;
+    let new_selectable_name: SelectableName = new_name.as_str().intern().into();
+    let is_already_defined = schema
+        .server_entity_data
+        .server_object_entity_available_selectables
+        .get(&parent_object_entity_id)
+        .is_some_and(|(selectables, _, _)| selectables.contains_key(&new_selectable_name));
+    if is_already_defined {
+        return Err(LSPRuntimeError::UnexpectedError(format!(
+            "{} already has a field named {}.",
+            schema
+                .server_entity_data
+                .server_object_entity(parent_object_entity_id)
+                .name,
+            new_name
+        )));
+    }
+
+    // Un-aliased selections are given the old name as an alias, so that the shape of the
+    // data read by the client field or pointer does not change.
+    let aliased_new_name = format!("{}: {}", selection.name.item, new_name);
+    let locations = selectable_definition_location(state, &schema, selectable_id)
+        .into_iter()
+        .map(|location| (location, new_name.clone()))
+        .chain(
+            selection_locations(state, &schema, selectable_id)
+                .into_iter()
+                .filter_map(|location| {
+                    let new_text = if location.is_unaliased_selection {
+                        aliased_new_name.clone()
+                    } else {
+                        new_name.clone()
+                    };
+                    Some((
+                        embedded_location_to_lsp_location(state, location.name)?,
+                        new_text,
+                    ))
+                }),
+        );
+
+    let mut changes: HashMap<_, Vec<TextEdit>> = HashMap::new();
+    for (location, new_text) in locations {
+        changes.entry(location.uri).or_default().push(TextEdit {
+            range: location.range,
+            new_text,
+        });
+    }
+
+    Ok(Some(WorkspaceEdit {
+        changes: Some(changes),
+        ..Default::default()
+    }))
+}
+
+/// Only user-written client fields and client pointers can be renamed. Server fields are
+/// defined in the schema, and other client fields are generated by the compiler.
+fn validate_can_rename<TNetworkProtocol: NetworkProtocol>(
+    schema: &Schema<TNetworkProtocol>,
+    selectable_id: SelectableId,
+) -> LSPRuntimeResult<()> {
+    match selectable_id {
+        DefinitionLocation::Client(SelectionType::Scalar(client_field_id)) => {
+            match schema.client_field(client_field_id).variant {
+                ClientFieldVariant::UserWritten(_) => Ok(()),
+                ClientFieldVariant::Link | ClientFieldVariant::ImperativelyLoadedField(_) => {
+                    Err(LSPRuntimeError::UnexpectedError(
+                        "This field is generated by the compiler, and cannot be renamed."
+                            .to_string(),
+                    ))
+                }
+            }
+        }
+        DefinitionLocation::Client(SelectionType::Object(_)) => Ok(()),
+        DefinitionLocation::Server(_) => Err(LSPRuntimeError::UnexpectedError(
+            "Server fields cannot be renamed. Only client fields and client pointers can be renamed."
+                .to_string(),
+        )),
+    }
+}
+
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct SelectionLocation {
+    name: EmbeddedLocation,
+    /// Whether this is a selection without an alias, whose name is therefore also the
+    /// key at which the selected value is read. Entrypoints are not selections, and
+    /// have no such key.
+    is_unaliased_selection: bool,
+}
+
+/// The locations of the names of every selection of the selectable, in the reader
+/// selection sets of client fields and pointers, and, for client fields, in entrypoints.
+/// Aliased selections are included; the location is that of the name, not the alias.
+fn selection_locations<TNetworkProtocol: NetworkProtocol>(
+    state: &LSPState,
+    schema: &Schema<TNetworkProtocol>,
+    selectable_id: SelectableId,
+) -> Vec<SelectionLocation> {
+    let mut locations = vec![];
+    let reader_selection_sets = schema
+        .client_scalar_selectables
+        .iter()
+        .map(|client_field| &client_field.reader_selection_set)
+        .chain(
+            schema
+                .client_object_selectables
+                .iter()
+                .map(|client_pointer| &client_pointer.reader_selection_set),
+        );
+    for reader_selection_set in reader_selection_sets {
+        add_selection_locations(reader_selection_set, selectable_id, &mut locations);
+    }
+
+    if let DefinitionLocation::Client(SelectionType::Scalar(client_field_id)) = selectable_id {
+        locations.extend(
+            entrypoint_locations(state, schema.client_field(client_field_id).type_and_field)
+                .into_iter()
+                .map(|name| SelectionLocation {
+                    name,
+                    is_unaliased_selection: false,
+                }),
+        );
+    }
+
+    locations.sort();
+    locations.dedup();
+    locations
+}
+
+fn add_selection_locations(
+    selection_set: &[WithSpan<ValidatedSelection>],
+    selectable_id: SelectableId,
+    locations: &mut Vec<SelectionLocation>,
+) {
+    visit_selection_set(selection_set, &mut |selection| {
+        let (selected_id, name_location, is_aliased): (SelectableId, _, _) = match selection {
+            SelectionType::Scalar(scalar_selection) => (
+                match scalar_selection.associated_data {
+                    DefinitionLocation::Server(id) => {
+                        DefinitionLocation::Server(SelectionType::Scalar(id))
+                    }
+                    DefinitionLocation::Client(id) => {
+                        DefinitionLocation::Client(SelectionType::Scalar(id))
+                    }
+                },
+                scalar_selection.name.location,
+                scalar_selection.reader_alias.is_some(),
+            ),
+            SelectionType::Object(object_selection) => (
+                match object_selection.associated_data {
+                    DefinitionLocation::Server(id) => {
+                        DefinitionLocation::Server(SelectionType::Object(id))
+                    }
+                    DefinitionLocation::Client(id) => {
+                        DefinitionLocation::Client(SelectionType::Object(id))
+                    }
+                },
+                object_selection.name.location,
+                object_selection.reader_alias.is_some(),
+            ),
+        };
+        if selected_id == selectable_id {
+            if let Location::Embedded(name_location) = name_location {
+                locations.push(SelectionLocation {
+                    name: name_location,
+                    is_unaliased_selection: !is_aliased,
+                });
+            }
+        }
+    });
+}
+
+/// Entrypoints are not stored with their locations, so we find them by re-parsing
+/// the iso literals in every file that may contain an entrypoint.
+fn entrypoint_locations(
+    state: &LSPState,
+    type_and_field: ObjectTypeAndFieldName,
+) -> Vec<EmbeddedLocation> {
+    let parent_type: UnvalidatedTypeName = type_and_field.type_name.into();
+    let relative_paths = state
+        .compiler_state()
+        .source_files
+        .iter()
+        .flat_map(|source_files| source_files.iso_literals.keys());
+
+    let mut locations = vec![];
+    for relative_path in relative_paths {
+        let Some(file_text) = relative_path_to_uri(state, *relative_path)
+            .and_then(|uri| state.text_for_uri_or_disk(&uri))
+        else {
+            continue;
+        };
+        if !file_text.contains("entrypoint") {
+            continue;
+        }
+        for extraction in extract_iso_literals_from_file_content(&file_text) {
+            if let Ok((
+                IsoLiteralExtractionResult::EntrypointDeclaration(declaration),
+                text_source,
+            )) = process_iso_literal_extraction(
+                extraction,
+                *relative_path,
+                state.compiler_state().config.current_working_directory,
+            ) {
+                if declaration.item.parent_type.item == parent_type
+                    && declaration.item.client_field_name.item == type_and_field.field_name
+                {
+                    locations.push(EmbeddedLocation::new(
+                        text_source,
+                        declaration.item.client_field_name.span,
+                    ));
+                }
+            }
+        }
+    }
+    locations
+}
+
+#[cfg(test)]
+mod tests {
+    use graphql_network_protocol::GraphQLNetworkProtocol;
+    use lsp_types::{TextDocumentIdentifier, TextDocumentPositionParams};
+
+    use super::*;
+    use crate::{
+        location_utils::{index_to_position, position_to_index},
+        lsp_state::tests::TestProject,
+    };
+
+    #[test]
+    fn validates_new_names() {
+        assert!(is_valid_name("avatar"));
+        assert!(is_valid_name("_Avatar2"));
+        assert!(!is_valid_name(""));
+        assert!(!is_valid_name("2avatar"));
+        assert!(!is_valid_name("avatar url"));
+        assert!(!is_valid_name("User.avatar"));
+    }
+
+    #[test]
+    fn renaming_keeps_the_shape_of_the_data_read_by_un_aliased_selections() {
+        let source = concat!(
+            "export const Greeting = iso(`field Query.Greeting { me { name } }`)(() => {});\n",
+            "export const Home = iso(`field Query.Home { Greeting, other: Greeting }`)(() => {});\n",
+            "iso(`entrypoint Query.Greeting`);\n",
+        );
+        let mut project = TestProject::new(
+            "rename",
+            "type Query { me: User }\ntype User { id: ID! name: String }\n",
+            source,
+        );
+        let uri = project.uri("src/Home.tsx");
+        project.state.document_opened(&uri, source).unwrap();
+
+        let edit = on_rename::<GraphQLNetworkProtocol>(
+            &mut project.state,
+            RenameParams {
+                text_document_position: TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier { uri: uri.clone() },
+                    position: index_to_position(source, source.find("Greeting,").unwrap()),
+                },
+                new_name: "Welcome".to_string(),
+                work_done_progress_params: Default::default(),
+            },
+        )
+        .unwrap()
+        .unwrap();
+
+        let mut edits = edit.changes.unwrap().remove(&uri).unwrap();
+        assert_eq!(edits.len(), 4);
+        edits.sort_by_key(|edit| std::cmp::Reverse(edit.range.start));
+        let mut renamed = source.to_string();
+        for edit in edits {
+            let start = position_to_index(source, edit.range.start).unwrap();
+            let end = position_to_index(source, edit.range.end).unwrap();
+            renamed.replace_range(start..end, &edit.new_text);
+        }
+        assert_eq!(
+            renamed,
+            concat!(
+                "export const Greeting = iso(`field Query.Welcome { me { name } }`)(() => {});\n",
+                "export const Home = iso(`field Query.Home { Greeting: Welcome, other: Welcome }`)(() => {});\n",
+                "iso(`entrypoint Query.Welcome`);\n",
+            )
+        );
+    }
+}
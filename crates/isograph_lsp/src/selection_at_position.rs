@@ -0,0 +1,296 @@
+use common_lang_types::{
+    CurrentWorkingDirectory, ObjectSelectableName, RelativePathToSourceFile, SelectableName, Span,
+    TextSource, UnvalidatedTypeName, WithLocation, WithSpan,
+};
+use isograph_compiler::{extract_iso_literals_from_file_content, process_iso_literal_extraction};
+use isograph_lang_parser::IsoLiteralExtractionResult;
+use isograph_lang_types::{SelectionType, ServerObjectEntityId, UnvalidatedSelection};
+use isograph_schema::{NetworkProtocol, Schema, SelectableId};
+use lsp_types::TextDocumentPositionParams;
+
+use crate::{
+    location_utils::{position_to_index, span_contains},
+    lsp_runtime_error::{LSPRuntimeError, LSPRuntimeResult},
+    lsp_state::LSPState,
+};
+
+pub(crate) struct IsoLiteralAtIndex {
+    pub(crate) extraction_result: IsoLiteralExtractionResult,
+    pub(crate) text_source: TextSource,
+    /// The index of the cursor, relative to the start of the iso literal.
+    pub(crate) index_in_literal: u32,
+}
+
+/// Find and parse the iso literal containing the byte index. Returns None if the
+/// index is not within an iso literal, or if the iso literal cannot be parsed.
+pub(crate) fn iso_literal_at_index(
+    file_text: &str,
+    index: usize,
+    relative_path: RelativePathToSourceFile,
+    current_working_directory: CurrentWorkingDirectory,
+) -> Option<IsoLiteralAtIndex> {
+    let extraction = extract_iso_literals_from_file_content(file_text).find(|extraction| {
+        let start = extraction.iso_literal_start_index;
+        start <= index && index <= start + extraction.iso_literal_text.len()
+    })?;
+
+    let start = extraction.iso_literal_start_index;
+    let (extraction_result, text_source) =
+        process_iso_literal_extraction(extraction, relative_path, current_working_directory)
+            .ok()?;
+
+    Some(IsoLiteralAtIndex {
+        extraction_result,
+        text_source,
+        index_in_literal: (index - start) as u32,
+    })
+}
+
+/// The text of the iso literal containing the byte index, and the index of the file at
+/// which it starts. While an iso literal is being typed, it may not have a closing
+/// backtick yet, in which case it extends to the end of the file.
+pub(crate) fn iso_literal_text_at_index(file_text: &str, index: usize) -> Option<(usize, &str)> {
+    if let Some(extraction) = extract_iso_literals_from_file_content(file_text).find(|extraction| {
+        let start = extraction.iso_literal_start_index;
+        start <= index && index <= start + extraction.iso_literal_text.len()
+    }) {
+        return Some((
+            extraction.iso_literal_start_index,
+            extraction.iso_literal_text,
+        ));
+    }
+
+    let start = ["iso(`", "iso`"]
+        .iter()
+        .filter_map(|opening| {
+            file_text
+                .get(..index)?
+                .rfind(opening)
+                .map(|opening_index| opening_index + opening.len())
+        })
+        .max()?;
+    let iso_literal_text = &file_text[start..];
+    (!iso_literal_text.contains('`')).then_some((start, iso_literal_text))
+}
+
+/// Find and parse the iso literal at the position of a request. Also returns the
+/// text of the document, which is needed to convert spans back into ranges.
+pub(crate) fn iso_literal_at_position(
+    state: &LSPState,
+    text_document_position: &TextDocumentPositionParams,
+) -> LSPRuntimeResult<(String, IsoLiteralAtIndex)> {
+    let uri = &text_document_position.text_document.uri;
+    let file_text = state
+        .text_for_uri_or_disk(uri)
+        .ok_or(LSPRuntimeError::ExpectedError)?;
+    let index = position_to_index(&file_text, text_document_position.position)
+        .ok_or(LSPRuntimeError::ExpectedError)?;
+    let relative_path = state.relative_path_for(uri)?;
+
+    let iso_literal = iso_literal_at_index(
+        &file_text,
+        index,
+        relative_path,
+        state.compiler_state().config.current_working_directory,
+    )
+    .ok_or(LSPRuntimeError::ExpectedError)?;
+    Ok((file_text, iso_literal))
+}
+
+pub(crate) struct SelectionAtIndex {
+    /// The parent type of the client field or pointer containing the selection,
+    /// e.g. User in `field User.Avatar { ... }`
+    pub(crate) parent_type: UnvalidatedTypeName,
+    /// The linked fields that must be traversed, starting at the parent type,
+    /// to reach the object on which the selection is made.
+    pub(crate) path: Vec<ObjectSelectableName>,
+    /// The name of the selected field. The span is relative to the iso literal.
+    pub(crate) name: WithSpan<SelectableName>,
+}
+
+impl IsoLiteralAtIndex {
+    /// Find the selection whose name or alias contains the cursor. The client field
+    /// named by an entrypoint, and the name of the client field or pointer being
+    /// declared, are treated as selections on the parent type.
+    pub(crate) fn selection_at_index(&self) -> Option<SelectionAtIndex> {
+        let (parent_type, declared_name, selection_set) = match &self.extraction_result {
+            IsoLiteralExtractionResult::ClientFieldDeclaration(declaration) => (
+                declaration.item.parent_type.item,
+                declaration.item.client_field_name.map(SelectableName::from),
+                &declaration.item.selection_set,
+            ),
+            IsoLiteralExtractionResult::ClientPointerDeclaration(declaration) => (
+                declaration.item.parent_type.item,
+                declaration
+                    .item
+                    .client_pointer_name
+                    .map(SelectableName::from),
+                &declaration.item.selection_set,
+            ),
+            IsoLiteralExtractionResult::EntrypointDeclaration(declaration) => {
+                let client_field_name = declaration.item.client_field_name;
+                return span_contains(client_field_name.span, self.index_in_literal).then(|| {
+                    SelectionAtIndex {
+                        parent_type: declaration.item.parent_type.item,
+                        path: vec![],
+                        name: client_field_name.map(SelectableName::from),
+                    }
+                });
+            }
+        };
+
+        if span_contains(declared_name.span, self.index_in_literal) {
+            return Some(SelectionAtIndex {
+                parent_type,
+                path: vec![],
+                name: declared_name,
+            });
+        }
+
+        let mut path = vec![];
+        let name = selection_in_selection_set(selection_set, self.index_in_literal, &mut path)?;
+        Some(SelectionAtIndex {
+            parent_type,
+            path,
+            name,
+        })
+    }
+}
+
+impl SelectionAtIndex {
+    /// The object on which the selection is made, found by starting at the parent type
+    /// and traversing the path of linked fields.
+    pub(crate) fn selection_parent_object_entity_id<TNetworkProtocol: NetworkProtocol>(
+        &self,
+        schema: &Schema<TNetworkProtocol>,
+    ) -> Option<ServerObjectEntityId> {
+        object_entity_id_at_path(schema, self.parent_type, &self.path)
+    }
+
+    pub(crate) fn selectable_id<TNetworkProtocol: NetworkProtocol>(
+        &self,
+        schema: &Schema<TNetworkProtocol>,
+    ) -> Option<SelectableId> {
+        let object_entity_id = self.selection_parent_object_entity_id(schema)?;
+        selectable_id_on_object(schema, object_entity_id, self.name.item)
+    }
+}
+
+/// Find the object reached by starting at the parent type and traversing a path of
+/// linked fields.
+pub(crate) fn object_entity_id_at_path<TNetworkProtocol: NetworkProtocol>(
+    schema: &Schema<TNetworkProtocol>,
+    parent_type: UnvalidatedTypeName,
+    path: &[ObjectSelectableName],
+) -> Option<ServerObjectEntityId> {
+    let parent_object_entity_id = match schema
+        .server_entity_data
+        .defined_entities
+        .get(&parent_type)?
+    {
+        SelectionType::Object(object_entity_id) => *object_entity_id,
+        SelectionType::Scalar(_) => return None,
+    };
+    schema
+        .traverse_object_selections(parent_object_entity_id, path.iter().copied())
+        .ok()
+        .map(|object| object.id)
+}
+
+pub(crate) fn selectable_id_on_object<TNetworkProtocol: NetworkProtocol>(
+    schema: &Schema<TNetworkProtocol>,
+    object_entity_id: ServerObjectEntityId,
+    name: SelectableName,
+) -> Option<SelectableId> {
+    schema
+        .server_entity_data
+        .server_object_entity_available_selectables
+        .get(&object_entity_id)?
+        .0
+        .get(&name)
+        .copied()
+}
+
+fn selection_in_selection_set(
+    selection_set: &[WithSpan<UnvalidatedSelection>],
+    index: u32,
+    path: &mut Vec<ObjectSelectableName>,
+) -> Option<WithSpan<SelectableName>> {
+    let selection = selection_set
+        .iter()
+        .find(|selection| span_contains(selection.span, index))?;
+
+    match &selection.item {
+        SelectionType::Scalar(scalar_selection) => name_if_index_within_name_or_alias(
+            scalar_selection.name.map(SelectableName::from),
+            scalar_selection
+                .reader_alias
+                .map(|alias| alias.location.span()),
+            index,
+        ),
+        SelectionType::Object(object_selection) => name_if_index_within_name_or_alias(
+            object_selection.name.map(SelectableName::from),
+            object_selection
+                .reader_alias
+                .map(|alias| alias.location.span()),
+            index,
+        )
+        .or_else(|| {
+            path.push(object_selection.name.item.into());
+            selection_in_selection_set(&object_selection.selection_set, index, path)
+        }),
+    }
+}
+
+fn name_if_index_within_name_or_alias(
+    name: WithLocation<SelectableName>,
+    alias_span: Option<Option<Span>>,
+    index: u32,
+) -> Option<WithSpan<SelectableName>> {
+    let name_span = name.location.span()?;
+    let is_within_alias = alias_span
+        .flatten()
+        .map(|alias_span| span_contains(alias_span, index))
+        .unwrap_or(false);
+    if span_contains(name_span, index) || is_within_alias {
+        Some(WithSpan::new(name.item, name_span))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use intern::string_key::{Intern, Lookup};
+
+    use super::iso_literal_at_index;
+
+    #[test]
+    fn finds_nested_and_aliased_selections() {
+        let text = "export const Foo = iso(`\n  field User.Foo {\n    avatar: picture\n    \
+                    bestFriend {\n      name\n    }\n  }\n`)(() => {});";
+        let find = |needle: &str| {
+            let index = text.find(needle).unwrap() + 1;
+            iso_literal_at_index(text, index, "foo.ts".intern().into(), "/".intern().into())
+                .and_then(|iso_literal| iso_literal.selection_at_index())
+                .map(|selection| {
+                    (
+                        selection.parent_type.lookup(),
+                        selection
+                            .path
+                            .iter()
+                            .map(|name| name.lookup())
+                            .collect::<Vec<_>>(),
+                        selection.name.item.lookup(),
+                    )
+                })
+        };
+
+        assert_eq!(find("avatar"), Some(("User", vec![], "picture")));
+        assert_eq!(find("picture"), Some(("User", vec![], "picture")));
+        assert_eq!(find("name"), Some(("User", vec!["bestFriend"], "name")));
+        assert_eq!(find("bestFriend"), Some(("User", vec![], "bestFriend")));
+        assert_eq!(find("Foo {"), Some(("User", vec![], "Foo")));
+        assert_eq!(find("export"), None);
+    }
+}
@@ -1,11 +1,16 @@
 use std::ops::ControlFlow;
 
 use crate::{
+    code_action::on_code_action,
+    completion::on_completion,
+    goto_definition::on_goto_definition,
+    hover::on_hover,
     lsp_notification_dispatch::LSPNotificationDispatch,
     lsp_process_error::LSPProcessResult,
     lsp_request_dispatch::LSPRequestDispatch,
     lsp_runtime_error::LSPRuntimeError,
     lsp_state::LSPState,
+    references::{on_prepare_rename, on_references, on_rename},
     semantic_tokens::{
         on_semantic_token_full_request, semantic_token_legend::semantic_token_legend,
     },
@@ -14,13 +19,18 @@ use crate::{
     },
 };
 use isograph_config::CompilerConfig;
+use isograph_schema::NetworkProtocol;
 use lsp_server::{Connection, ErrorCode, Response, ResponseError};
-use lsp_types::request::SemanticTokensFullRequest;
+use lsp_types::request::{
+    CodeActionRequest, Completion, GotoDefinition, HoverRequest, PrepareRenameRequest, References,
+    Rename, SemanticTokensFullRequest,
+};
 use lsp_types::{
     notification::{DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument},
-    InitializeParams, SemanticTokensFullOptions, SemanticTokensOptions,
-    SemanticTokensServerCapabilities, ServerCapabilities, TextDocumentSyncCapability,
-    TextDocumentSyncKind, WorkDoneProgressOptions,
+    CodeActionKind, CodeActionOptions, CodeActionProviderCapability, CompletionOptions,
+    HoverProviderCapability, InitializeParams, OneOf, RenameOptions, SemanticTokensFullOptions,
+    SemanticTokensOptions, SemanticTokensServerCapabilities, ServerCapabilities,
+    TextDocumentSyncCapability, TextDocumentSyncKind, Url, WorkDoneProgressOptions,
 };
 
 /// Initializes an LSP connection, handling the `initialize` message and `initialized` notification
@@ -37,6 +47,21 @@ pub fn initialize(connection: &Connection) -> LSPProcessResult<InitializeParams>
                 full: Some(SemanticTokensFullOptions::Bool(true)),
             },
         )),
+        definition_provider: Some(OneOf::Left(true)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        references_provider: Some(OneOf::Left(true)),
+        rename_provider: Some(OneOf::Right(RenameOptions {
+            prepare_provider: Some(true),
+            work_done_progress_options: WorkDoneProgressOptions::default(),
+        })),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(vec!["@".to_string(), "(".to_string(), "{".to_string()]),
+            ..Default::default()
+        }),
+        code_action_provider: Some(CodeActionProviderCapability::Options(CodeActionOptions {
+            code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
+            ..Default::default()
+        })),
         ..Default::default()
     };
     let server_capabilities = serde_json::to_value(server_capabilities)?;
@@ -46,23 +71,27 @@ pub fn initialize(connection: &Connection) -> LSPProcessResult<InitializeParams>
 }
 
 /// Run the main server loop
-pub async fn run(
+pub async fn run<TNetworkProtocol: NetworkProtocol>(
     connection: Connection,
-    config: CompilerConfig,
+    configs: Vec<CompilerConfig>,
     _params: InitializeParams,
 ) -> LSPProcessResult<()> {
     eprintln!("Running server loop");
-    let mut state = LSPState::new(connection.sender.clone(), config);
+    let mut state = LSPState::new(connection.sender.clone(), configs);
     while let Ok(message) = connection.receiver.recv() {
         match message {
             lsp_server::Message::Request(request) => {
                 eprintln!("Received request: {:?}", request);
-                let response = dispatch_request(request, &mut state);
+                let response = dispatch_request::<TNetworkProtocol>(request, &mut state);
                 eprintln!("Sending response: {:?}", response);
                 state.send_message(response.into());
             }
             lsp_server::Message::Notification(notification) => {
-                dispatch_notification(notification, &mut state);
+                if let ControlFlow::Break(Some(error)) =
+                    dispatch_notification::<TNetworkProtocol>(notification, &mut state)
+                {
+                    eprintln!("Error handling notification: {:?}", error);
+                }
             }
             lsp_server::Message::Response(response) => {
                 eprintln!("Received response: {:?}", response);
@@ -73,24 +102,51 @@ pub async fn run(
     panic!("Client exited without proper shutdown sequence.")
 }
 
-fn dispatch_notification(
+fn dispatch_notification<TNetworkProtocol: NetworkProtocol>(
     notification: lsp_server::Notification,
     lsp_state: &mut LSPState,
 ) -> ControlFlow<Option<LSPRuntimeError>, ()> {
     LSPNotificationDispatch::new(notification, lsp_state)
-        .on_notification_sync::<DidOpenTextDocument>(on_did_open_text_document)?
-        .on_notification_sync::<DidCloseTextDocument>(on_did_close_text_document)?
-        .on_notification_sync::<DidChangeTextDocument>(on_did_change_text_document)?
+        .on_notification_sync::<DidOpenTextDocument>(on_did_open_text_document::<TNetworkProtocol>)?
+        .on_notification_sync::<DidCloseTextDocument>(
+            on_did_close_text_document::<TNetworkProtocol>,
+        )?
+        .on_notification_sync::<DidChangeTextDocument>(
+            on_did_change_text_document::<TNetworkProtocol>,
+        )?
         .notification();
 
     ControlFlow::Continue(())
 }
-fn dispatch_request(request: lsp_server::Request, lsp_state: &mut LSPState) -> Response {
+fn dispatch_request<TNetworkProtocol: NetworkProtocol>(
+    request: lsp_server::Request,
+    lsp_state: &mut LSPState,
+) -> Response {
+    // Every request that we handle is about a text document. If the config file
+    // contains multiple projects, the request is answered by the project that
+    // compiles that document.
+    if let Some(uri) = request
+        .params
+        .get("textDocument")
+        .and_then(|text_document| text_document.get("uri"))
+        .and_then(|uri| uri.as_str())
+        .and_then(|uri| Url::parse(uri).ok())
+    {
+        lsp_state.select_project_for_document(&uri);
+    }
+
     // Returns ControlFlow::Break(ServerResponse) if the request
     // was handled, ControlFlow::Continue(Request) otherwise.
     let get_response = || {
         let request = LSPRequestDispatch::new(request, lsp_state)
             .on_request_sync::<SemanticTokensFullRequest>(on_semantic_token_full_request)?
+            .on_request_sync::<GotoDefinition>(on_goto_definition::<TNetworkProtocol>)?
+            .on_request_sync::<HoverRequest>(on_hover::<TNetworkProtocol>)?
+            .on_request_sync::<Completion>(on_completion::<TNetworkProtocol>)?
+            .on_request_sync::<References>(on_references::<TNetworkProtocol>)?
+            .on_request_sync::<PrepareRenameRequest>(on_prepare_rename::<TNetworkProtocol>)?
+            .on_request_sync::<Rename>(on_rename::<TNetworkProtocol>)?
+            .on_request_sync::<CodeActionRequest>(on_code_action)?
             .request();
 
         // If we have gotten here, we have not handled the request
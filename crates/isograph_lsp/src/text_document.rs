@@ -1,3 +1,4 @@
+use isograph_schema::NetworkProtocol;
 use lsp_types::{
     notification::{
         DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, Notification,
@@ -5,28 +6,31 @@ use lsp_types::{
     DidChangeTextDocumentParams, DidOpenTextDocumentParams, TextDocumentItem,
 };
 
-use crate::{lsp_runtime_error::LSPRuntimeResult, lsp_state::LSPState};
+use crate::{
+    diagnostics::publish_diagnostics, lsp_runtime_error::LSPRuntimeResult, lsp_state::LSPState,
+};
 
-pub fn on_did_open_text_document(
+pub fn on_did_open_text_document<TNetworkProtocol: NetworkProtocol>(
     lsp_state: &mut LSPState,
     params: <DidOpenTextDocument as Notification>::Params,
 ) -> LSPRuntimeResult<()> {
     let DidOpenTextDocumentParams { text_document } = params;
     let TextDocumentItem { text, uri, .. } = text_document;
 
-    lsp_state.document_opened(&uri, &text)
+    lsp_state.document_opened(&uri, &text)?;
+    publish_diagnostics::<TNetworkProtocol>(lsp_state)
 }
 
-#[allow(clippy::unnecessary_wraps)]
-pub fn on_did_close_text_document(
+pub fn on_did_close_text_document<TNetworkProtocol: NetworkProtocol>(
     lsp_state: &mut LSPState,
     params: <DidCloseTextDocument as Notification>::Params,
 ) -> LSPRuntimeResult<()> {
     let uri = params.text_document.uri;
-    lsp_state.document_closed(&uri)
+    lsp_state.document_closed(&uri)?;
+    publish_diagnostics::<TNetworkProtocol>(lsp_state)
 }
 
-pub fn on_did_change_text_document(
+pub fn on_did_change_text_document<TNetworkProtocol: NetworkProtocol>(
     lsp_state: &mut LSPState,
     params: <DidChangeTextDocument as Notification>::Params,
 ) -> LSPRuntimeResult<()> {
@@ -41,5 +45,6 @@ pub fn on_did_change_text_document(
         .first()
         .expect("content_changes should always be non-empty");
 
-    lsp_state.document_changed(&uri, &content_change.text)
+    lsp_state.document_changed(&uri, &content_change.text)?;
+    publish_diagnostics::<TNetworkProtocol>(lsp_state)
 }
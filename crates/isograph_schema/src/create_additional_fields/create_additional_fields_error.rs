@@ -2,8 +2,9 @@ use std::collections::HashMap;
 
 use crate::{NetworkProtocol, Schema};
 use common_lang_types::{
-    IsographObjectTypeName, SelectableName, StringLiteralValue, UnvalidatedTypeName, VariableName,
-    WithLocation,
+    did_you_mean, DiagnosticCode, FieldArgumentName, GraphQLScalarTypeName, HasDiagnosticCode,
+    IsographObjectTypeName, SelectableName, ServerObjectSelectableName, ServerScalarSelectableName,
+    StringLiteralValue, UnvalidatedTypeName, VariableName, WithLocation,
 };
 use intern::{string_key::Intern, Lookup};
 use isograph_lang_types::ServerObjectEntityId;
@@ -114,7 +115,7 @@ pub enum CreateAdditionalFieldsError {
     )]
     IdFieldMustBeNonNullIdType {
         parent_type: IsographObjectTypeName,
-        strong_field_name: &'static str,
+        strong_field_name: ServerScalarSelectableName,
     },
 
     #[error(
@@ -140,6 +141,218 @@ pub enum CreateAdditionalFieldsError {
 
     #[error("Expected {type_name} to be an object, but it was a scalar.")]
     GenericObjectIsScalar { type_name: UnvalidatedTypeName },
+
+    #[error(
+        "The refetch_from_root config option contains the type `{type_name}`, \
+        but no object type with that name exists.{}",
+        did_you_mean(*suggestion)
+    )]
+    RefetchFromRootTypeDoesNotExist {
+        type_name: IsographObjectTypeName,
+        suggestion: Option<IsographObjectTypeName>,
+    },
+
+    #[error(
+        "The root path `{path}` of `{type_name}` selects `{parent_type_name}.{field_name}`, \
+        but `{parent_type_name}` has no object field named `{field_name}`.{}",
+        did_you_mean(*suggestion)
+    )]
+    RefetchFromRootFieldDoesNotExist {
+        type_name: IsographObjectTypeName,
+        path: StringLiteralValue,
+        parent_type_name: IsographObjectTypeName,
+        field_name: SelectableName,
+        suggestion: Option<SelectableName>,
+    },
+
+    #[error(
+        "The root path `{path}` of `{type_name}` selects `{parent_type_name}.{field_name}`, \
+        which returns a list. Each field in a root path must return a single object."
+    )]
+    RefetchFromRootFieldIsPlural {
+        type_name: IsographObjectTypeName,
+        path: StringLiteralValue,
+        parent_type_name: IsographObjectTypeName,
+        field_name: SelectableName,
+    },
+
+    #[error(
+        "The root path `{path}` of `{type_name}` selects `{parent_type_name}.{field_name}`, \
+        which has required arguments. Fields in a root path are selected without arguments."
+    )]
+    RefetchFromRootFieldHasRequiredArguments {
+        type_name: IsographObjectTypeName,
+        path: StringLiteralValue,
+        parent_type_name: IsographObjectTypeName,
+        field_name: SelectableName,
+    },
+
+    #[error(
+        "The root path `{path}` of `{type_name}` leads to an object of type \
+        `{path_type_name}`, not `{type_name}`."
+    )]
+    RefetchFromRootPathHasWrongType {
+        type_name: IsographObjectTypeName,
+        path: StringLiteralValue,
+        path_type_name: IsographObjectTypeName,
+    },
+
+    #[error(
+        "The root path of `{type_name}` is declared both with a @refetchFromRoot \
+        directive and in the refetch_from_root config option. Remove one of them."
+    )]
+    RefetchFromRootDeclaredTwice { type_name: IsographObjectTypeName },
+
+    #[error(
+        "The refetch_field config option names the field `{query_type_name}.{field_name}`, \
+        but `{query_type_name}` has no object field named `{field_name}`.{}",
+        did_you_mean(*suggestion)
+    )]
+    RefetchFieldDoesNotExist {
+        query_type_name: IsographObjectTypeName,
+        field_name: ServerObjectSelectableName,
+        suggestion: Option<SelectableName>,
+    },
+
+    #[error(
+        "The refetch_field config option passes the argument `{argument_name}` to \
+        `{query_type_name}.{field_name}`, but that field has no argument named \
+        `{argument_name}`.{}",
+        did_you_mean(*suggestion)
+    )]
+    RefetchFieldArgumentDoesNotExist {
+        query_type_name: IsographObjectTypeName,
+        field_name: ServerObjectSelectableName,
+        argument_name: FieldArgumentName,
+        suggestion: Option<FieldArgumentName>,
+    },
+
+    #[error(
+        "The field `{query_type_name}.{field_name}` has a required argument \
+        `{argument_name}`, which is not passed by the refetch_field config option."
+    )]
+    RefetchFieldArgumentIsMissing {
+        query_type_name: IsographObjectTypeName,
+        field_name: ServerObjectSelectableName,
+        argument_name: FieldArgumentName,
+    },
+
+    #[error(
+        "The refetch_field config option must pass the id of the refetched object \
+        (\"id\") to exactly one argument of `{query_type_name}.{field_name}`."
+    )]
+    RefetchFieldMustHaveOneIdArgument {
+        query_type_name: IsographObjectTypeName,
+        field_name: ServerObjectSelectableName,
+    },
+
+    #[error(
+        "The id_fields config option contains the type `{type_name}`, \
+        but no object type with that name exists.{}",
+        did_you_mean(*suggestion)
+    )]
+    IdFieldsTypeDoesNotExist {
+        type_name: IsographObjectTypeName,
+        suggestion: Option<IsographObjectTypeName>,
+    },
+
+    #[error(
+        "The id_fields config option names `{type_name}.{field_name}` as the id field \
+        of `{type_name}`, but `{type_name}` has no scalar field named `{field_name}`.{}",
+        did_you_mean(*suggestion)
+    )]
+    IdFieldDoesNotExist {
+        type_name: IsographObjectTypeName,
+        field_name: ServerScalarSelectableName,
+        suggestion: Option<SelectableName>,
+    },
+
+    #[error(
+        "The id_fields config option names `{type_name}.{field_name}` as the id field \
+        of `{type_name}`, but `{type_name}` also has a field named `id`. \
+        The id field is selected as `id`, so these would conflict."
+    )]
+    IdFieldConflictsWithFieldNamedId {
+        type_name: IsographObjectTypeName,
+        field_name: ServerScalarSelectableName,
+    },
+
+    #[error(
+        "The custom_scalar_types config option contains the scalar `{scalar_name}`, \
+        but no scalar with that name exists.{}",
+        did_you_mean(*suggestion)
+    )]
+    CustomScalarTypeDoesNotExist {
+        scalar_name: GraphQLScalarTypeName,
+        suggestion: Option<GraphQLScalarTypeName>,
+    },
+
+    #[error(
+        "The custom_scalar_types config option contains `{enum_name}`, which is an enum. \
+        Enums are typed as the union of their values, and cannot be given a custom type."
+    )]
+    CustomScalarTypeIsEnum { enum_name: GraphQLScalarTypeName },
+}
+
+impl HasDiagnosticCode for CreateAdditionalFieldsError {
+    fn diagnostic_code(&self) -> DiagnosticCode {
+        match self {
+            CreateAdditionalFieldsError::CompilerCreatedFieldExistsOnType { .. } => {
+                DiagnosticCode(801)
+            }
+            CreateAdditionalFieldsError::DuplicateField { .. } => DiagnosticCode(802),
+            CreateAdditionalFieldsError::InvalidField => DiagnosticCode(803),
+            CreateAdditionalFieldsError::InvalidMutationField => DiagnosticCode(804),
+            CreateAdditionalFieldsError::PrimaryDirectiveArgumentDoesNotExistOnField { .. } => {
+                DiagnosticCode(805)
+            }
+            CreateAdditionalFieldsError::PrimaryDirectiveCannotRemapObject { .. } => {
+                DiagnosticCode(806)
+            }
+            CreateAdditionalFieldsError::PrimaryDirectiveFieldNotFound { .. } => {
+                DiagnosticCode(807)
+            }
+            CreateAdditionalFieldsError::FailedToDeserialize(_) => DiagnosticCode(808),
+            CreateAdditionalFieldsError::IdFieldMustBeNonNullIdType { .. } => DiagnosticCode(809),
+            CreateAdditionalFieldsError::FieldArgumentTypeDoesNotExist { .. } => {
+                DiagnosticCode(810)
+            }
+            CreateAdditionalFieldsError::FieldTypenameDoesNotExist { .. } => DiagnosticCode(811),
+            CreateAdditionalFieldsError::DuplicateTypeDefinition { .. } => DiagnosticCode(812),
+            CreateAdditionalFieldsError::GenericObjectIsScalar { .. } => DiagnosticCode(813),
+            CreateAdditionalFieldsError::RefetchFromRootTypeDoesNotExist { .. } => {
+                DiagnosticCode(814)
+            }
+            CreateAdditionalFieldsError::RefetchFromRootFieldDoesNotExist { .. } => {
+                DiagnosticCode(815)
+            }
+            CreateAdditionalFieldsError::RefetchFromRootFieldIsPlural { .. } => DiagnosticCode(816),
+            CreateAdditionalFieldsError::RefetchFromRootFieldHasRequiredArguments { .. } => {
+                DiagnosticCode(817)
+            }
+            CreateAdditionalFieldsError::RefetchFromRootPathHasWrongType { .. } => {
+                DiagnosticCode(818)
+            }
+            CreateAdditionalFieldsError::RefetchFromRootDeclaredTwice { .. } => DiagnosticCode(819),
+            CreateAdditionalFieldsError::RefetchFieldDoesNotExist { .. } => DiagnosticCode(820),
+            CreateAdditionalFieldsError::RefetchFieldArgumentDoesNotExist { .. } => {
+                DiagnosticCode(821)
+            }
+            CreateAdditionalFieldsError::RefetchFieldArgumentIsMissing { .. } => {
+                DiagnosticCode(822)
+            }
+            CreateAdditionalFieldsError::RefetchFieldMustHaveOneIdArgument { .. } => {
+                DiagnosticCode(823)
+            }
+            CreateAdditionalFieldsError::IdFieldsTypeDoesNotExist { .. } => DiagnosticCode(824),
+            CreateAdditionalFieldsError::IdFieldDoesNotExist { .. } => DiagnosticCode(825),
+            CreateAdditionalFieldsError::IdFieldConflictsWithFieldNamedId { .. } => {
+                DiagnosticCode(826)
+            }
+            CreateAdditionalFieldsError::CustomScalarTypeDoesNotExist { .. } => DiagnosticCode(827),
+            CreateAdditionalFieldsError::CustomScalarTypeIsEnum { .. } => DiagnosticCode(828),
+        }
+    }
 }
 
 #[derive(Debug)]
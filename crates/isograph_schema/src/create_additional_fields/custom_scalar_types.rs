@@ -0,0 +1,70 @@
+use std::collections::BTreeMap;
+
+use common_lang_types::{
+    closest_match, GraphQLScalarTypeName, JavascriptName, Location, WithLocation,
+};
+use intern::Lookup;
+
+use crate::{NetworkProtocol, Schema};
+
+use super::create_additional_fields_error::CreateAdditionalFieldsError;
+
+impl<TNetworkProtocol: NetworkProtocol> Schema<TNetworkProtocol> {
+    /// Validate the custom_scalar_types config option, and set the javascript name of
+    /// each scalar that it contains.
+    ///
+    /// Enums are modeled as scalars, but they are typed as the union of their values,
+    /// so they cannot be given a custom type.
+    pub fn add_custom_scalar_types(
+        &mut self,
+        custom_scalar_types: &BTreeMap<GraphQLScalarTypeName, JavascriptName>,
+    ) -> Result<(), Vec<WithLocation<CreateAdditionalFieldsError>>> {
+        let mut errors = vec![];
+
+        for (scalar_name, javascript_name) in custom_scalar_types {
+            let server_scalar_entity = self
+                .server_entity_data
+                .server_scalars
+                .iter_mut()
+                .find(|server_scalar_entity| server_scalar_entity.name.item == *scalar_name);
+
+            match server_scalar_entity {
+                Some(server_scalar_entity) if server_scalar_entity.enum_values.is_some() => {
+                    errors.push(WithLocation::new(
+                        CreateAdditionalFieldsError::CustomScalarTypeIsEnum {
+                            enum_name: *scalar_name,
+                        },
+                        Location::generated(),
+                    ));
+                }
+                Some(server_scalar_entity) => {
+                    server_scalar_entity.javascript_name = *javascript_name;
+                }
+                None => {
+                    errors.push(WithLocation::new(
+                        CreateAdditionalFieldsError::CustomScalarTypeDoesNotExist {
+                            scalar_name: *scalar_name,
+                            suggestion: closest_match(
+                                scalar_name.lookup(),
+                                self.server_entity_data
+                                    .server_scalars
+                                    .iter()
+                                    .filter(|server_scalar_entity| {
+                                        server_scalar_entity.enum_values.is_none()
+                                    })
+                                    .map(|server_scalar_entity| server_scalar_entity.name.item),
+                            ),
+                        },
+                        Location::generated(),
+                    ));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
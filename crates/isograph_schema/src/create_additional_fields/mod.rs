@@ -1,8 +1,12 @@
 pub(crate) mod add_link_fields;
 mod argument_map;
 mod create_additional_fields_error;
+pub(crate) mod custom_scalar_types;
 pub(crate) mod expose_field_directive;
+pub(crate) mod refetch_field_options;
+pub(crate) mod refetch_from_root_directive;
 mod transfer_supertype_selectables_to_subtypes;
 
 pub use create_additional_fields_error::*;
 pub use expose_field_directive::*;
+pub use refetch_from_root_directive::*;
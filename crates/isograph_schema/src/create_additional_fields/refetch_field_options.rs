@@ -0,0 +1,203 @@
+use common_lang_types::{
+    closest_match, FieldArgumentName, Location, SelectableName, UnvalidatedTypeName, WithLocation,
+};
+use intern::{string_key::Intern, Lookup};
+use isograph_config::{CompilerConfigOptions, RefetchFieldArgumentValue, RefetchFieldOptions};
+use isograph_lang_types::{DefinitionLocation, SelectionType, ServerEntityId};
+
+use crate::{NetworkProtocol, Schema};
+
+use super::create_additional_fields_error::CreateAdditionalFieldsError;
+
+impl<TNetworkProtocol: NetworkProtocol> Schema<TNetworkProtocol> {
+    /// Validate the refetch_field and id_fields config options, and store the refetch
+    /// field in refetch_field.
+    ///
+    /// If no refetch field is configured, objects are refetched via Query.node, which
+    /// is not validated, since many schemas without a node field never refetch anything.
+    ///
+    /// This must happen before client fields are added, since their refetch strategies
+    /// select the refetch field.
+    pub fn add_refetch_field_options(
+        &mut self,
+        options: &CompilerConfigOptions,
+    ) -> Result<(), Vec<WithLocation<CreateAdditionalFieldsError>>> {
+        let mut errors = vec![];
+
+        if let Some(refetch_field) = &options.refetch_field {
+            match self.validate_refetch_field(refetch_field) {
+                Ok(()) => self.refetch_field = refetch_field.clone(),
+                Err(e) => errors.push(WithLocation::new(e, Location::generated())),
+            }
+        }
+
+        for (type_name, id_field_name) in &options.id_fields {
+            let unvalidated_type_name: UnvalidatedTypeName = (*type_name).into();
+            let object_entity_id = match self
+                .server_entity_data
+                .defined_entities
+                .get(&unvalidated_type_name)
+            {
+                Some(ServerEntityId::Object(object_entity_id)) => *object_entity_id,
+                _ => {
+                    errors.push(WithLocation::new(
+                        CreateAdditionalFieldsError::IdFieldsTypeDoesNotExist {
+                            type_name: *type_name,
+                            suggestion: self.closest_object_type_name(unvalidated_type_name),
+                        },
+                        Location::generated(),
+                    ));
+                    continue;
+                }
+            };
+
+            let (available_selectables, id_field, _) = self
+                .server_entity_data
+                .server_object_entity_available_selectables
+                .get(&object_entity_id)
+                .expect(
+                    "Expected object_entity_id to exist \
+                    in server_object_entity_available_selectables",
+                );
+
+            // The id field is set when server fields are inserted, so if it is
+            // missing, the type has no scalar field with the configured name.
+            if id_field.is_none() {
+                errors.push(WithLocation::new(
+                    CreateAdditionalFieldsError::IdFieldDoesNotExist {
+                        type_name: *type_name,
+                        field_name: *id_field_name,
+                        suggestion: closest_match(
+                            id_field_name.lookup(),
+                            available_selectables
+                                .iter()
+                                .filter(|(_, selectable_id)| {
+                                    matches!(
+                                        selectable_id,
+                                        DefinitionLocation::Server(SelectionType::Scalar(_))
+                                    )
+                                })
+                                .map(|(name, _)| *name),
+                        ),
+                    },
+                    Location::generated(),
+                ));
+            } else if *id_field_name != "id"
+                && available_selectables.contains_key(&"id".intern().into())
+            {
+                errors.push(WithLocation::new(
+                    CreateAdditionalFieldsError::IdFieldConflictsWithFieldNamedId {
+                        type_name: *type_name,
+                        field_name: *id_field_name,
+                    },
+                    Location::generated(),
+                ));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Validate that the refetch field exists on Query, that each configured argument
+    /// exists, that all required arguments are configured, and that exactly one
+    /// argument is passed the id.
+    fn validate_refetch_field(
+        &self,
+        refetch_field: &RefetchFieldOptions,
+    ) -> Result<(), CreateAdditionalFieldsError> {
+        let query_id = self.query_id();
+        let query_type_name = self.server_entity_data.server_object_entity(query_id).name;
+        let field_name = refetch_field.name;
+
+        let available_selectables = &self
+            .server_entity_data
+            .server_object_entity_available_selectables
+            .get(&query_id)
+            .expect(
+                "Expected query_id to exist \
+                in server_object_entity_available_selectables",
+            )
+            .0;
+
+        let server_object_selectable = match available_selectables.get(&field_name.into()) {
+            Some(DefinitionLocation::Server(SelectionType::Object(
+                server_object_selectable_id,
+            ))) => self.server_object_selectable(*server_object_selectable_id),
+            _ => {
+                return Err(CreateAdditionalFieldsError::RefetchFieldDoesNotExist {
+                    query_type_name,
+                    field_name,
+                    suggestion: closest_match(
+                        field_name.lookup(),
+                        available_selectables
+                            .iter()
+                            .filter(|(_, selectable_id)| {
+                                matches!(
+                                    selectable_id,
+                                    DefinitionLocation::Server(SelectionType::Object(_))
+                                )
+                            })
+                            .map(|(name, _): (&SelectableName, _)| *name),
+                    ),
+                })
+            }
+        };
+
+        let argument_names = server_object_selectable
+            .arguments
+            .iter()
+            .map(|argument| -> FieldArgumentName { argument.item.name.item.unchecked_conversion() })
+            .collect::<Vec<_>>();
+
+        for argument_name in refetch_field.arguments.keys() {
+            if !argument_names.contains(argument_name) {
+                return Err(
+                    CreateAdditionalFieldsError::RefetchFieldArgumentDoesNotExist {
+                        query_type_name,
+                        field_name,
+                        argument_name: *argument_name,
+                        suggestion: closest_match(
+                            argument_name.lookup(),
+                            argument_names.iter().copied(),
+                        ),
+                    },
+                );
+            }
+        }
+
+        for argument in &server_object_selectable.arguments {
+            let argument_name: FieldArgumentName = argument.item.name.item.unchecked_conversion();
+            if argument.item.default_value.is_none()
+                && !argument.item.type_.is_nullable()
+                && !refetch_field.arguments.contains_key(&argument_name)
+            {
+                return Err(CreateAdditionalFieldsError::RefetchFieldArgumentIsMissing {
+                    query_type_name,
+                    field_name,
+                    argument_name,
+                });
+            }
+        }
+
+        if refetch_field
+            .arguments
+            .values()
+            .filter(|value| **value == RefetchFieldArgumentValue::Id)
+            .count()
+            != 1
+        {
+            return Err(
+                CreateAdditionalFieldsError::RefetchFieldMustHaveOneIdArgument {
+                    query_type_name,
+                    field_name,
+                },
+            );
+        }
+
+        Ok(())
+    }
+}
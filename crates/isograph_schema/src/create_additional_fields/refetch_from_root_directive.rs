@@ -0,0 +1,245 @@
+use std::collections::BTreeMap;
+
+use common_lang_types::{
+    closest_match, DirectiveName, IsographObjectTypeName, SelectableName, StringLiteralValue,
+    UnvalidatedTypeName, WithLocation,
+};
+use graphql_lang_types::{from_graph_ql_directive, DeserializationError};
+use intern::{string_key::Intern, Lookup};
+use isograph_lang_types::{
+    DefinitionLocation, SelectionType, ServerEntityId, ServerObjectEntityId, TypeAnnotation,
+    UnionVariant,
+};
+use lazy_static::lazy_static;
+use serde::Deserialize;
+
+use crate::{
+    NetworkProtocol, RefetchFromRootRefetchStrategy, Schema, WrappedSelectionMapSelection,
+};
+
+use super::create_additional_fields_error::CreateAdditionalFieldsError;
+
+lazy_static! {
+    static ref REFETCH_FROM_ROOT_DIRECTIVE: DirectiveName = "refetchFromRoot".intern().into();
+}
+
+#[derive(Deserialize, Eq, PartialEq, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct RefetchFromRootDirective {
+    path: StringLiteralValue,
+}
+
+impl RefetchFromRootDirective {
+    pub fn new(path: StringLiteralValue) -> Self {
+        Self { path }
+    }
+}
+
+impl<TNetworkProtocol: NetworkProtocol> Schema<TNetworkProtocol> {
+    /// Validate the root paths declared with @refetchFromRoot directives and with the
+    /// refetch_from_root config option, and store them in refetch_root_paths.
+    ///
+    /// This must happen before client fields are added, since client fields on
+    /// these objects are refetched (e.g. when selected loadably) via the root path.
+    pub fn add_refetch_root_paths(
+        &mut self,
+        config_refetch_root_paths: &BTreeMap<
+            IsographObjectTypeName,
+            WithLocation<StringLiteralValue>,
+        >,
+    ) -> Result<(), Vec<WithLocation<CreateAdditionalFieldsError>>> {
+        let mut errors = vec![];
+        let mut declared_paths = BTreeMap::new();
+
+        for (object_entity_id, (_, _, directives)) in &self
+            .server_entity_data
+            .server_object_entity_available_selectables
+        {
+            for directive in directives {
+                if directive.name.item != *REFETCH_FROM_ROOT_DIRECTIVE {
+                    continue;
+                }
+                match from_graph_ql_directive::<RefetchFromRootDirective>(directive) {
+                    Ok(RefetchFromRootDirective { path }) => {
+                        declared_paths
+                            .insert(*object_entity_id, (path, directive.name.location.into()));
+                    }
+                    Err(DeserializationError::Custom(err)) => errors.push(WithLocation::new(
+                        CreateAdditionalFieldsError::FailedToDeserialize(err),
+                        directive.name.location.into(),
+                    )),
+                }
+            }
+        }
+
+        for (type_name, path) in config_refetch_root_paths {
+            let unvalidated_type_name: UnvalidatedTypeName = (*type_name).into();
+            let object_entity_id = match self
+                .server_entity_data
+                .defined_entities
+                .get(&unvalidated_type_name)
+            {
+                Some(ServerEntityId::Object(object_entity_id)) => *object_entity_id,
+                _ => {
+                    errors.push(WithLocation::new(
+                        CreateAdditionalFieldsError::RefetchFromRootTypeDoesNotExist {
+                            type_name: *type_name,
+                            suggestion: self.closest_object_type_name(unvalidated_type_name),
+                        },
+                        path.location,
+                    ));
+                    continue;
+                }
+            };
+            if declared_paths
+                .insert(object_entity_id, (path.item, path.location))
+                .is_some()
+            {
+                errors.push(WithLocation::new(
+                    CreateAdditionalFieldsError::RefetchFromRootDeclaredTwice {
+                        type_name: *type_name,
+                    },
+                    path.location,
+                ));
+            }
+        }
+
+        for (object_entity_id, (path, location)) in declared_paths {
+            match self.refetch_from_root_strategy(object_entity_id, path) {
+                Ok(strategy) => {
+                    self.refetch_root_paths.insert(object_entity_id, strategy);
+                }
+                Err(e) => errors.push(WithLocation::new(e, location)),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Follow the path (e.g. viewer.settings) from Query, and validate that it leads
+    /// to a single object of the given type.
+    fn refetch_from_root_strategy(
+        &self,
+        object_entity_id: ServerObjectEntityId,
+        path: StringLiteralValue,
+    ) -> Result<RefetchFromRootRefetchStrategy, CreateAdditionalFieldsError> {
+        let query_id = self.query_id();
+        let type_name = self
+            .server_entity_data
+            .server_object_entity(object_entity_id)
+            .name;
+
+        let mut current_object_entity_id = query_id;
+        let mut wrapped_selections = vec![];
+        for field_name in path.lookup().split('.') {
+            let field_name: SelectableName = field_name.intern().into();
+            let current_object_name = self
+                .server_entity_data
+                .server_object_entity(current_object_entity_id)
+                .name;
+            let available_selectables = &self
+                .server_entity_data
+                .server_object_entity_available_selectables
+                .get(&current_object_entity_id)
+                .expect(
+                    "Expected object_entity_id to exist \
+                    in server_object_entity_available_selectables",
+                )
+                .0;
+
+            let server_object_selectable = match available_selectables.get(&field_name) {
+                Some(DefinitionLocation::Server(SelectionType::Object(
+                    server_object_selectable_id,
+                ))) => self.server_object_selectable(*server_object_selectable_id),
+                _ => {
+                    return Err(
+                        CreateAdditionalFieldsError::RefetchFromRootFieldDoesNotExist {
+                            type_name,
+                            path,
+                            parent_type_name: current_object_name,
+                            field_name,
+                            suggestion: closest_match(
+                                field_name.lookup(),
+                                available_selectables
+                                    .iter()
+                                    .filter(|(_, selectable_id)| {
+                                        matches!(
+                                            selectable_id,
+                                            DefinitionLocation::Server(SelectionType::Object(_))
+                                        )
+                                    })
+                                    .map(|(name, _)| *name),
+                            ),
+                        },
+                    )
+                }
+            };
+
+            if is_plural(&server_object_selectable.target_object_entity) {
+                return Err(CreateAdditionalFieldsError::RefetchFromRootFieldIsPlural {
+                    type_name,
+                    path,
+                    parent_type_name: current_object_name,
+                    field_name,
+                });
+            }
+            if server_object_selectable.arguments.iter().any(|argument| {
+                argument.item.default_value.is_none() && !argument.item.type_.is_nullable()
+            }) {
+                return Err(
+                    CreateAdditionalFieldsError::RefetchFromRootFieldHasRequiredArguments {
+                        type_name,
+                        path,
+                        parent_type_name: current_object_name,
+                        field_name,
+                    },
+                );
+            }
+
+            current_object_entity_id = *server_object_selectable.target_object_entity.inner();
+            wrapped_selections.push(WrappedSelectionMapSelection::LinkedField {
+                server_object_selectable_name: server_object_selectable.name.item,
+                arguments: vec![],
+                concrete_type: self
+                    .server_entity_data
+                    .server_object_entity(current_object_entity_id)
+                    .concrete_type,
+            });
+        }
+
+        if current_object_entity_id != object_entity_id {
+            return Err(
+                CreateAdditionalFieldsError::RefetchFromRootPathHasWrongType {
+                    type_name,
+                    path,
+                    path_type_name: self
+                        .server_entity_data
+                        .server_object_entity(current_object_entity_id)
+                        .name,
+                },
+            );
+        }
+
+        // selection_map_wrapped expects the innermost selection first
+        wrapped_selections.reverse();
+        Ok(RefetchFromRootRefetchStrategy {
+            root_fetchable_type: query_id,
+            path: wrapped_selections,
+        })
+    }
+}
+
+fn is_plural<TInner>(type_annotation: &TypeAnnotation<TInner>) -> bool {
+    match type_annotation {
+        TypeAnnotation::Scalar(_) => false,
+        TypeAnnotation::Union(union_type_annotation) => union_type_annotation
+            .variants
+            .iter()
+            .any(|variant| matches!(variant, UnionVariant::Plural(_))),
+        TypeAnnotation::Plural(_) => true,
+    }
+}
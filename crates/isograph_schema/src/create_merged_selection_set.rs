@@ -1,9 +1,9 @@
 use std::collections::{btree_map::Entry, BTreeMap, BTreeSet, HashSet};
 
 use common_lang_types::{
-    ClientScalarSelectableName, IsographObjectTypeName, Location, QueryOperationName,
-    ScalarSelectableName, SelectableName, ServerObjectSelectableName, ServerScalarSelectableName,
-    Span, VariableName, WithLocation, WithSpan,
+    ClientScalarSelectableName, IsographObjectTypeName, Location, ObjectTypeAndFieldName,
+    QueryOperationName, ScalarSelectableName, SelectableName, ServerObjectSelectableName,
+    ServerScalarSelectableName, Span, VariableName, WithLocation, WithSpan,
 };
 use graphql_lang_types::{
     GraphQLNamedTypeAnnotation, GraphQLNonNullTypeAnnotation, GraphQLTypeAnnotation,
@@ -48,7 +48,6 @@ pub struct FieldTraversalResult {
 
 lazy_static! {
     pub static ref REFETCH_FIELD_NAME: ClientScalarSelectableName = "__refetch".intern().into();
-    pub static ref NODE_FIELD_NAME: ServerObjectSelectableName = "node".intern().into();
     pub static ref TYPENAME_FIELD_NAME: ServerScalarSelectableName = "__typename".intern().into();
     pub static ref LINK_FIELD_NAME: ClientScalarSelectableName = "link".intern().into();
 }
@@ -66,6 +65,7 @@ pub enum MergedServerSelection {
     LinkedField(MergedLinkedFieldSelection),
     // TODO does this belong? This is very GraphQL specific.
     InlineFragment(MergedInlineFragmentSelection),
+    DeferredFragment(MergedDeferredFragmentSelection),
 }
 
 impl MergedServerSelection {
@@ -81,6 +81,11 @@ impl MergedServerSelection {
                 )
                 .collect(),
             MergedServerSelection::InlineFragment(_) => vec![],
+            MergedServerSelection::DeferredFragment(deferred_fragment) => deferred_fragment
+                .selection_map
+                .values()
+                .flat_map(|x| x.reachable_variables())
+                .collect(),
         }
     }
 }
@@ -96,10 +101,17 @@ fn get_variables(arguments: &[ArgumentKeyAndValue]) -> impl Iterator<Item = Vari
 pub struct MergedScalarFieldSelection {
     pub name: ScalarSelectableName,
     pub arguments: Vec<ArgumentKeyAndValue>,
+    /// Some if this is an id field that is not named id (see the id_fields config
+    /// option), which is selected as id (e.g. id: uuid), so that the object is
+    /// normalized by its id.
+    pub id_alias: Option<ScalarSelectableName>,
 }
 
 impl MergedScalarFieldSelection {
     pub fn normalization_alias(&self) -> Option<String> {
+        if let Some(id_alias) = self.id_alias {
+            return Some(id_alias.to_string());
+        }
         // None if the alias is the same as the name (i.e. there are no args)
         if self.arguments.is_empty() {
             None
@@ -143,6 +155,14 @@ pub struct MergedInlineFragmentSelection {
     pub selection_map: MergedSelectionMap,
 }
 
+/// The selections of a client field that is selected with @loadable(defer: true),
+/// which are selected in the parent query under `... @defer(label: "Type__field")`.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct MergedDeferredFragmentSelection {
+    pub label: ObjectTypeAndFieldName,
+    pub selection_map: MergedSelectionMap,
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, PartialOrd, Ord, Hash)]
 pub enum NormalizationKey {
     Discriminator, // AKA typename
@@ -150,6 +170,7 @@ pub enum NormalizationKey {
     // TODO this should not have NameAndArguments, but LinkedFieldNameAndArguments
     ServerField(NameAndArguments),
     InlineFragment(IsographObjectTypeName),
+    DeferredFragment(ObjectTypeAndFieldName),
 }
 
 impl NormalizationKey {
@@ -168,6 +189,7 @@ impl NormalizationKey {
                 ),
             ),
             NormalizationKey::InlineFragment(o) => NormalizationKey::InlineFragment(*o),
+            NormalizationKey::DeferredFragment(l) => NormalizationKey::DeferredFragment(*l),
         }
     }
 }
@@ -284,6 +306,9 @@ pub fn current_target_merged_selections<'a>(
             MergedServerSelection::InlineFragment(ref inline_fragment) => {
                 parent_selection_map = &inline_fragment.selection_map;
             }
+            MergedServerSelection::DeferredFragment(ref deferred_fragment) => {
+                parent_selection_map = &deferred_fragment.selection_map;
+            }
         }
     }
     parent_selection_map
@@ -309,6 +334,7 @@ fn transform_and_merge_child_selection_map_into_parent_map(
                                 scalar_field_selection.arguments.into_iter(),
                                 parent_variable_context,
                             ),
+                            id_alias: scalar_field_selection.id_alias,
                         })
                     }
                     MergedServerSelection::LinkedField(linked_field_selection) => {
@@ -334,6 +360,15 @@ fn transform_and_merge_child_selection_map_into_parent_map(
                             ),
                         })
                     }
+                    MergedServerSelection::DeferredFragment(deferred_fragment_selection) => {
+                        MergedServerSelection::DeferredFragment(MergedDeferredFragmentSelection {
+                            label: deferred_fragment_selection.label,
+                            selection_map: transform_child_map_with_parent_context(
+                                &deferred_fragment_selection.selection_map,
+                                parent_variable_context,
+                            ),
+                        })
+                    }
                 };
                 vacant.insert(transformed);
             }
@@ -383,6 +418,22 @@ fn transform_and_merge_child_selection_map_into_parent_map(
                             )
                         }
                     }
+                    MergedServerSelection::DeferredFragment(target_deferred_fragment) => {
+                        if let MergedServerSelection::DeferredFragment(child_deferred_fragment) =
+                            new_server_field_selection
+                        {
+                            transform_and_merge_child_selection_map_into_parent_map(
+                                &mut target_deferred_fragment.selection_map,
+                                &child_deferred_fragment.selection_map,
+                                parent_variable_context,
+                            )
+                        } else {
+                            panic!(
+                                "Error: tried to merge non-deferred fragment into deferred \
+                                fragment. This is indicative of a bug in Isograph."
+                            )
+                        }
+                    }
                 }
             }
         };
@@ -593,8 +644,6 @@ pub fn imperative_field_subfields_or_inline_fragments(
 ) -> Vec<WrappedSelectionMapSelection> {
     let top_level_schema_field_arguments = top_level_schema_field_arguments
         .iter()
-        // TODO don't clone
-        .cloned()
         .map(|variable_definition| ArgumentKeyAndValue {
             key: variable_definition.name.item.unchecked_conversion(),
             value: NonConstantValue::Variable(variable_definition.name.item),
@@ -700,6 +749,7 @@ fn merge_validated_selections_into_selection_map<TNetworkProtocol: NetworkProtoc
                             }
                             ScalarSelectionDirectiveSet::None(_) => (),
                             ScalarSelectionDirectiveSet::Loadable(_) => (),
+                            ScalarSelectionDirectiveSet::Connection(_) => (),
                         };
 
                         merge_scalar_server_field(
@@ -718,8 +768,11 @@ fn merge_validated_selections_into_selection_map<TNetworkProtocol: NetworkProtoc
                             newly_encountered_scalar_client_selectable,
                             &scalar_field_selection.scalar_selection_directive_set,
                         ) {
-                            Some(Loadability::LoadablySelectedField(_loadable_variant)) => {
-                                create_merged_selection_map_for_field_and_insert_into_global_map(
+                            Some(Loadability::LoadablySelectedField(loadable_variant)) => {
+                                let FieldTraversalResult {
+                                    merged_selection_map: child_merged_selection_map,
+                                    ..
+                                } = create_merged_selection_map_for_field_and_insert_into_global_map(
                                     schema,
                                     parent_object_entity_id,
                                     parent_object,
@@ -743,6 +796,16 @@ fn merge_validated_selections_into_selection_map<TNetworkProtocol: NetworkProtoc
                                                 it is encountered loadably",
                                     );
                                 state.was_ever_selected_loadably = true;
+
+                                if loadable_variant.defer {
+                                    merge_deferred_client_field(
+                                        parent_map,
+                                        newly_encountered_scalar_client_selectable,
+                                        &child_merged_selection_map,
+                                        variable_context,
+                                        &scalar_field_selection.arguments,
+                                    );
+                                }
                             }
                             Some(Loadability::ImperativelyLoadedField(variant)) => {
                                 insert_imperative_field_into_refetch_paths(
@@ -854,6 +917,12 @@ fn merge_validated_selections_into_selection_map<TNetworkProtocol: NetworkProtoc
                                             This is indicative of a bug in Isograph."
                                         )
                                     }
+                                    MergedServerSelection::DeferredFragment(_) => {
+                                        panic!(
+                                            "Expected inline fragment, but encountered deferred \
+                                            fragment. This is indicative of a bug in Isograph."
+                                        )
+                                    }
                                     MergedServerSelection::InlineFragment(
                                         existing_inline_fragment,
                                     ) => {
@@ -964,6 +1033,12 @@ fn merge_validated_selections_into_selection_map<TNetworkProtocol: NetworkProtoc
                                             This is indicative of a bug in Isograph."
                                         )
                                     }
+                                    MergedServerSelection::DeferredFragment(_) => {
+                                        panic!(
+                                            "Expected linked field, but encountered deferred \
+                                            fragment. This is indicative of a bug in Isograph."
+                                        )
+                                    }
                                 }
                             }
                         }
@@ -1099,6 +1174,47 @@ fn merge_non_loadable_client_type<TNetworkProtocol: NetworkProtocol>(
     );
 }
 
+/// Select the selections of a client field that is selected with
+/// @loadable(defer: true) in a deferred fragment of the parent query. The field's
+/// entrypoint is still generated, so that it can be refetched.
+fn merge_deferred_client_field<TNetworkProtocol: NetworkProtocol>(
+    parent_map: &mut MergedSelectionMap,
+    deferred_client_field: &ClientScalarSelectable<TNetworkProtocol>,
+    child_merged_selection_map: &MergedSelectionMap,
+    parent_variable_context: &VariableContext,
+    selection_arguments: &[WithLocation<SelectionFieldArgument>],
+) {
+    let transformed_child_variable_context = parent_variable_context.child_variable_context(
+        selection_arguments,
+        &deferred_client_field.variable_definitions,
+        &ScalarSelectionDirectiveSet::None(EmptyDirectiveSet {}),
+    );
+    let label = deferred_client_field.type_and_field;
+    match parent_map.entry(NormalizationKey::DeferredFragment(label)) {
+        Entry::Occupied(mut occupied) => match occupied.get_mut() {
+            MergedServerSelection::DeferredFragment(deferred_fragment) => {
+                transform_and_merge_child_selection_map_into_parent_map(
+                    &mut deferred_fragment.selection_map,
+                    child_merged_selection_map,
+                    &transformed_child_variable_context,
+                );
+            }
+            _ => panic!("Expected deferred fragment, probably a bug in Isograph"),
+        },
+        Entry::Vacant(vacant_entry) => {
+            vacant_entry.insert(MergedServerSelection::DeferredFragment(
+                MergedDeferredFragmentSelection {
+                    label,
+                    selection_map: transform_child_map_with_parent_context(
+                        child_merged_selection_map,
+                        &transformed_child_variable_context,
+                    ),
+                },
+            ));
+        }
+    }
+}
+
 fn merge_scalar_server_field(
     scalar_field: &ValidatedScalarSelection,
     parent_map: &mut MergedSelectionMap,
@@ -1122,6 +1238,9 @@ fn merge_scalar_server_field(
                 MergedServerSelection::InlineFragment(_) => {
                     panic!("Unexpected inline fragment, probably a bug in Isograph");
                 }
+                MergedServerSelection::DeferredFragment(_) => {
+                    panic!("Unexpected deferred fragment, probably a bug in Isograph");
+                }
             };
         }
         Entry::Vacant(vacant_entry) => {
@@ -1135,6 +1254,7 @@ fn merge_scalar_server_field(
                             .map(|arg| arg.item.into_key_and_value()),
                         variable_context,
                     ),
+                    id_alias: None,
                 },
             ));
         }
@@ -1171,6 +1291,9 @@ fn select_typename_and_id_fields_in_merged_selection<TNetworkProtocol: NetworkPr
                     MergedServerSelection::InlineFragment(_) => {
                         panic!("Unexpected inline fragment, probably a bug in Isograph");
                     }
+                    MergedServerSelection::DeferredFragment(_) => {
+                        panic!("Unexpected deferred fragment, probably a bug in Isograph");
+                    }
                 };
             }
             Entry::Vacant(vacant_entry) => {
@@ -1184,11 +1307,23 @@ fn select_typename_and_id_fields_in_merged_selection<TNetworkProtocol: NetworkPr
                     MergedScalarFieldSelection {
                         name,
                         arguments: vec![],
+                        id_alias: if name == "id" {
+                            None
+                        } else {
+                            Some("id".intern().into())
+                        },
                     },
                 ));
             }
         }
     }
+
+    // Selection sets cannot be empty. They can be empty here if the type has no id
+    // field, and only loadable fields (which are refetched from the root, and thus
+    // select nothing in the parent query) are selected on it.
+    if merged_selection_map.is_empty() {
+        maybe_add_typename_selection(merged_selection_map);
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Ord, Eq, Hash)]
@@ -1201,6 +1336,24 @@ pub enum WrappedSelectionMapSelection {
     InlineFragment(IsographObjectTypeName),
 }
 
+impl WrappedSelectionMapSelection {
+    pub fn normalization_key(&self) -> NormalizationKey {
+        match self {
+            WrappedSelectionMapSelection::LinkedField {
+                server_object_selectable_name,
+                arguments,
+                ..
+            } => NormalizationKey::ServerField(NameAndArguments {
+                name: (*server_object_selectable_name).into(),
+                arguments: arguments.clone(),
+            }),
+            WrappedSelectionMapSelection::InlineFragment(isograph_object_type_name) => {
+                NormalizationKey::InlineFragment(*isograph_object_type_name)
+            }
+        }
+    }
+}
+
 pub fn selection_map_wrapped(
     mut inner_selection_map: MergedSelectionMap,
     // NOTE: these must be in reverse order, e.g. node { ... on Foo { etc } } would be
@@ -1212,6 +1365,7 @@ pub fn selection_map_wrapped(
     // TODO unify this with type_to_refine_to
     for subfield_or_inline_fragment in subfields_or_inline_fragments {
         let mut map = BTreeMap::new();
+        let normalization_key = subfield_or_inline_fragment.normalization_key();
         match subfield_or_inline_fragment {
             WrappedSelectionMapSelection::LinkedField {
                 server_object_selectable_name,
@@ -1219,10 +1373,7 @@ pub fn selection_map_wrapped(
                 concrete_type,
             } => {
                 map.insert(
-                    NormalizationKey::ServerField(NameAndArguments {
-                        name: server_object_selectable_name.into(),
-                        arguments: arguments.clone(),
-                    }),
+                    normalization_key,
                     MergedServerSelection::LinkedField(MergedLinkedFieldSelection {
                         name: server_object_selectable_name,
                         selection_map: inner_selection_map,
@@ -1234,7 +1385,7 @@ pub fn selection_map_wrapped(
             WrappedSelectionMapSelection::InlineFragment(isograph_object_type_name) => {
                 maybe_add_typename_selection(&mut inner_selection_map);
                 map.insert(
-                    NormalizationKey::InlineFragment(isograph_object_type_name),
+                    normalization_key,
                     MergedServerSelection::InlineFragment(MergedInlineFragmentSelection {
                         type_to_refine_to: isograph_object_type_name,
                         selection_map: inner_selection_map,
@@ -1255,6 +1406,7 @@ fn maybe_add_typename_selection(selections: &mut MergedSelectionMap) {
         MergedServerSelection::ScalarField(MergedScalarFieldSelection {
             name: (*TYPENAME_FIELD_NAME).into(),
             arguments: vec![],
+            id_alias: None,
         }),
     );
 }
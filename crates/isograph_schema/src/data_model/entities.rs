@@ -1,8 +1,8 @@
-use std::{collections::BTreeMap, marker::PhantomData};
+use std::{collections::BTreeMap, fmt, marker::PhantomData};
 
 use common_lang_types::{
-    DescriptionValue, GraphQLScalarTypeName, IsographObjectTypeName, JavascriptName,
-    SelectableName, WithLocation, WithSpan,
+    DescriptionValue, EnumLiteralValue, GraphQLScalarTypeName, IsographObjectTypeName,
+    JavascriptName, SelectableName, WithLocation, WithSpan,
 };
 use isograph_lang_types::{
     impl_with_id, DefinitionLocation, SelectionType, ServerObjectEntityId, ServerScalarEntityId,
@@ -15,12 +15,50 @@ pub struct ServerScalarEntity<TNetworkProtocol: NetworkProtocol> {
     pub description: Option<WithSpan<DescriptionValue>>,
     pub name: WithLocation<GraphQLScalarTypeName>,
     pub javascript_name: JavascriptName,
+    /// Some if the scalar is an enum, in which case these are the allowed values.
+    pub enum_values: Option<Vec<EnumLiteralValue>>,
     pub output_format: PhantomData<TNetworkProtocol>,
 }
 
 impl_with_id!(ServerScalarEntity<TNetworkProtocol: NetworkProtocol>, ServerScalarEntityId);
 
-type SelectableId = DefinitionLocation<ServerSelectableId, ClientSelectableId>;
+impl<TNetworkProtocol: NetworkProtocol> ServerScalarEntity<TNetworkProtocol> {
+    /// The type of this scalar in generated TypeScript. Enums are typed as the union
+    /// of their values, e.g. 'ADMIN' | 'MEMBER'.
+    pub fn javascript_type(&self) -> JavascriptType<'_> {
+        match &self.enum_values {
+            Some(enum_values) if !enum_values.is_empty() => {
+                JavascriptType::StringLiterals(enum_values)
+            }
+            _ => JavascriptType::Named(self.javascript_name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum JavascriptType<'a> {
+    Named(JavascriptName),
+    StringLiterals(&'a [EnumLiteralValue]),
+}
+
+impl fmt::Display for JavascriptType<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JavascriptType::Named(javascript_name) => write!(f, "{javascript_name}"),
+            JavascriptType::StringLiterals(values) => {
+                for (index, value) in values.iter().enumerate() {
+                    if index != 0 {
+                        write!(f, " | ")?;
+                    }
+                    write!(f, "'{value}'")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+pub type SelectableId = DefinitionLocation<ServerSelectableId, ClientSelectableId>;
 
 pub type ServerObjectEntityAvailableSelectables = BTreeMap<SelectableName, SelectableId>;
 
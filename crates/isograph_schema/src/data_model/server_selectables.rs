@@ -1,7 +1,8 @@
-use std::{fmt::Debug, marker::PhantomData};
+use std::{collections::BTreeMap, fmt::Debug, marker::PhantomData};
 
 use common_lang_types::{
-    DescriptionValue, ServerObjectSelectableName, ServerScalarSelectableName, WithLocation,
+    DescriptionValue, ServerObjectSelectableName, ServerScalarSelectableName, VariableName,
+    WithLocation,
 };
 use isograph_lang_types::{
     impl_with_id, impl_with_target_id, SelectionType, ServerEntityId, ServerObjectEntityId,
@@ -20,6 +21,10 @@ pub struct ServerScalarSelectable<TNetworkProtocol: NetworkProtocol> {
 
     pub parent_object_entity_id: ServerObjectEntityId,
     pub arguments: Vec<WithLocation<VariableDefinition<ServerEntityId>>>,
+    /// Some if the field is deprecated
+    pub deprecation_reason: Option<DescriptionValue>,
+    /// The reasons that deprecated arguments are deprecated
+    pub argument_deprecation_reasons: BTreeMap<VariableName, DescriptionValue>,
     pub phantom_data: PhantomData<TNetworkProtocol>,
 }
 
@@ -37,6 +42,10 @@ pub struct ServerObjectSelectable<TNetworkProtocol: NetworkProtocol> {
 
     pub parent_object_entity_id: ServerObjectEntityId,
     pub arguments: Vec<WithLocation<VariableDefinition<ServerEntityId>>>,
+    /// Some if the field is deprecated
+    pub deprecation_reason: Option<DescriptionValue>,
+    /// The reasons that deprecated arguments are deprecated
+    pub argument_deprecation_reasons: BTreeMap<VariableName, DescriptionValue>,
     pub phantom_data: PhantomData<TNetworkProtocol>,
 }
 
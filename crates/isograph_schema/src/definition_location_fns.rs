@@ -16,6 +16,18 @@ pub fn description<TNetworkProtocol: NetworkProtocol>(
     }
 }
 
+pub fn deprecation_reason<TNetworkProtocol: NetworkProtocol>(
+    definition_location: &DefinitionLocation<
+        &ServerObjectSelectable<TNetworkProtocol>,
+        &ClientObjectSelectable<TNetworkProtocol>,
+    >,
+) -> Option<DescriptionValue> {
+    match definition_location {
+        DefinitionLocation::Server(server_field) => server_field.deprecation_reason,
+        DefinitionLocation::Client(_) => None,
+    }
+}
+
 pub fn output_type_annotation<'a, TNetworkProtocol: NetworkProtocol>(
     definition_location: &'a DefinitionLocation<
         &ServerObjectSelectable<TNetworkProtocol>,
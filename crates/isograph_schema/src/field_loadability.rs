@@ -33,6 +33,10 @@ pub fn categorize_field_loadability<'a, TNetworkProtocol: NetworkProtocol>(
             ScalarSelectionDirectiveSet::Loadable(l) => {
                 Some(Loadability::LoadablySelectedField(&l.loadable))
             }
+            // Connection fields are loadable fields whose selection set is a connection
+            ScalarSelectionDirectiveSet::Connection(c) => {
+                Some(Loadability::LoadablySelectedField(&c.connection))
+            }
         },
         ClientFieldVariant::ImperativelyLoadedField(i) => {
             Some(Loadability::ImperativelyLoadedField(i))
@@ -5,12 +5,12 @@ use std::{
 
 use common_lang_types::{
     ClientScalarSelectableName, GraphQLScalarTypeName, IsoLiteralText, IsographObjectTypeName,
-    JavascriptName, Location, ObjectSelectableName, SelectableName, UnvalidatedTypeName,
-    WithLocation,
+    JavascriptName, Location, ObjectSelectableName, SelectableName, ServerScalarSelectableName,
+    UnvalidatedTypeName, WithLocation,
 };
 use graphql_lang_types::{GraphQLConstantValue, GraphQLDirective, GraphQLNamedTypeAnnotation};
-use intern::string_key::Intern;
-use isograph_config::CompilerConfigOptions;
+use intern::{string_key::Intern, Lookup};
+use isograph_config::{CompilerConfigOptions, RefetchFieldOptions};
 use isograph_lang_types::{
     ArgumentKeyAndValue, ClientFieldDirectiveSet, ClientObjectSelectableId,
     ClientScalarSelectableId, DefinitionLocation, EmptyDirectiveSet, ObjectSelection,
@@ -23,10 +23,10 @@ use lazy_static::lazy_static;
 use crate::{
     create_additional_fields::{CreateAdditionalFieldsError, CreateAdditionalFieldsResult},
     ClientFieldVariant, ClientObjectSelectable, ClientScalarSelectable, ClientSelectableId,
-    NetworkProtocol, NormalizationKey, ObjectSelectable, ObjectSelectableId, ServerEntity,
-    ServerObjectEntity, ServerObjectEntityAvailableSelectables, ServerObjectSelectable,
-    ServerScalarEntity, ServerScalarSelectable, ServerSelectable, ServerSelectableId,
-    UseRefetchFieldRefetchStrategy,
+    NetworkProtocol, NormalizationKey, ObjectSelectable, ObjectSelectableId,
+    RefetchFromRootRefetchStrategy, ServerEntity, ServerObjectEntity,
+    ServerObjectEntityAvailableSelectables, ServerObjectSelectable, ServerScalarEntity,
+    ServerScalarSelectable, ServerSelectable, ServerSelectableId, UseRefetchFieldRefetchStrategy,
 };
 
 lazy_static! {
@@ -49,6 +49,15 @@ pub struct Schema<TNetworkProtocol: NetworkProtocol> {
 
     /// These are root types like Query, Mutation, Subscription
     pub fetchable_types: BTreeMap<ServerObjectEntityId, RootOperationName>,
+
+    /// Objects that are refetched by re-running a query from a root type, instead of
+    /// via Query.node. These are declared with @refetchFromRoot directives or the
+    /// refetch_from_root config option.
+    pub refetch_root_paths: BTreeMap<ServerObjectEntityId, RefetchFromRootRefetchStrategy>,
+
+    /// The field on Query through which objects with an id are refetched, e.g.
+    /// node(id: $id). This can be configured with the refetch_field config option.
+    pub refetch_field: RefetchFieldOptions,
 }
 
 impl<TNetworkProtocol: NetworkProtocol> Default for Schema<TNetworkProtocol> {
@@ -124,6 +133,8 @@ impl<TNetworkProtocol: NetworkProtocol> Schema<TNetworkProtocol> {
                 null_type_id,
             },
             fetchable_types: BTreeMap::new(),
+            refetch_root_paths: BTreeMap::new(),
+            refetch_field: RefetchFieldOptions::default(),
         }
     }
 
@@ -282,7 +293,7 @@ impl<TNetworkProtocol: NetworkProtocol> Schema<TNetworkProtocol> {
     pub fn server_selectable(
         &self,
         server_selectable_id: ServerSelectableId,
-    ) -> ServerSelectable<TNetworkProtocol> {
+    ) -> ServerSelectable<'_, TNetworkProtocol> {
         match server_selectable_id {
             SelectionType::Scalar(server_scalar_selectable_id) => {
                 SelectionType::Scalar(self.server_scalar_selectable(server_scalar_selectable_id))
@@ -332,10 +343,16 @@ impl<TNetworkProtocol: NetworkProtocol> Schema<TNetworkProtocol> {
         }
 
         // TODO do not do this here, this is a GraphQL-ism
-        if server_scalar_selectable.name.item == "id" {
+        let id_field_name = options
+            .id_fields
+            .get(&parent_type_name)
+            .map(|id_field_name| id_field_name.lookup())
+            .unwrap_or("id");
+        if server_scalar_selectable.name.item == id_field_name {
             set_and_validate_id_field(
                 id_field,
                 next_server_scalar_selectable_id,
+                server_scalar_selectable.name.item,
                 parent_type_name,
                 options,
                 inner_non_null_named_type,
@@ -410,7 +427,7 @@ impl<TNetworkProtocol: NetworkProtocol> Schema<TNetworkProtocol> {
     pub fn object_selectable(
         &self,
         field_id: ObjectSelectableId,
-    ) -> ObjectSelectable<TNetworkProtocol> {
+    ) -> ObjectSelectable<'_, TNetworkProtocol> {
         match field_id {
             DefinitionLocation::Server(server_field_id) => {
                 DefinitionLocation::Server(self.server_object_selectable(server_field_id))
@@ -518,7 +535,7 @@ impl<TNetworkProtocol: NetworkProtocol> ServerEntityData<TNetworkProtocol> {
             .map(|(id, scalar)| WithId::new(id.into(), scalar))
     }
 
-    pub fn server_entity(&self, type_id: ServerEntityId) -> ServerEntity<TNetworkProtocol> {
+    pub fn server_entity(&self, type_id: ServerEntityId) -> ServerEntity<'_, TNetworkProtocol> {
         match type_id {
             ServerEntityId::Object(object_entity_id) => {
                 ServerEntity::Object(self.server_object_entity(object_entity_id))
@@ -646,6 +663,7 @@ fn add_schema_defined_scalar_type<TNetworkProtocol: NetworkProtocol>(
         description: None,
         name: typename,
         javascript_name,
+        enum_values: None,
         output_format: std::marker::PhantomData,
     });
     defined_types.insert(
@@ -683,6 +701,7 @@ pub type ScalarSelectableId =
 fn set_and_validate_id_field(
     id_field: &mut Option<ServerStrongIdFieldId>,
     current_field_id: ServerScalarSelectableId,
+    id_field_name: ServerScalarSelectableName,
     parent_type_name: IsographObjectTypeName,
     options: &CompilerConfigOptions,
     inner_non_null_named_type: Option<&GraphQLNamedTypeAnnotation<UnvalidatedTypeName>>,
@@ -700,7 +719,7 @@ fn set_and_validate_id_field(
             if type_.0.item != *ID_GRAPHQL_TYPE {
                 options.on_invalid_id_type.on_failure(|| {
                     CreateAdditionalFieldsError::IdFieldMustBeNonNullIdType {
-                        strong_field_name: "id",
+                        strong_field_name: id_field_name,
                         parent_type: parent_type_name,
                     }
                 })?;
@@ -710,7 +729,7 @@ fn set_and_validate_id_field(
         None => {
             options.on_invalid_id_type.on_failure(|| {
                 CreateAdditionalFieldsError::IdFieldMustBeNonNullIdType {
-                    strong_field_name: "id",
+                    strong_field_name: id_field_name,
                     parent_type: parent_type_name,
                 }
             })?;
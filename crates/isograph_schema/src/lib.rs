@@ -11,8 +11,10 @@ mod process_client_field_declaration;
 mod refetch_strategy;
 mod root_types;
 mod validate_argument_types;
+mod validate_connection_selections;
 mod validate_entrypoint;
 mod validate_use_of_arguments;
+mod validate_use_of_deprecated_fields;
 mod variable_context;
 mod visit_selection_set;
 
@@ -28,6 +30,9 @@ pub use object_type_definition::*;
 pub use process_client_field_declaration::*;
 pub use refetch_strategy::*;
 pub use root_types::*;
+pub use validate_connection_selections::*;
 pub use validate_entrypoint::*;
 pub use validate_use_of_arguments::*;
+pub use validate_use_of_deprecated_fields::*;
 pub use variable_context::*;
+pub use visit_selection_set::*;
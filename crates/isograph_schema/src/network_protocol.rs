@@ -12,6 +12,7 @@ use common_lang_types::{
 use graphql_lang_types::{
     GraphQLConstantValue, GraphQLDirective, GraphQLFieldDefinition, RootOperationKind,
 };
+use isograph_config::QueryTextFragmentsOption;
 use isograph_lang_types::SchemaSource;
 use pico::{Database, SourceId};
 
@@ -21,7 +22,7 @@ use crate::{
 };
 
 pub trait NetworkProtocol:
-    Debug + Clone + Copy + Eq + PartialEq + Ord + PartialOrd + Hash + Default
+    Debug + Clone + Copy + Eq + PartialEq + Ord + PartialOrd + Hash + Default + 'static
 where
     Self: Sized,
 {
@@ -30,7 +31,7 @@ where
     type TypeSystemDocument: Debug + Clone + 'static;
     type TypeSystemExtensionDocument: Debug + Clone + 'static;
 
-    type SchemaObjectAssociatedData: Debug;
+    type SchemaObjectAssociatedData: Debug + 'static;
 
     #[allow(clippy::type_complexity)]
     fn parse_and_process_type_system_documents(
@@ -45,6 +46,7 @@ where
         selection_map: &MergedSelectionMap,
         query_variables: impl Iterator<Item = &'a ValidatedVariableDefinition> + 'a,
         root_operation_name: &RootOperationName,
+        query_text_fragments: QueryTextFragmentsOption,
     ) -> QueryText;
 }
 
@@ -1,17 +1,17 @@
 use std::collections::HashMap;
 
 use common_lang_types::{
-    ClientScalarSelectableName, ConstExportName, IsographDirectiveName, IsographObjectTypeName,
-    Location, ObjectTypeAndFieldName, RelativePathToSourceFile, SelectableName,
-    ServerObjectSelectableName, TextSource, UnvalidatedTypeName, VariableName, WithLocation,
-    WithSpan,
+    closest_match, did_you_mean, ClientScalarSelectableName, ConstExportName, DiagnosticCode,
+    HasDiagnosticCode, IsographDirectiveName, IsographObjectTypeName, Location,
+    ObjectTypeAndFieldName, RelativePathToSourceFile, SelectableName, ServerObjectSelectableName,
+    TextSource, UnvalidatedTypeName, VariableName, WithLocation, WithSpan,
 };
-use intern::string_key::Intern;
+use intern::Lookup;
 use isograph_lang_types::{
-    ArgumentKeyAndValue, ClientFieldDeclaration, ClientFieldDirectiveSet, ClientObjectSelectableId,
+    ClientFieldDeclaration, ClientFieldDirectiveSet, ClientObjectSelectableId,
     ClientPointerDeclaration, ClientScalarSelectableId, DefinitionLocation, DeserializationError,
-    NonConstantValue, SelectionType, ServerEntityId, ServerObjectEntityId, TypeAnnotation,
-    UnvalidatedSelection, VariableDefinition,
+    SelectionType, ServerEntityId, ServerObjectEntityId, TypeAnnotation, UnvalidatedSelection,
+    VariableDefinition,
 };
 
 use thiserror::Error;
@@ -19,7 +19,7 @@ use thiserror::Error;
 use crate::{
     refetch_strategy::{generate_refetch_field_strategy, id_selection, RefetchStrategy},
     ClientObjectSelectable, ClientScalarSelectable, FieldMapItem, NetworkProtocol, Schema,
-    WrappedSelectionMapSelection, NODE_FIELD_NAME,
+    WrappedSelectionMapSelection,
 };
 
 pub type UnprocessedSelection = WithSpan<UnvalidatedSelection>;
@@ -46,12 +46,17 @@ impl<TNetworkProtocol: NetworkProtocol> Schema<TNetworkProtocol> {
             .server_entity_data
             .defined_entities
             .get(&client_field_declaration.item.parent_type.item)
-            .ok_or(WithLocation::new(
-                ProcessClientFieldDeclarationError::ParentTypeNotDefined {
-                    parent_type_name: client_field_declaration.item.parent_type.item,
-                },
-                Location::new(text_source, client_field_declaration.item.parent_type.span),
-            ))?;
+            .ok_or_else(|| {
+                WithLocation::new(
+                    ProcessClientFieldDeclarationError::ParentTypeNotDefined {
+                        parent_type_name: client_field_declaration.item.parent_type.item,
+                        suggestion: self.closest_object_type_name(
+                            client_field_declaration.item.parent_type.item,
+                        ),
+                    },
+                    Location::new(text_source, client_field_declaration.item.parent_type.span),
+                )
+            })?;
 
         let unprocess_client_field_items = match parent_type_id {
             ServerEntityId::Object(object_entity_id) => self
@@ -85,29 +90,39 @@ impl<TNetworkProtocol: NetworkProtocol> Schema<TNetworkProtocol> {
             .server_entity_data
             .defined_entities
             .get(&client_pointer_declaration.item.parent_type.item)
-            .ok_or(WithLocation::new(
-                ProcessClientFieldDeclarationError::ParentTypeNotDefined {
-                    parent_type_name: client_pointer_declaration.item.parent_type.item,
-                },
-                Location::new(
-                    text_source,
-                    client_pointer_declaration.item.parent_type.span,
-                ),
-            ))?;
+            .ok_or_else(|| {
+                WithLocation::new(
+                    ProcessClientFieldDeclarationError::ParentTypeNotDefined {
+                        parent_type_name: client_pointer_declaration.item.parent_type.item,
+                        suggestion: self.closest_object_type_name(
+                            client_pointer_declaration.item.parent_type.item,
+                        ),
+                    },
+                    Location::new(
+                        text_source,
+                        client_pointer_declaration.item.parent_type.span,
+                    ),
+                )
+            })?;
 
         let target_type_id = self
             .server_entity_data
             .defined_entities
             .get(client_pointer_declaration.item.target_type.inner())
-            .ok_or(WithLocation::new(
-                ProcessClientFieldDeclarationError::ParentTypeNotDefined {
-                    parent_type_name: *client_pointer_declaration.item.target_type.inner(),
-                },
-                Location::new(
-                    text_source,
-                    *client_pointer_declaration.item.target_type.span(),
-                ),
-            ))?;
+            .ok_or_else(|| {
+                WithLocation::new(
+                    ProcessClientFieldDeclarationError::ParentTypeNotDefined {
+                        parent_type_name: *client_pointer_declaration.item.target_type.inner(),
+                        suggestion: self.closest_object_type_name(
+                            *client_pointer_declaration.item.target_type.inner(),
+                        ),
+                    },
+                    Location::new(
+                        text_source,
+                        *client_pointer_declaration.item.target_type.span(),
+                    ),
+                )
+            })?;
 
         let unprocessed_client_pointer_items = match parent_type_id {
             ServerEntityId::Object(object_entity_id) => match target_type_id {
@@ -160,6 +175,21 @@ impl<TNetworkProtocol: NetworkProtocol> Schema<TNetworkProtocol> {
         Ok(unprocessed_client_pointer_items)
     }
 
+    /// The name of the object type that is closest to `type_name`, if any, for
+    /// suggesting a fix when `type_name` is not defined.
+    pub(crate) fn closest_object_type_name(
+        &self,
+        type_name: UnvalidatedTypeName,
+    ) -> Option<IsographObjectTypeName> {
+        closest_match(
+            type_name.lookup(),
+            self.server_entity_data
+                .server_objects
+                .iter()
+                .map(|object| object.name),
+        )
+    }
+
     fn add_client_field_to_object(
         &mut self,
         parent_object_entity_id: ServerObjectEntityId,
@@ -237,21 +267,24 @@ impl<TNetworkProtocol: NetworkProtocol> Schema<TNetworkProtocol> {
                 server_object_entity_available_selectables",
             )
             .1;
-        let refetch_strategy = id_field.map(|_| {
-            // Assume that if we have an id field, this implements Node
-            RefetchStrategy::UseRefetchField(generate_refetch_field_strategy(
-                vec![id_selection()],
-                query_id,
-                vec![
-                    WrappedSelectionMapSelection::InlineFragment(object.name),
-                    WrappedSelectionMapSelection::LinkedField {
-                        server_object_selectable_name: *NODE_FIELD_NAME,
-                        arguments: id_top_level_arguments(),
-                        concrete_type: None,
-                    },
-                ],
-            ))
-        });
+        let object_name = object.name;
+        let refetch_root_path = self.refetch_root_paths.get(&parent_object_entity_id);
+        let refetch_strategy = match refetch_root_path {
+            Some(refetch_root_path) => {
+                Some(RefetchStrategy::RefetchFromRoot(refetch_root_path.clone()))
+            }
+            None => id_field.map(|id_field| {
+                // Assume that if we have an id field, this can be refetched via the
+                // refetch field (i.e. it implements Node, by default)
+                RefetchStrategy::UseRefetchField(generate_refetch_field_strategy(
+                    vec![id_selection(
+                        self.server_scalar_selectable(id_field.into()).name.item,
+                    )],
+                    query_id,
+                    self.refetch_field_subfields(object_name),
+                ))
+            }),
+        };
 
         Ok(UnprocessedClientFieldItem {
             client_field_id: next_client_field_id,
@@ -306,28 +339,23 @@ impl<TNetworkProtocol: NetworkProtocol> Schema<TNetworkProtocol> {
                 to exist in server_object_entity_available_selectables",
             )
             .1;
-        let refetch_strategy = match id_field {
+        let (refetch_strategy, id_field_name) = match id_field {
             None => Err(WithSpan::new(
                 ProcessClientFieldDeclarationError::ClientPointerTargetTypeHasNoId {
                     target_type_name: *client_pointer_declaration.item.target_type.inner(),
                 },
                 *client_pointer_declaration.item.target_type.span(),
             )),
-            Some(_) => {
-                // Assume that if we have an id field, this implements Node
-                Ok(RefetchStrategy::UseRefetchField(
-                    generate_refetch_field_strategy(
+            Some(id_field) => {
+                // Assume that if we have an id field, this can be refetched via the
+                // refetch field (i.e. it implements Node, by default)
+                Ok((
+                    RefetchStrategy::UseRefetchField(generate_refetch_field_strategy(
                         vec![],
                         query_id,
-                        vec![
-                            WrappedSelectionMapSelection::InlineFragment(to_object.name),
-                            WrappedSelectionMapSelection::LinkedField {
-                                server_object_selectable_name: *NODE_FIELD_NAME,
-                                arguments: id_top_level_arguments(),
-                                concrete_type: None,
-                            },
-                        ],
-                    ),
+                        self.refetch_field_subfields(to_object.name),
+                    )),
+                    self.server_scalar_selectable(id_field.into()).name.item,
                 ))
             }
         }?;
@@ -394,7 +422,7 @@ impl<TNetworkProtocol: NetworkProtocol> Schema<TNetworkProtocol> {
         Ok(UnprocessedClientPointerItem {
             client_pointer_id: next_client_pointer_id,
             reader_selection_set: unprocessed_fields,
-            refetch_selection_set: vec![id_selection()],
+            refetch_selection_set: vec![id_selection(id_field_name)],
         })
     }
 }
@@ -404,9 +432,13 @@ type ProcessClientFieldDeclarationResult<T> =
 
 #[derive(Error, Eq, PartialEq, Debug)]
 pub enum ProcessClientFieldDeclarationError {
-    #[error("`{parent_type_name}` is not a type that has been defined.")]
+    #[error(
+        "`{parent_type_name}` is not a type that has been defined.{}",
+        did_you_mean(*suggestion)
+    )]
     ParentTypeNotDefined {
         parent_type_name: UnvalidatedTypeName,
+        suggestion: Option<IsographObjectTypeName>,
     },
 
     #[error("Directive {directive_name} is not supported on client pointers.")]
@@ -455,6 +487,40 @@ pub enum ProcessClientFieldDeclarationError {
     },
 }
 
+impl HasDiagnosticCode for ProcessClientFieldDeclarationError {
+    fn diagnostic_code(&self) -> DiagnosticCode {
+        match self {
+            ProcessClientFieldDeclarationError::ParentTypeNotDefined { .. } => DiagnosticCode(201),
+            ProcessClientFieldDeclarationError::DirectiveNotSupportedOnClientPointer { .. } => {
+                DiagnosticCode(202)
+            }
+            ProcessClientFieldDeclarationError::InvalidParentType { .. } => DiagnosticCode(203),
+            ProcessClientFieldDeclarationError::ClientPointerInvalidTargetType { .. } => {
+                DiagnosticCode(204)
+            }
+            ProcessClientFieldDeclarationError::ClientPointerTargetTypeHasNoId { .. } => {
+                DiagnosticCode(205)
+            }
+            ProcessClientFieldDeclarationError::ParentAlreadyHasField { .. } => DiagnosticCode(206),
+            ProcessClientFieldDeclarationError::UnableToDeserializeDirectives { .. } => {
+                DiagnosticCode(207)
+            }
+            ProcessClientFieldDeclarationError::FieldArgumentTypeDoesNotExist { .. } => {
+                DiagnosticCode(208)
+            }
+        }
+    }
+
+    fn suggestion(&self) -> Option<String> {
+        match self {
+            ProcessClientFieldDeclarationError::ParentTypeNotDefined { suggestion, .. } => {
+                suggestion.map(|suggestion| suggestion.to_string())
+            }
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct PrimaryFieldInfo {
     pub primary_field_name: ServerObjectSelectableName,
@@ -518,13 +584,6 @@ fn get_client_variant(client_field_declaration: &ClientFieldDeclaration) -> Clie
     })
 }
 
-pub fn id_top_level_arguments() -> Vec<ArgumentKeyAndValue> {
-    vec![ArgumentKeyAndValue {
-        key: "id".intern().into(),
-        value: NonConstantValue::Variable("id".intern().into()),
-    }]
-}
-
 pub fn validate_variable_definition(
     defined_types: &HashMap<UnvalidatedTypeName, ServerEntityId>,
     variable_definition: WithSpan<VariableDefinition<UnvalidatedTypeName>>,
@@ -1,15 +1,19 @@
 use std::{collections::BTreeSet, fmt::Debug};
 
-use common_lang_types::{Location, Span, VariableName, WithLocation, WithSpan};
-use intern::string_key::Intern;
+use common_lang_types::{
+    IsographObjectTypeName, Location, ServerScalarSelectableName, Span, VariableName, WithLocation,
+    WithSpan,
+};
+use intern::{string_key::Intern, Lookup};
+use isograph_config::RefetchFieldArgumentValue;
 use isograph_lang_types::{
-    EmptyDirectiveSet, ScalarSelection, ScalarSelectionDirectiveSet,
-    SelectionTypeContainingSelections, ServerObjectEntityId,
+    ArgumentKeyAndValue, EmptyDirectiveSet, NonConstantValue, ScalarSelection,
+    ScalarSelectionDirectiveSet, SelectionTypeContainingSelections, ServerObjectEntityId,
 };
 
 use crate::{
-    get_reachable_variables, selection_map_wrapped, MergedSelectionMap, UnprocessedSelection,
-    WrappedSelectionMapSelection,
+    get_reachable_variables, selection_map_wrapped, MergedSelectionMap, NetworkProtocol, Schema,
+    UnprocessedSelection, WrappedSelectionMapSelection,
 };
 
 #[derive(Debug)]
@@ -23,7 +27,7 @@ pub enum RefetchStrategy<
             TSelectionTypeSelectionLinkedFieldAssociatedData,
         >,
     ),
-    // RefetchFromRoot
+    RefetchFromRoot(RefetchFromRootRefetchStrategy),
 }
 
 impl<
@@ -37,18 +41,19 @@ impl<
 {
     pub fn refetch_selection_set(
         &self,
-    ) -> &Vec<
-        WithSpan<
-            SelectionTypeContainingSelections<
-                TSelectionTypeSelectionScalarFieldAssociatedData,
-                TSelectionTypeSelectionLinkedFieldAssociatedData,
-            >,
+    ) -> &[WithSpan<
+        SelectionTypeContainingSelections<
+            TSelectionTypeSelectionScalarFieldAssociatedData,
+            TSelectionTypeSelectionLinkedFieldAssociatedData,
         >,
-    > {
+    >] {
         match self {
             RefetchStrategy::UseRefetchField(used_refetch_field) => {
                 &used_refetch_field.refetch_selection_set
             }
+            // The object is found by following the path from the root, so nothing
+            // needs to be read out of it.
+            RefetchStrategy::RefetchFromRoot(_) => &[],
         }
     }
 }
@@ -104,6 +109,20 @@ pub struct UseRefetchFieldRefetchStrategy<
     pub generate_refetch_query: Box<dyn GenerateRefetchQueryFn>,
 }
 
+/// Refetch an object that is not reachable via `node` (e.g. because it has no id)
+/// by re-running a query from a root type, following a path of fields that
+/// is declared with the `@refetchFromRoot` directive or the `refetch_from_root`
+/// config option. For example, `Viewer` might be refetched via `Query.viewer`.
+#[derive(Debug, Clone)]
+pub struct RefetchFromRootRefetchStrategy {
+    /// Query, Mutation, etc.
+    pub root_fetchable_type: ServerObjectEntityId,
+    /// The linked fields from the root type to the refetched object, in reverse
+    /// order (as expected by selection_map_wrapped), e.g. viewer.settings would be
+    /// [settings_field_selection, viewer_field_selection].
+    pub path: Vec<WrappedSelectionMapSelection>,
+}
+
 pub trait GenerateRefetchQueryFn: Debug {
     fn generate_refetch_query(
         &self,
@@ -130,11 +149,65 @@ impl GenerateRefetchQueryFn for GenerateRefetchQueryImpl {
     }
 }
 
-pub fn id_selection() -> UnprocessedSelection {
+impl<TNetworkProtocol: NetworkProtocol> Schema<TNetworkProtocol> {
+    /// The selections through which an object of the given type is refetched, innermost
+    /// first, i.e. an inline fragment on the type, wrapped in the configured refetch field.
+    /// By default, this is node(id: $id) { ... on Pet { ... } }.
+    pub fn refetch_field_subfields(
+        &self,
+        type_name: IsographObjectTypeName,
+    ) -> Vec<WrappedSelectionMapSelection> {
+        vec![
+            WrappedSelectionMapSelection::InlineFragment(type_name),
+            self.refetch_field_selection(type_name),
+        ]
+    }
+
+    /// The configured refetch field (by default, node(id: $id)), with its arguments
+    /// filled in for an object of the given type.
+    pub fn refetch_field_selection(
+        &self,
+        type_name: IsographObjectTypeName,
+    ) -> WrappedSelectionMapSelection {
+        WrappedSelectionMapSelection::LinkedField {
+            server_object_selectable_name: self.refetch_field.name,
+            arguments: self
+                .refetch_field
+                .arguments
+                .iter()
+                .map(|(argument_name, value)| ArgumentKeyAndValue {
+                    key: *argument_name,
+                    value: match value {
+                        // The id is always passed as $id, regardless of the name of the
+                        // argument, since this is the variable that the runtime sets.
+                        RefetchFieldArgumentValue::Id => {
+                            NonConstantValue::Variable("id".intern().into())
+                        }
+                        RefetchFieldArgumentValue::Typename => {
+                            NonConstantValue::String(type_name.lookup().intern().into())
+                        }
+                    },
+                })
+                .collect(),
+            concrete_type: None,
+        }
+    }
+}
+
+/// Select the id field of an object, so that its id can be passed to the refetch field.
+/// Id fields that are not named id (see the id_fields config option) are aliased to id.
+pub fn id_selection(id_field_name: ServerScalarSelectableName) -> UnprocessedSelection {
     WithSpan::new(
         SelectionTypeContainingSelections::Scalar(ScalarSelection {
-            name: WithLocation::new("id".intern().into(), Location::generated()),
-            reader_alias: None,
+            name: WithLocation::new(id_field_name.into(), Location::generated()),
+            reader_alias: if id_field_name == "id" {
+                None
+            } else {
+                Some(WithLocation::new(
+                    "id".intern().into(),
+                    Location::generated(),
+                ))
+            },
             scalar_selection_directive_set: ScalarSelectionDirectiveSet::None(EmptyDirectiveSet {}),
             associated_data: (),
             arguments: vec![],
@@ -1,6 +1,6 @@
 use common_lang_types::{
-    EnumLiteralValue, GraphQLScalarTypeName, Location, SelectableName, UnvalidatedTypeName,
-    ValueKeyName, VariableName, WithLocation, WithSpan,
+    DiagnosticCode, EnumLiteralValue, GraphQLScalarTypeName, HasDiagnosticCode, Location,
+    SelectableName, UnvalidatedTypeName, ValueKeyName, VariableName, WithLocation, WithSpan,
 };
 use graphql_lang_types::{
     GraphQLListTypeAnnotation, GraphQLNamedTypeAnnotation, GraphQLNonNullTypeAnnotation,
@@ -496,8 +496,33 @@ fn enum_satisfies_type<TNetworkProtocol: NetworkProtocol>(
                 location,
             ))
         }
-        SelectionType::Scalar(_scalar_entity_id) => {
-            todo!("Validate enum literal. Parser doesn't support enum literals yet")
+        SelectionType::Scalar(scalar_entity_id) => {
+            let scalar_entity = schema_data.server_scalar_entity(scalar_entity_id);
+            match &scalar_entity.enum_values {
+                Some(enum_values) => {
+                    if enum_values.contains(enum_literal_value) {
+                        Ok(())
+                    } else {
+                        Err(WithLocation::new(
+                            ValidateArgumentTypesError::InvalidEnumValue {
+                                enum_type: scalar_entity.name.item,
+                                actual: *enum_literal_value,
+                                allowed_values: enum_values.clone(),
+                            },
+                            location,
+                        ))
+                    }
+                }
+                None => Err(WithLocation::new(
+                    ValidateArgumentTypesError::ExpectedTypeFoundEnum {
+                        expected: GraphQLTypeAnnotation::Named(GraphQLNamedTypeAnnotation(
+                            enum_type.clone().map(|_| scalar_entity.name.item.into()),
+                        )),
+                        actual: *enum_literal_value,
+                    },
+                    location,
+                )),
+            }
         }
     }
 }
@@ -579,6 +604,16 @@ pub enum ValidateArgumentTypesError {
         actual: EnumLiteralValue,
     },
 
+    #[error(
+        "{actual} is not a valid value of the enum {enum_type}. Valid values are: {0}",
+        allowed_values.iter().map(|value| value.to_string()).collect::<Vec<_>>().join(", ")
+    )]
+    InvalidEnumValue {
+        enum_type: GraphQLScalarTypeName,
+        actual: EnumLiteralValue,
+        allowed_values: Vec<EnumLiteralValue>,
+    },
+
     #[error("This variable is not defined: ${undefined_variable}")]
     UsedUndefinedVariable { undefined_variable: VariableName },
 
@@ -598,3 +633,20 @@ pub enum ValidateArgumentTypesError {
         extra_fields: Vec<NameValuePair<ValueKeyName, NonConstantValue>>,
     },
 }
+
+impl HasDiagnosticCode for ValidateArgumentTypesError {
+    fn diagnostic_code(&self) -> DiagnosticCode {
+        match self {
+            ValidateArgumentTypesError::ExpectedTypeFoundVariable { .. } => DiagnosticCode(601),
+            ValidateArgumentTypesError::ExpectedTypeFoundScalar { .. } => DiagnosticCode(602),
+            ValidateArgumentTypesError::ExpectedTypeFoundObject { .. } => DiagnosticCode(603),
+            ValidateArgumentTypesError::ExpectedTypeFoundList { .. } => DiagnosticCode(604),
+            ValidateArgumentTypesError::ExpectedNonNullTypeFoundNull { .. } => DiagnosticCode(605),
+            ValidateArgumentTypesError::ExpectedTypeFoundEnum { .. } => DiagnosticCode(606),
+            ValidateArgumentTypesError::InvalidEnumValue { .. } => DiagnosticCode(607),
+            ValidateArgumentTypesError::UsedUndefinedVariable { .. } => DiagnosticCode(608),
+            ValidateArgumentTypesError::MissingFields { .. } => DiagnosticCode(609),
+            ValidateArgumentTypesError::ExtraneousFields { .. } => DiagnosticCode(610),
+        }
+    }
+}
@@ -0,0 +1,321 @@
+use std::collections::{BTreeMap, HashSet};
+
+use common_lang_types::{
+    DiagnosticCode, FieldArgumentName, HasDiagnosticCode, Location, ObjectTypeAndFieldName,
+    SelectableName, ServerObjectSelectableName, WithLocation, WithSpan,
+};
+use intern::string_key::Intern;
+use isograph_lang_types::{
+    ClientScalarSelectableId, DefinitionLocation, NonConstantValue, ScalarSelectionDirectiveSet,
+    SelectionType,
+};
+use thiserror::Error;
+
+use crate::{
+    visit_selection_set::visit_selection_set, ClientFieldVariant, ClientScalarSelectable,
+    ClientSelectableId, NetworkProtocol, Schema, ValidatedObjectSelection, ValidatedSelection,
+};
+
+// These are the field and argument names used by the GraphQL Cursor Connections
+// specification.
+const CONNECTION_EDGES_FIELD_NAME: &str = "edges";
+const CONNECTION_NODE_FIELD_NAME: &str = "node";
+const CONNECTION_PAGE_INFO_FIELD_NAME: &str = "pageInfo";
+const CONNECTION_END_CURSOR_FIELD_NAME: &str = "endCursor";
+const CONNECTION_HAS_NEXT_PAGE_FIELD_NAME: &str = "hasNextPage";
+const CONNECTION_CURSOR_ARGUMENT_NAME: &str = "after";
+const CONNECTION_COUNT_ARGUMENT_NAME: &str = "first";
+
+/// Validate that every client field that is selected with @connection selects exactly
+/// one connection field, i.e. a server field whose type has edges and pageInfo fields,
+/// that the after and first arguments of that field are passed variables, and that
+/// pageInfo { endCursor hasNextPage } and edges { node } are selected without aliases,
+/// since useConnectionSpecPagination reads them by name.
+///
+/// Client fields whose selection sets are invalid are skipped.
+pub fn validate_connection_selections<TNetworkProtocol: NetworkProtocol>(
+    schema: &Schema<TNetworkProtocol>,
+    client_selectables_with_invalid_selection_sets: &HashSet<ClientSelectableId>,
+) -> Result<(), Vec<WithLocation<ValidateConnectionSelectionsError>>> {
+    // Each client field is validated once, and errors that do not have a more specific
+    // location are reported at the first selection of it with @connection.
+    let mut connection_selections = BTreeMap::<ClientScalarSelectableId, Location>::new();
+    let reader_selection_sets = schema
+        .client_scalar_selectables
+        .iter()
+        .map(|client_field| &client_field.reader_selection_set)
+        .chain(
+            schema
+                .client_object_selectables
+                .iter()
+                .map(|client_pointer| &client_pointer.reader_selection_set),
+        );
+    for reader_selection_set in reader_selection_sets {
+        visit_selection_set(reader_selection_set, &mut |selection| {
+            if let SelectionType::Scalar(scalar_selection) = selection {
+                if let (
+                    ScalarSelectionDirectiveSet::Connection(_),
+                    DefinitionLocation::Client(client_field_id),
+                ) = (
+                    scalar_selection.scalar_selection_directive_set,
+                    scalar_selection.associated_data,
+                ) {
+                    connection_selections
+                        .entry(client_field_id)
+                        .or_insert(scalar_selection.name.location);
+                }
+            }
+        });
+    }
+
+    let mut errors = vec![];
+    for (client_field_id, selection_location) in connection_selections {
+        if client_selectables_with_invalid_selection_sets
+            .contains(&SelectionType::Scalar(client_field_id))
+        {
+            continue;
+        }
+        if let Err((error, location)) =
+            validate_connection_field(schema, schema.client_field(client_field_id))
+        {
+            errors.push(WithLocation::new(
+                error,
+                location.unwrap_or(selection_location),
+            ));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Validate the connection field selected by a client field that is selected with
+/// @connection. If there is no more specific location for an error, its location
+/// is None.
+fn validate_connection_field<TNetworkProtocol: NetworkProtocol>(
+    schema: &Schema<TNetworkProtocol>,
+    client_field: &ClientScalarSelectable<TNetworkProtocol>,
+) -> Result<(), (ValidateConnectionSelectionsError, Option<Location>)> {
+    let client_field_name = client_field.type_and_field;
+    if !matches!(client_field.variant, ClientFieldVariant::UserWritten(_)) {
+        return Err((
+            ValidateConnectionSelectionsError::ConnectionFieldIsNotUserWritten {
+                client_field_name,
+            },
+            None,
+        ));
+    }
+
+    let mut connection_selections =
+        client_field
+            .reader_selection_set
+            .iter()
+            .filter_map(|selection| match &selection.item {
+                SelectionType::Object(object_selection) => {
+                    is_connection_selection(schema, object_selection).then_some(object_selection)
+                }
+                SelectionType::Scalar(_) => None,
+            });
+
+    let connection_selection = connection_selections.next().ok_or((
+        ValidateConnectionSelectionsError::NoConnectionFieldSelected { client_field_name },
+        None,
+    ))?;
+    if let Some(other_connection_selection) = connection_selections.next() {
+        return Err((
+            ValidateConnectionSelectionsError::MultipleConnectionFieldsSelected {
+                client_field_name,
+                first_field_name: connection_selection.name.item,
+                second_field_name: other_connection_selection.name.item,
+            },
+            Some(other_connection_selection.name.location),
+        ));
+    }
+
+    let connection_field_name = connection_selection.name.item;
+    for argument_name in [
+        CONNECTION_CURSOR_ARGUMENT_NAME,
+        CONNECTION_COUNT_ARGUMENT_NAME,
+    ] {
+        let is_passed_a_variable = connection_selection.arguments.iter().any(|argument| {
+            argument.item.name.item == argument_name
+                && matches!(argument.item.value.item, NonConstantValue::Variable(_))
+        });
+        if !is_passed_a_variable {
+            return Err((
+                ValidateConnectionSelectionsError::ConnectionArgumentIsNotVariable {
+                    client_field_name,
+                    connection_field_name,
+                    argument_name: argument_name.intern().into(),
+                },
+                Some(connection_selection.name.location),
+            ));
+        }
+    }
+
+    for subfield_path in [
+        [
+            CONNECTION_PAGE_INFO_FIELD_NAME,
+            CONNECTION_END_CURSOR_FIELD_NAME,
+        ],
+        [
+            CONNECTION_PAGE_INFO_FIELD_NAME,
+            CONNECTION_HAS_NEXT_PAGE_FIELD_NAME,
+        ],
+        [CONNECTION_EDGES_FIELD_NAME, CONNECTION_NODE_FIELD_NAME],
+    ] {
+        if !selects_path(&connection_selection.selection_set, &subfield_path) {
+            return Err((
+                ValidateConnectionSelectionsError::ConnectionSubfieldNotSelected {
+                    client_field_name,
+                    connection_field_name,
+                    subfield_path: subfield_path.join("."),
+                },
+                Some(connection_selection.name.location),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether the selected field is a server field whose type has edges and pageInfo
+/// fields.
+fn is_connection_selection<TNetworkProtocol: NetworkProtocol>(
+    schema: &Schema<TNetworkProtocol>,
+    object_selection: &ValidatedObjectSelection,
+) -> bool {
+    let server_object_selectable_id = match object_selection.associated_data {
+        DefinitionLocation::Server(server_object_selectable_id) => server_object_selectable_id,
+        DefinitionLocation::Client(_) => return false,
+    };
+    let target_object_entity_id = *schema
+        .server_object_selectable(server_object_selectable_id)
+        .target_object_entity
+        .inner();
+    let available_selectables = &schema
+        .server_entity_data
+        .server_object_entity_available_selectables
+        .get(&target_object_entity_id)
+        .expect(
+            "Expected object_entity_id to exist \
+            in server_object_entity_available_selectables",
+        )
+        .0;
+
+    [CONNECTION_EDGES_FIELD_NAME, CONNECTION_PAGE_INFO_FIELD_NAME]
+        .into_iter()
+        .all(|field_name| {
+            let field_name: SelectableName = field_name.intern().into();
+            matches!(
+                available_selectables.get(&field_name),
+                Some(DefinitionLocation::Server(SelectionType::Object(_)))
+            )
+        })
+}
+
+/// Whether each field in the path is selected, without an alias. Aliased fields do not
+/// count, since the data read by the client field contains the alias instead.
+fn selects_path(selection_set: &[WithSpan<ValidatedSelection>], path: &[&'static str]) -> bool {
+    let Some((first, rest)) = path.split_first() else {
+        return true;
+    };
+    selection_set.iter().any(|selection| match &selection.item {
+        SelectionType::Scalar(scalar_selection) => {
+            rest.is_empty()
+                && scalar_selection.name.item == *first
+                && scalar_selection.reader_alias.is_none()
+        }
+        SelectionType::Object(object_selection) => {
+            object_selection.name.item == *first
+                && object_selection.reader_alias.is_none()
+                && selects_path(&object_selection.selection_set, rest)
+        }
+    })
+}
+
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+pub enum ValidateConnectionSelectionsError {
+    #[error(
+        "`{}.{}` cannot be selected with `@connection`. Only client fields \
+        declared with the `field` keyword can be selected with `@connection`.",
+        client_field_name.type_name,
+        client_field_name.field_name
+    )]
+    ConnectionFieldIsNotUserWritten {
+        client_field_name: ObjectTypeAndFieldName,
+    },
+
+    #[error(
+        "`{}.{}` is selected with `@connection`, but it does not select a connection \
+        field. A connection field is a server field whose type has `edges` and \
+        `pageInfo` fields.",
+        client_field_name.type_name,
+        client_field_name.field_name
+    )]
+    NoConnectionFieldSelected {
+        client_field_name: ObjectTypeAndFieldName,
+    },
+
+    #[error(
+        "`{}.{}` is selected with `@connection`, but it selects more than one \
+        connection field: `{first_field_name}` and `{second_field_name}`.",
+        client_field_name.type_name,
+        client_field_name.field_name
+    )]
+    MultipleConnectionFieldsSelected {
+        client_field_name: ObjectTypeAndFieldName,
+        first_field_name: ServerObjectSelectableName,
+        second_field_name: ServerObjectSelectableName,
+    },
+
+    #[error(
+        "`{}.{}` is selected with `@connection`, so the `{argument_name}` argument \
+        of `{connection_field_name}` must be passed a variable, e.g. \
+        `{argument_name}: ${argument_name}`.",
+        client_field_name.type_name,
+        client_field_name.field_name
+    )]
+    ConnectionArgumentIsNotVariable {
+        client_field_name: ObjectTypeAndFieldName,
+        connection_field_name: ServerObjectSelectableName,
+        argument_name: FieldArgumentName,
+    },
+
+    #[error(
+        "`{}.{}` is selected with `@connection`, so `{connection_field_name}.{subfield_path}` \
+        must be selected, without an alias.",
+        client_field_name.type_name,
+        client_field_name.field_name
+    )]
+    ConnectionSubfieldNotSelected {
+        client_field_name: ObjectTypeAndFieldName,
+        connection_field_name: ServerObjectSelectableName,
+        subfield_path: String,
+    },
+}
+
+impl HasDiagnosticCode for ValidateConnectionSelectionsError {
+    fn diagnostic_code(&self) -> DiagnosticCode {
+        match self {
+            ValidateConnectionSelectionsError::ConnectionFieldIsNotUserWritten { .. } => {
+                DiagnosticCode(901)
+            }
+            ValidateConnectionSelectionsError::NoConnectionFieldSelected { .. } => {
+                DiagnosticCode(902)
+            }
+            ValidateConnectionSelectionsError::MultipleConnectionFieldsSelected { .. } => {
+                DiagnosticCode(903)
+            }
+            ValidateConnectionSelectionsError::ConnectionArgumentIsNotVariable { .. } => {
+                DiagnosticCode(904)
+            }
+            ValidateConnectionSelectionsError::ConnectionSubfieldNotSelected { .. } => {
+                DiagnosticCode(905)
+            }
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use std::collections::HashMap;
 
 use common_lang_types::{
-    IsoLiteralText, IsographObjectTypeName, Location, ServerScalarSelectableName, TextSource,
-    UnvalidatedTypeName, WithLocation, WithSpan,
+    DiagnosticCode, HasDiagnosticCode, IsoLiteralText, IsographObjectTypeName, Location,
+    ServerScalarSelectableName, TextSource, UnvalidatedTypeName, WithLocation, WithSpan,
 };
 use isograph_lang_types::{
     ClientScalarSelectableId, DefinitionLocation, EntrypointDeclaration, SelectionType,
@@ -13,13 +13,16 @@ use thiserror::Error;
 
 use crate::{NetworkProtocol, Schema};
 
+/// Validate each entrypoint declaration. The valid entrypoints are returned alongside
+/// the errors, so that compilation can continue and report errors from later phases.
+#[allow(clippy::type_complexity)]
 pub fn validate_entrypoints<TNetworkProtocol: NetworkProtocol>(
     schema: &Schema<TNetworkProtocol>,
     entrypoint_declarations: Vec<(TextSource, WithSpan<EntrypointDeclaration>)>,
-) -> Result<
+) -> (
     HashMap<ClientScalarSelectableId, IsoLiteralText>,
     Vec<WithLocation<ValidateEntrypointDeclarationError>>,
-> {
+) {
     let mut errors = vec![];
     let mut entrypoints = HashMap::new();
     for (text_source, entrypoint_declaration) in entrypoint_declarations {
@@ -36,11 +39,7 @@ pub fn validate_entrypoints<TNetworkProtocol: NetworkProtocol>(
         }
     }
 
-    if errors.is_empty() {
-        Ok(entrypoints)
-    } else {
-        Err(errors)
-    }
+    (entrypoints, errors)
 }
 
 fn validate_entrypoint_type_and_field<TNetworkProtocol: NetworkProtocol>(
@@ -198,3 +197,19 @@ pub enum ValidateEntrypointDeclarationError {
         client_field_name: ServerScalarSelectableName,
     },
 }
+
+impl HasDiagnosticCode for ValidateEntrypointDeclarationError {
+    fn diagnostic_code(&self) -> DiagnosticCode {
+        match self {
+            ValidateEntrypointDeclarationError::ParentTypeNotDefined { .. } => DiagnosticCode(301),
+            ValidateEntrypointDeclarationError::InvalidParentType { .. } => DiagnosticCode(302),
+            ValidateEntrypointDeclarationError::NonFetchableParentType { .. } => {
+                DiagnosticCode(303)
+            }
+            ValidateEntrypointDeclarationError::ClientFieldMustExist { .. } => DiagnosticCode(304),
+            ValidateEntrypointDeclarationError::FieldMustBeClientField { .. } => {
+                DiagnosticCode(305)
+            }
+        }
+    }
+}
@@ -1,14 +1,14 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, HashSet};
 
 use common_lang_types::{
-    FieldArgumentName, IsographObjectTypeName, Location, ObjectTypeAndFieldName, SelectableName,
-    VariableName, WithLocation, WithSpan,
+    closest_match, did_you_mean, DiagnosticCode, FieldArgumentName, HasDiagnosticCode,
+    IsographObjectTypeName, Location, ObjectTypeAndFieldName, SelectableName, VariableName,
+    WithLocation, WithSpan,
 };
 
-use intern::string_key::Intern;
+use intern::{string_key::Intern, Lookup};
 use isograph_lang_types::{
-    DefinitionLocation, NonConstantValue, ScalarSelectionDirectiveSet, SelectionFieldArgument,
-    SelectionType,
+    DefinitionLocation, NonConstantValue, SelectionFieldArgument, SelectionType,
 };
 use lazy_static::lazy_static;
 use thiserror::Error;
@@ -16,7 +16,8 @@ use thiserror::Error;
 use crate::{
     validate_argument_types::{value_satisfies_type, ValidateArgumentTypesError},
     visit_selection_set::visit_selection_set,
-    ClientScalarOrObjectSelectable, NetworkProtocol, Schema, ValidatedVariableDefinition,
+    ClientScalarOrObjectSelectable, ClientSelectableId, NetworkProtocol, Schema,
+    ValidatedVariableDefinition,
 };
 
 type UsedVariables = BTreeSet<VariableName>;
@@ -35,21 +36,35 @@ lazy_static! {
 /// This should not be validated here, and can be fixed with better modeling (i.e.
 /// have different associated data for fields that points to server objects and
 /// fields that point to client objects.)
+///
+/// Client types whose selection sets are invalid (and thus were not added to the
+/// schema) are skipped, since their variables would otherwise be reported as unused.
 pub fn validate_use_of_arguments<TNetworkProtocol: NetworkProtocol>(
     validated_schema: &Schema<TNetworkProtocol>,
+    client_selectables_with_invalid_selection_sets: &HashSet<ClientSelectableId>,
 ) -> Result<(), Vec<WithLocation<ValidateUseOfArgumentsError>>> {
     let mut errors = vec![];
-    for client_scalar_selectable in &validated_schema.client_scalar_selectables {
+    for client_scalar_selectable in validated_schema.client_scalar_selectables_and_ids() {
+        if client_selectables_with_invalid_selection_sets
+            .contains(&SelectionType::Scalar(client_scalar_selectable.id))
+        {
+            continue;
+        }
         validate_use_of_arguments_for_client_type(
             validated_schema,
-            client_scalar_selectable,
+            client_scalar_selectable.item,
             &mut errors,
         );
     }
-    for client_object_selectable in &validated_schema.client_object_selectables {
+    for client_object_selectable in validated_schema.client_object_selectables_and_ids() {
+        if client_selectables_with_invalid_selection_sets
+            .contains(&SelectionType::Object(client_object_selectable.id))
+        {
+            continue;
+        }
         validate_use_of_arguments_for_client_type(
             validated_schema,
-            client_object_selectable,
+            client_object_selectable.item,
             &mut errors,
         );
     }
@@ -87,11 +102,11 @@ fn validate_use_of_arguments_for_client_type<TNetworkProtocol: NetworkProtocol>(
                         .collect(),
                 };
 
-                // Only loadably selected fields are allowed to have missing arguments
-                let can_have_missing_args = matches!(
-                    scalar_selection.scalar_selection_directive_set,
-                    ScalarSelectionDirectiveSet::Loadable(_)
-                );
+                // Only loadably selected fields (that are not deferred) are allowed to have
+                // missing arguments
+                let can_have_missing_args = scalar_selection
+                    .scalar_selection_directive_set
+                    .arguments_can_be_provided_at_runtime();
 
                 validate_use_of_arguments_impl(
                     schema,
@@ -183,13 +198,10 @@ fn validate_use_of_arguments_impl<TNetworkProtocol: NetworkProtocol>(
         }
     }
 
-    maybe_push_errors(
+    validate_no_extraneous_arguments(
         errors,
-        validate_no_extraneous_arguments(
-            &field_argument_definitions,
-            selection_supplied_arguments,
-            name_location,
-        ),
+        &field_argument_definitions,
+        selection_supplied_arguments,
     );
 
     extend_reachable_variables_with_args(reachable_variables, selection_supplied_arguments);
@@ -280,41 +292,48 @@ fn get_missing_and_provided_arguments<'a>(
         })
 }
 
+/// Each argument that is not defined on the field is reported separately, at the
+/// argument's name, so that editors can offer to replace it with a suggestion.
 fn validate_no_extraneous_arguments(
+    errors: &mut Vec<WithLocation<ValidateUseOfArgumentsError>>,
     field_argument_definitions: &[&ValidatedVariableDefinition],
     selection_supplied_arguments: &[WithLocation<SelectionFieldArgument>],
-    location: Location,
-) -> ValidateUseOfArgumentsResult<()> {
-    let extra_arguments: Vec<_> = selection_supplied_arguments
-        .iter()
-        .filter_map(|arg| {
-            // TODO remove this
-            // With @exposeField on Query, id field is needed because the generated
-            // query is like node(id: $id) { ... everything else }, but that
-            // id field is added in somewhere else
-
-            if arg.item.name.item == *ID {
-                return None;
-            }
-
-            let is_defined = field_argument_definitions
-                .iter()
-                .any(|definition| definition.name.item == arg.item.name.item);
-
-            if !is_defined {
-                return Some(arg.clone());
-            }
-            None
-        })
-        .collect();
+) {
+    for arg in selection_supplied_arguments {
+        // TODO remove this
+        // With @exposeField on Query, id field is needed because the generated
+        // query is like node(id: $id) { ... everything else }, but that
+        // id field is added in somewhere else
+        if arg.item.name.item == *ID {
+            continue;
+        }
 
-    if !extra_arguments.is_empty() {
-        return Err(WithLocation::new(
-            ValidateUseOfArgumentsError::ExtraneousArgument { extra_arguments },
-            location,
-        ));
+        let is_defined = field_argument_definitions
+            .iter()
+            .any(|definition| definition.name.item == arg.item.name.item);
+
+        if !is_defined {
+            let name_location = match arg.location {
+                Location::Embedded(embedded_location) => {
+                    Location::new(embedded_location.text_source, arg.item.name.span)
+                }
+                Location::Generated => Location::Generated,
+            };
+            errors.push(WithLocation::new(
+                ValidateUseOfArgumentsError::ExtraneousArgument {
+                    argument_name: arg.item.name.item,
+                    suggestion: closest_match(
+                        arg.item.name.item.lookup(),
+                        field_argument_definitions
+                            .iter()
+                            .map(|definition| definition.name.item),
+                    )
+                    .map(|name| name.unchecked_conversion()),
+                },
+                name_location,
+            ));
+        }
     }
-    Ok(())
 }
 
 pub fn extend_reachable_variables_with_arg(
@@ -368,11 +387,12 @@ pub enum ValidateUseOfArgumentsError {
     MissingArguments { missing_arguments: MissingArguments },
 
     #[error(
-        "This field has extra arguments: {0}",
-        extra_arguments.iter().map(|arg| format!("{}", arg.item.name)).collect::<Vec<_>>().join(", ")
+        "This field has an extra argument: `{argument_name}`.{}",
+        did_you_mean(*suggestion)
     )]
     ExtraneousArgument {
-        extra_arguments: Vec<WithLocation<SelectionFieldArgument>>,
+        argument_name: FieldArgumentName,
+        suggestion: Option<FieldArgumentName>,
     },
 
     #[error(
@@ -391,3 +411,26 @@ pub enum ValidateUseOfArgumentsError {
         message: ValidateArgumentTypesError,
     },
 }
+
+impl HasDiagnosticCode for ValidateUseOfArgumentsError {
+    fn diagnostic_code(&self) -> DiagnosticCode {
+        match self {
+            ValidateUseOfArgumentsError::MissingArguments { .. } => DiagnosticCode(501),
+            ValidateUseOfArgumentsError::ExtraneousArgument { .. } => DiagnosticCode(502),
+            ValidateUseOfArgumentsError::UnusedVariables { .. } => DiagnosticCode(503),
+            ValidateUseOfArgumentsError::ValidateArgumentType { message } => {
+                message.diagnostic_code()
+            }
+        }
+    }
+
+    fn suggestion(&self) -> Option<String> {
+        match self {
+            ValidateUseOfArgumentsError::ExtraneousArgument { suggestion, .. } => {
+                suggestion.map(|suggestion| suggestion.to_string())
+            }
+            ValidateUseOfArgumentsError::ValidateArgumentType { message } => message.suggestion(),
+            _ => None,
+        }
+    }
+}
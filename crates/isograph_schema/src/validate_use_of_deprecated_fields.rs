@@ -0,0 +1,155 @@
+use common_lang_types::{
+    DescriptionValue, DiagnosticCode, FieldArgumentName, HasDiagnosticCode, IsographObjectTypeName,
+    Location, SelectableName, VariableName, WithLocation,
+};
+use isograph_lang_types::{DefinitionLocation, SelectionFieldArgument, SelectionType};
+use thiserror::Error;
+
+use crate::{visit_selection_set::visit_selection_set, NetworkProtocol, Schema};
+
+/// Find every selection of a server field, and every argument, that is marked
+/// @deprecated in the schema. Depending on the on_deprecated_field config option, the
+/// caller reports these uses as warnings or as errors.
+pub fn validate_use_of_deprecated_fields<TNetworkProtocol: NetworkProtocol>(
+    schema: &Schema<TNetworkProtocol>,
+) -> Vec<WithLocation<ValidateUseOfDeprecatedFieldsError>> {
+    let mut uses = vec![];
+    let reader_selection_sets = schema
+        .client_scalar_selectables
+        .iter()
+        .map(|client_field| &client_field.reader_selection_set)
+        .chain(
+            schema
+                .client_object_selectables
+                .iter()
+                .map(|client_pointer| &client_pointer.reader_selection_set),
+        );
+
+    for reader_selection_set in reader_selection_sets {
+        visit_selection_set(reader_selection_set, &mut |selection| {
+            let (deprecation_reason, argument_deprecation_reasons, parent_object_entity_id) =
+                match selection {
+                    SelectionType::Scalar(scalar_selection) => {
+                        match scalar_selection.associated_data {
+                            DefinitionLocation::Server(server_scalar_selectable_id) => {
+                                let field =
+                                    schema.server_scalar_selectable(server_scalar_selectable_id);
+                                (
+                                    field.deprecation_reason,
+                                    &field.argument_deprecation_reasons,
+                                    field.parent_object_entity_id,
+                                )
+                            }
+                            DefinitionLocation::Client(_) => return,
+                        }
+                    }
+                    SelectionType::Object(object_selection) => {
+                        match object_selection.associated_data {
+                            DefinitionLocation::Server(server_object_selectable_id) => {
+                                let field =
+                                    schema.server_object_selectable(server_object_selectable_id);
+                                (
+                                    field.deprecation_reason,
+                                    &field.argument_deprecation_reasons,
+                                    field.parent_object_entity_id,
+                                )
+                            }
+                            DefinitionLocation::Client(_) => return,
+                        }
+                    }
+                };
+            let (name, arguments) = match selection {
+                SelectionType::Scalar(scalar_selection) => (
+                    scalar_selection.name.map(SelectableName::from),
+                    &scalar_selection.arguments,
+                ),
+                SelectionType::Object(object_selection) => (
+                    object_selection.name.map(SelectableName::from),
+                    &object_selection.arguments,
+                ),
+            };
+            let parent_type = schema
+                .server_entity_data
+                .server_object_entity(parent_object_entity_id)
+                .name;
+
+            let mut report = |error: ValidateUseOfDeprecatedFieldsError, location: Location| {
+                uses.push(WithLocation::new(error, location));
+            };
+
+            if let Some(reason) = deprecation_reason {
+                report(
+                    ValidateUseOfDeprecatedFieldsError::DeprecatedFieldSelected {
+                        parent_type,
+                        field_name: name.item,
+                        reason,
+                    },
+                    name.location,
+                );
+            }
+
+            for argument in arguments {
+                let SelectionFieldArgument {
+                    name: argument_name,
+                    ..
+                } = &argument.item;
+                let variable_name: VariableName = argument_name.item.unchecked_conversion();
+                if let Some(reason) = argument_deprecation_reasons.get(&variable_name) {
+                    report(
+                        ValidateUseOfDeprecatedFieldsError::DeprecatedArgumentUsed {
+                            parent_type,
+                            field_name: name.item,
+                            argument_name: argument_name.item,
+                            reason: *reason,
+                        },
+                        argument.location,
+                    );
+                }
+            }
+        });
+    }
+
+    uses
+}
+
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+pub enum ValidateUseOfDeprecatedFieldsError {
+    #[error(
+        "The field `{parent_type}.{field_name}` is deprecated. Reason: {reason}\n\
+        This can be configured using the \"on_deprecated_field\" config parameter."
+    )]
+    DeprecatedFieldSelected {
+        parent_type: IsographObjectTypeName,
+        field_name: SelectableName,
+        reason: DescriptionValue,
+    },
+
+    #[error(
+        "The argument `{argument_name}` of the field `{parent_type}.{field_name}` is \
+        deprecated. Reason: {reason}\n\
+        This can be configured using the \"on_deprecated_field\" config parameter."
+    )]
+    DeprecatedArgumentUsed {
+        parent_type: IsographObjectTypeName,
+        field_name: SelectableName,
+        argument_name: FieldArgumentName,
+        reason: DescriptionValue,
+    },
+}
+
+impl HasDiagnosticCode for ValidateUseOfDeprecatedFieldsError {
+    fn diagnostic_code(&self) -> DiagnosticCode {
+        match self {
+            ValidateUseOfDeprecatedFieldsError::DeprecatedFieldSelected { .. } => {
+                DiagnosticCode(701)
+            }
+            ValidateUseOfDeprecatedFieldsError::DeprecatedArgumentUsed { .. } => {
+                DiagnosticCode(702)
+            }
+        }
+    }
+
+    fn reports_deprecation(&self) -> bool {
+        true
+    }
+}
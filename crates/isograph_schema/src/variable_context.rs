@@ -44,7 +44,7 @@ impl VariableContext {
                 {
                     Some(arg) => arg,
                     None => {
-                        if matches!(selection_variant, ScalarSelectionDirectiveSet::Loadable(_)) {
+                        if selection_variant.arguments_can_be_provided_at_runtime() {
                             // If this field was selected loadably, missing arguments are allowed.
                             // These missing arguments become variables that are provided at
                             // runtime. If they are missing at runtime, they will fall back to
@@ -3,7 +3,7 @@ use isograph_lang_types::{
     ObjectSelection, ScalarSelection, SelectionType, SelectionTypeContainingSelections,
 };
 
-pub(crate) fn visit_selection_set<
+pub fn visit_selection_set<
     TSelectionTypeSelectionScalarFieldAssociatedData,
     TSelectionTypeSelectionLinkedFieldAssociatedData,
 >(
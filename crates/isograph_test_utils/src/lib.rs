@@ -0,0 +1,54 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// A directory containing the files of a test project (e.g. a config file, a schema
+/// and source files), created in the system's temporary directory. The directory is
+/// removed when this is dropped.
+pub struct TestProjectDirectory {
+    path: PathBuf,
+}
+
+impl TestProjectDirectory {
+    /// Create the directory and the files, whose paths are relative to the directory.
+    /// The name of the test is part of the name of the directory, so tests that run
+    /// concurrently must have different names.
+    pub fn new(test_name: &str, files: &[(&str, &str)]) -> Self {
+        let path =
+            std::env::temp_dir().join(format!("isograph_{test_name}_test_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+
+        // The temporary directory may be a symlink (e.g. on macOS), but the compiler
+        // works with canonicalized paths.
+        let directory = TestProjectDirectory {
+            path: path.canonicalize().unwrap(),
+        };
+        for (relative_path, content) in files {
+            directory.write(relative_path, content);
+        }
+        directory
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn join(&self, relative_path: impl AsRef<Path>) -> PathBuf {
+        self.path.join(relative_path)
+    }
+
+    /// Write the file, creating the directories containing it if necessary.
+    pub fn write(&self, relative_path: impl AsRef<Path>, content: &str) {
+        let path = self.join(relative_path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+}
+
+impl Drop for TestProjectDirectory {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
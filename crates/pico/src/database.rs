@@ -78,6 +78,12 @@ impl Database {
         );
     }
 
+    /// The current revision of the database. It is incremented whenever the value of
+    /// a source changes, or a source is removed.
+    pub fn current_epoch(&self) -> Epoch {
+        self.storage.current_epoch
+    }
+
     pub fn get<T: 'static>(&self, id: SourceId<T>) -> &T {
         let source_node = self.storage.get_source_node(id.key).expect(
             "source node not found. SourceId should not be used \
@@ -14,6 +14,7 @@ mod source;
 
 pub use database::*;
 pub use derived_node::*;
+pub use epoch::Epoch;
 pub use execute_memoized_function::*;
 pub use intern::*;
 pub use memo_ref::*;
@@ -3,7 +3,8 @@ use graphql_lang_types::{
     from_graph_ql_directive, DeserializationError, GraphQLConstantValue, GraphQLDirective,
 };
 use intern::string_key::Intern;
-use isograph_schema::{ExposeFieldDirective, FieldMapItem};
+use isograph_schema::{ExposeFieldDirective, FieldMapItem, RefetchFromRootDirective};
+use serde::de::DeserializeOwned;
 use std::error::Error;
 
 use graphql_lang_types::{GraphQLTypeSystemExtension, GraphQLTypeSystemExtensionOrDefinition};
@@ -19,6 +20,10 @@ fn unwrap_directive(
 }
 
 fn parse_mutation(source: &str) -> Result<Vec<ExposeFieldDirective>, Box<dyn Error>> {
+    parse_directives(source)
+}
+
+fn parse_directives<T: DeserializeOwned>(source: &str) -> Result<Vec<T>, Box<dyn Error>> {
     let text_source = TextSource {
         relative_path_to_source_file: "dummy".intern().into(),
         span: None,
@@ -33,11 +38,11 @@ fn parse_mutation(source: &str) -> Result<Vec<ExposeFieldDirective>, Box<dyn Err
         .collect::<Result<Vec<_>, _>>()?;
     let directives: Vec<GraphQLDirective<GraphQLConstantValue>> =
         directives.into_iter().flatten().collect();
-    let expose_field_directives: Result<Vec<ExposeFieldDirective>, _> = directives
+    let parsed_directives: Result<Vec<T>, _> = directives
         .into_iter()
-        .map(|directive| from_graph_ql_directive::<ExposeFieldDirective>(&directive))
+        .map(|directive| from_graph_ql_directive::<T>(&directive))
         .collect();
-    Ok(expose_field_directives?)
+    Ok(parsed_directives?)
 }
 
 #[test]
@@ -96,8 +101,27 @@ fn test_mutation_extension_set_pet_bestfriend_parsing() -> Result<(), Box<dyn Er
     Ok(())
 }
 
-fn match_failure_message(
-    expose_field_directives: Result<Vec<ExposeFieldDirective>, Box<dyn Error>>,
+#[test]
+fn test_refetch_from_root_parsing() -> Result<(), Box<dyn Error>> {
+    let refetch_from_root_directives = parse_directives::<RefetchFromRootDirective>(include_str!(
+        "fixtures/directives/refetch_from_root_valid.graphql"
+    ))?;
+    assert_eq!(
+        refetch_from_root_directives,
+        vec![RefetchFromRootDirective::new(StringLiteralValue::from(
+            "viewer.settings".intern()
+        ))]
+    );
+
+    let refetch_from_root_directives = parse_directives::<RefetchFromRootDirective>(include_str!(
+        "fixtures/directives/refetch_from_root_missing_path.graphql"
+    ));
+    match_failure_message(refetch_from_root_directives, "missing field `path`");
+    Ok(())
+}
+
+fn match_failure_message<T>(
+    expose_field_directives: Result<Vec<T>, Box<dyn Error>>,
     message: &str,
 ) {
     match expose_field_directives {
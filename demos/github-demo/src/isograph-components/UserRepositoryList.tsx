@@ -13,7 +13,7 @@ import { Route } from './GithubDemo';
 export const RepositoryList = iso(`
   field User.RepositoryList @component {
     firstPage: RepositoryConnection(first: 10)
-    RepositoryConnection @loadable
+    RepositoryConnection @connection
   }
 `)(function UserRepositoryListComponent(
   { data },
@@ -1,21 +1,24 @@
 
 export type Actor__UserLink__param = {
+  /**
+   * Represents an object which can take actions on GitHub. Typically a User or Bot.
+   */
   readonly data: {
     /**
-The username of the actor.
-    */
+     * The username of the actor.
+     */
     readonly login: string,
     /**
-A client pointer for the User type.
-    */
+     * A client pointer for the User type.
+     */
     readonly asUser: ({
       /**
-The Node ID of the User object
-      */
+       * The Node ID of the User object
+       */
       readonly id: string,
       /**
-The user's Twitter username.
-      */
+       * The user's Twitter username.
+       */
       readonly twitterUsername: (string | null),
     } | null),
   },
@@ -1,9 +1,12 @@
 
 export type IssueComment__formattedCommentCreationDate__param = {
+  /**
+   * Represents a comment on an Issue.
+   */
   readonly data: {
     /**
-Identifies the date and time when the object was created.
-    */
+     * Identifies the date and time when the object was created.
+     */
     readonly createdAt: string,
   },
   readonly parameters: Record<PropertyKey, never>,
@@ -2,35 +2,38 @@ import { type IssueComment__formattedCommentCreationDate__output_type } from '..
 import type { PullRequest__CommentList__parameters } from './parameters_type';
 
 export type PullRequest__CommentList__param = {
+  /**
+   * A repository pull request.
+   */
   readonly data: {
     /**
-A list of comments associated with the pull request.
-    */
+     * A list of comments associated with the pull request.
+     */
     readonly comments: {
       /**
-A list of edges.
-      */
+       * A list of edges.
+       */
       readonly edges: (ReadonlyArray<({
         /**
-The item at the end of the edge.
-        */
+         * The item at the end of the edge.
+         */
         readonly node: ({
           /**
-The Node ID of the IssueComment object
-          */
+           * The Node ID of the IssueComment object
+           */
           readonly id: string,
           /**
-The body rendered to text.
-          */
+           * The body rendered to text.
+           */
           readonly bodyText: string,
           readonly formattedCommentCreationDate: IssueComment__formattedCommentCreationDate__output_type,
           /**
-The actor who authored the comment.
-          */
+           * The actor who authored the comment.
+           */
           readonly author: ({
             /**
-The username of the actor.
-            */
+             * The username of the actor.
+             */
             readonly login: string,
           } | null),
         } | null),
@@ -1,25 +1,28 @@
 
 export type PullRequest__PullRequestLink__param = {
+  /**
+   * A repository pull request.
+   */
   readonly data: {
     /**
-Identifies the pull request number.
-    */
+     * Identifies the pull request number.
+     */
     readonly number: number,
     /**
-The repository associated with this node.
-    */
+     * The repository associated with this node.
+     */
     readonly repository: {
       /**
-The name of the repository.
-      */
+       * The name of the repository.
+       */
       readonly name: string,
       /**
-The User owner of the repository.
-      */
+       * The User owner of the repository.
+       */
       readonly owner: {
         /**
-The username used to login.
-        */
+         * The username used to login.
+         */
         readonly login: string,
       },
     },
@@ -1,9 +1,12 @@
 
 export type PullRequest__createdAtFormatted__param = {
+  /**
+   * A repository pull request.
+   */
   readonly data: {
     /**
-Identifies the date and time when the object was created.
-    */
+     * Identifies the date and time when the object was created.
+     */
     readonly createdAt: string,
   },
   readonly parameters: Record<PropertyKey, never>,
@@ -1,14 +1,17 @@
 import { type User__Avatar__output_type } from '../../User/Avatar/output_type';
 
 export type Query__Header__param = {
+  /**
+   * The query root of GitHub's GraphQL interface.
+   */
   readonly data: {
     /**
-The currently authenticated user.
-    */
+     * The currently authenticated user.
+     */
     readonly viewer: {
       /**
-The user's public profile name.
-      */
+       * The user's public profile name.
+       */
       readonly name: (string | null),
       readonly Avatar: User__Avatar__output_type,
     },
@@ -2,6 +2,9 @@ import { type Query__Header__output_type } from '../../Query/Header/output_type'
 import { type Query__HomePageList__output_type } from '../../Query/HomePageList/output_type';
 
 export type Query__HomePage__param = {
+  /**
+   * The query root of GitHub's GraphQL interface.
+   */
   readonly data: {
     readonly Header: Query__Header__output_type,
     readonly HomePageList: Query__HomePageList__output_type,
@@ -2,23 +2,26 @@ import { type User__RepositoryList__output_type } from '../../User/RepositoryLis
 import { type User____refetch__output_type } from '../../User/__refetch/output_type';
 
 export type Query__HomePageList__param = {
+  /**
+   * The query root of GitHub's GraphQL interface.
+   */
   readonly data: {
     /**
-The currently authenticated user.
-    */
+     * The currently authenticated user.
+     */
     readonly viewer: {
       /**
-The username used to login.
-      */
+       * The username used to login.
+       */
       readonly login: string,
       /**
-The user's public profile name.
-      */
+       * The user's public profile name.
+       */
       readonly name: (string | null),
       readonly RepositoryList: User__RepositoryList__output_type,
       /**
-A refetch field for the User type.
-      */
+       * A refetch field for the User type.
+       */
       readonly __refetch: User____refetch__output_type,
     },
   },
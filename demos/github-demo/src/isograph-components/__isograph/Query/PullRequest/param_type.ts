@@ -3,6 +3,9 @@ import { type Query__PullRequestDetail__output_type } from '../../Query/PullRequ
 import type { Query__PullRequest__parameters } from './parameters_type';
 
 export type Query__PullRequest__param = {
+  /**
+   * The query root of GitHub's GraphQL interface.
+   */
   readonly data: {
     readonly Header: Query__Header__output_type,
     readonly PullRequestDetail: Query__PullRequestDetail__output_type,
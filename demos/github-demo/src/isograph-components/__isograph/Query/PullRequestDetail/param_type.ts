@@ -2,22 +2,25 @@ import { type PullRequest__CommentList__output_type } from '../../PullRequest/Co
 import type { Query__PullRequestDetail__parameters } from './parameters_type';
 
 export type Query__PullRequestDetail__param = {
+  /**
+   * The query root of GitHub's GraphQL interface.
+   */
   readonly data: {
     /**
-Lookup a given repository by the owner and repository name.
-    */
+     * Lookup a given repository by the owner and repository name.
+     */
     readonly repository: ({
       /**
-Returns a single pull request from the current repository by number.
-      */
+       * Returns a single pull request from the current repository by number.
+       */
       readonly pullRequest: ({
         /**
-Identifies the pull request title.
-        */
+         * Identifies the pull request title.
+         */
         readonly title: string,
         /**
-The body rendered to HTML.
-        */
+         * The body rendered to HTML.
+         */
         readonly bodyHTML: string,
         readonly CommentList: PullRequest__CommentList__output_type,
       } | null),
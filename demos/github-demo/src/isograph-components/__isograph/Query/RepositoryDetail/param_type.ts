@@ -4,29 +4,32 @@ import { type Starrable__IsStarred__output_type } from '../../Starrable/IsStarre
 import type { Query__RepositoryDetail__parameters } from './parameters_type';
 
 export type Query__RepositoryDetail__param = {
+  /**
+   * The query root of GitHub's GraphQL interface.
+   */
   readonly data: {
     /**
-Lookup a given repository by the owner and repository name.
-    */
+     * Lookup a given repository by the owner and repository name.
+     */
     readonly repository: ({
       readonly IsStarred: Starrable__IsStarred__output_type,
       /**
-The repository's name with owner.
-      */
+       * The repository's name with owner.
+       */
       readonly nameWithOwner: string,
       /**
-The repository parent, if this is a fork.
-      */
+       * The repository parent, if this is a fork.
+       */
       readonly parent: ({
         readonly RepositoryLink: Repository__RepositoryLink__output_type,
         /**
-The repository's name with owner.
-        */
+         * The repository's name with owner.
+         */
         readonly nameWithOwner: string,
       } | null),
       /**
-A list of pull requests that have been opened in the repository.
-      */
+       * A list of pull requests that have been opened in the repository.
+       */
       readonly pullRequests: {
         readonly PullRequestTable: PullRequestConnection__PullRequestTable__output_type,
       },
@@ -3,6 +3,9 @@ import { type Query__RepositoryDetail__output_type } from '../../Query/Repositor
 import type { Query__RepositoryPage__parameters } from './parameters_type';
 
 export type Query__RepositoryPage__param = {
+  /**
+   * The query root of GitHub's GraphQL interface.
+   */
   readonly data: {
     readonly Header: Query__Header__output_type,
     readonly RepositoryDetail: Query__RepositoryDetail__output_type,
@@ -2,14 +2,17 @@ import { type User__RepositoryList__output_type } from '../../User/RepositoryLis
 import type { Query__UserDetail__parameters } from './parameters_type';
 
 export type Query__UserDetail__param = {
+  /**
+   * The query root of GitHub's GraphQL interface.
+   */
   readonly data: {
     /**
-Lookup a user by login.
-    */
+     * Lookup a user by login.
+     */
     readonly user: ({
       /**
-The user's public profile name.
-      */
+       * The user's public profile name.
+       */
       readonly name: (string | null),
       readonly RepositoryList: User__RepositoryList__output_type,
     } | null),
@@ -3,6 +3,9 @@ import { type Query__UserDetail__output_type } from '../../Query/UserDetail/outp
 import type { Query__UserPage__parameters } from './parameters_type';
 
 export type Query__UserPage__param = {
+  /**
+   * The query root of GitHub's GraphQL interface.
+   */
   readonly data: {
     readonly Header: Query__Header__output_type,
     readonly UserDetail: Query__UserDetail__output_type,
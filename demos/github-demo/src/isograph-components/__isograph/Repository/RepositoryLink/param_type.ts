@@ -1,21 +1,24 @@
 
 export type Repository__RepositoryLink__param = {
+  /**
+   * A repository contains the content for a project.
+   */
   readonly data: {
     /**
-The Node ID of the Repository object
-    */
+     * The Node ID of the Repository object
+     */
     readonly id: string,
     /**
-The name of the repository.
-    */
+     * The name of the repository.
+     */
     readonly name: string,
     /**
-The User owner of the repository.
-    */
+     * The User owner of the repository.
+     */
     readonly owner: {
       /**
-The username used to login.
-      */
+       * The username used to login.
+       */
       readonly login: string,
     },
   },
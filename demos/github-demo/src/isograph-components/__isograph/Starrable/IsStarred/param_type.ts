@@ -1,13 +1,16 @@
 
 export type Starrable__IsStarred__param = {
+  /**
+   * Things that can be starred.
+   */
   readonly data: {
     /**
-Returns a count of how many stargazers there are on this object
-    */
+     * Returns a count of how many stargazers there are on this object
+     */
     readonly stargazerCount: number,
     /**
-Returns a boolean indicating whether the viewing user has starred this starrable.
-    */
+     * Returns a boolean indicating whether the viewing user has starred this starrable.
+     */
     readonly viewerHasStarred: boolean,
   },
   readonly parameters: Record<PropertyKey, never>,
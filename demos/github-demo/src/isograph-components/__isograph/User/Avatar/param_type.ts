@@ -1,13 +1,16 @@
 
 export type User__Avatar__param = {
+  /**
+   * A user is an individual's account on GitHub that owns repositories and can make new content.
+   */
   readonly data: {
     /**
-The user's public profile name.
-    */
+     * The user's public profile name.
+     */
     readonly name: (string | null),
     /**
-A URL pointing to the user's public avatar.
-    */
+     * A URL pointing to the user's public avatar.
+     */
     readonly avatarUrl: string,
   },
   readonly parameters: Record<PropertyKey, never>,
@@ -2,36 +2,39 @@ import { type Repository__RepositoryRow__output_type } from '../../Repository/Re
 import type { User__RepositoryConnection__parameters } from './parameters_type';
 
 export type User__RepositoryConnection__param = {
+  /**
+   * A user is an individual's account on GitHub that owns repositories and can make new content.
+   */
   readonly data: {
     /**
-A list of repositories that the user owns.
-    */
+     * A list of repositories that the user owns.
+     */
     readonly repositories: {
       /**
-Information to aid in pagination.
-      */
+       * Information to aid in pagination.
+       */
       readonly pageInfo: {
         /**
-When paginating forwards, are there more items?
-        */
+         * When paginating forwards, are there more items?
+         */
         readonly hasNextPage: boolean,
         /**
-When paginating forwards, the cursor to continue.
-        */
+         * When paginating forwards, the cursor to continue.
+         */
         readonly endCursor: (string | null),
       },
       /**
-A list of edges.
-      */
+       * A list of edges.
+       */
       readonly edges: (ReadonlyArray<({
         /**
-The item at the end of the edge.
-        */
+         * The item at the end of the edge.
+         */
         readonly node: ({
           readonly RepositoryRow: Repository__RepositoryRow__output_type,
           /**
-The Node ID of the Repository object
-          */
+           * The Node ID of the Repository object
+           */
           readonly id: string,
         } | null),
       } | null)> | null),
@@ -3,6 +3,9 @@ import { type LoadableField, type ExtractParameters } from '@isograph/react';
 import { type User__RepositoryConnection__param } from '../../User/RepositoryConnection/param_type';
 
 export type User__RepositoryList__param = {
+  /**
+   * A user is an individual's account on GitHub that owns repositories and can make new content.
+   */
   readonly data: {
     readonly firstPage: User__RepositoryConnection__output_type,
     readonly RepositoryConnection: LoadableField<
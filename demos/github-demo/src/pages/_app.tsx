@@ -2,21 +2,25 @@ import {
   createIsographEnvironment,
   createIsographStore,
   IsographEnvironmentProvider,
+  type IsographOperation,
 } from '@isograph/react';
 import type { AppProps } from 'next/app';
 import { useMemo } from 'react';
 
 function makeNetworkRequest<T>(
-  queryText: string,
+  operation: IsographOperation,
   variables: unknown,
 ): Promise<T> {
+  if (operation.kind === 'PersistedOperation') {
+    throw new Error('Persisted queries are not enabled in this project.');
+  }
   const promise = fetch('https://api.github.com/graphql', {
     method: 'POST',
     headers: {
       Authorization: 'Bearer ' + process.env.NEXT_PUBLIC_GITHUB_TOKEN,
       'Content-Type': 'application/json',
     },
-    body: JSON.stringify({ query: queryText, variables }),
+    body: JSON.stringify({ query: operation.queryText, variables }),
   }).then(async (response) => {
     const json = await response.json();
 
@@ -2,6 +2,7 @@ import {
   createIsographEnvironment,
   createIsographStore,
   IsographEnvironmentProvider,
+  type IsographOperation,
   type Link,
   type StoreRecord,
 } from '@isograph/react';
@@ -9,15 +10,18 @@ import type { AppProps } from 'next/app';
 import { useMemo } from 'react';
 
 function makeNetworkRequest<T>(
-  queryText: string,
+  operation: IsographOperation,
   variables: unknown,
 ): Promise<T> {
+  if (operation.kind === 'PersistedOperation') {
+    throw new Error('Persisted queries are not enabled in this project.');
+  }
   const promise = fetch('http://localhost:4000/graphql', {
     method: 'POST',
     headers: {
       'Content-Type': 'application/json',
     },
-    body: JSON.stringify({ query: queryText, variables }),
+    body: JSON.stringify({ query: operation.queryText, variables }),
   }).then(async (response) => {
     const json = await response.json();
 
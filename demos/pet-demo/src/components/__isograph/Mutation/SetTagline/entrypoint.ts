@@ -12,6 +12,7 @@ const artifact: IsographEntrypoint<
   NormalizationAst
 > = {
   kind: "Entrypoint",
+  operationKind: "Mutation",
   networkRequestInfo: {
     kind: "NetworkRequestInfo",
     queryText,
@@ -4,14 +4,14 @@ import { type BlogItem__BlogItemDisplay__output_type } from '../../BlogItem/Blog
 export type NewsfeedItem__NewsfeedAdOrBlog__param = {
   readonly data: {
     /**
-A client pointer for the AdItem type.
-    */
+     * A client pointer for the AdItem type.
+     */
     readonly asAdItem: ({
       readonly AdItemDisplayWrapper: AdItem__AdItemDisplayWrapper__output_type,
     } | null),
     /**
-A client pointer for the BlogItem type.
-    */
+     * A client pointer for the BlogItem type.
+     */
     readonly asBlogItem: ({
       readonly BlogItemDisplay: BlogItem__BlogItemDisplay__output_type,
     } | null),
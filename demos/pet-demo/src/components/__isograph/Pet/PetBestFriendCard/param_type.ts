@@ -4,10 +4,10 @@ export type Pet__PetBestFriendCard__param = {
   readonly data: {
     readonly id: string,
     /**
-Pet.PetUpdater
-A component to test behavior with respect to mutations.
-You can update the best friend and the tagline.
-    */
+     * Pet.PetUpdater
+     * A component to test behavior with respect to mutations.
+     * You can update the best friend and the tagline.
+     */
     readonly PetUpdater: Pet__PetUpdater__output_type,
     readonly best_friend_relationship: ({
       readonly picture_together: (string | null),
@@ -15,6 +15,7 @@ const artifact: IsographEntrypoint<
   NormalizationAst
 > = {
   kind: "Entrypoint",
+  operationKind: "Query",
   networkRequestInfo: {
     kind: "NetworkRequestInfo",
     queryText,
@@ -1,4 +1,9 @@
 import type { ExtractSecondParam, CombineWithIntrinsicAttributes } from '@isograph/react';
 import type React from 'react';
 import { PetUpdater as resolver } from '../../../PetUpdater';
+/**
+ * Pet.PetUpdater
+ * A component to test behavior with respect to mutations.
+ * You can update the best friend and the tagline.
+ */
 export type Pet__PetUpdater__output_type = (React.FC<CombineWithIntrinsicAttributes<ExtractSecondParam<typeof resolver>>>);
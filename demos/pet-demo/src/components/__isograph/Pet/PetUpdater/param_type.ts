@@ -3,6 +3,11 @@ import { type Pet__set_best_friend__output_type } from '../../Pet/set_best_frien
 import { type Pet__set_pet_tagline__output_type } from '../../Pet/set_pet_tagline/output_type';
 import type { StartUpdate } from '@isograph/react';
 
+/**
+ * Pet.PetUpdater
+ * A component to test behavior with respect to mutations.
+ * You can update the best friend and the tagline.
+ */
 export type Pet__PetUpdater__param = {
   readonly data: {
     readonly set_best_friend: Pet__set_best_friend__output_type,
@@ -13,8 +18,8 @@ export type Pet__PetUpdater__param = {
     readonly set_pet_tagline: Pet__set_pet_tagline__output_type,
     readonly tagline: string,
     /**
-A refetch field for the Pet type.
-    */
+     * A refetch field for the Pet type.
+     */
     readonly __refetch: Pet____refetch__output_type,
   },
   readonly parameters: Record<PropertyKey, never>,
@@ -27,8 +32,8 @@ A refetch field for the Pet type.
     readonly set_pet_tagline: Pet__set_pet_tagline__output_type,
     tagline: string,
     /**
-A refetch field for the Pet type.
-    */
+     * A refetch field for the Pet type.
+     */
     readonly __refetch: Pet____refetch__output_type,
   }>,
 };
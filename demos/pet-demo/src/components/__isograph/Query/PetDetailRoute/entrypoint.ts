@@ -23,6 +23,7 @@ const artifact: IsographEntrypoint<
   NormalizationAst
 > = {
   kind: "Entrypoint",
+  operationKind: "Query",
   networkRequestInfo: {
     kind: "NetworkRequestInfo",
     queryText,
@@ -5,14 +5,14 @@ export type Viewer__NewsfeedPaginationComponent__param = {
   readonly data: {
     readonly newsfeed: ReadonlyArray<{
       /**
-A client pointer for the AdItem type.
-      */
+       * A client pointer for the AdItem type.
+       */
       readonly asAdItem: ({
         readonly id: string,
       } | null),
       /**
-A client pointer for the BlogItem type.
-      */
+       * A client pointer for the BlogItem type.
+       */
       readonly asBlogItem: ({
         readonly id: string,
       } | null),
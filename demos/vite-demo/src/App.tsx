@@ -2,20 +2,24 @@ import {
   createIsographEnvironment,
   createIsographStore,
   IsographEnvironmentProvider,
+  type IsographOperation,
 } from '@isograph/react';
 import { Suspense, useMemo } from 'react';
 import HomePageRoute from './components/HomePageRoute';
 
 function makeNetworkRequest<T>(
-  queryText: string,
+  operation: IsographOperation,
   variables: unknown,
 ): Promise<T> {
+  if (operation.kind === 'PersistedOperation') {
+    throw new Error('Persisted queries are not enabled in this project.');
+  }
   const promise = fetch('https://graphqlpokemon.favware.tech/v8', {
     method: 'POST',
     headers: {
       'Content-Type': 'application/json',
     },
-    body: JSON.stringify({ query: queryText, variables }),
+    body: JSON.stringify({ query: operation.queryText, variables }),
   }).then(async (response) => {
     const json = await response.json();
 
@@ -3,28 +3,28 @@ import { type Pokemon__Pokemon__output_type } from '../../Pokemon/Pokemon/output
 export type Query__HomePage__param = {
   readonly data: {
     /**
-Returns a list of all the known Pokémon.
-
-For every Pokémon all the data on each requested field is returned.
-
-**_NOTE:_ To skip all CAP Pokémon, PokéStar Pokémon, Missingno, and 'M (00) provide an `offset` of 89**
-
-You can provide `take` to limit the amount of Pokémon to return (default: 1), set the offset of where to start with `offset`, and reverse the entire array with `reverse`.
-
-You can provide `takeFlavorTexts` to limit the amount of flavour texts to return, set the offset of where to start with `offsetFlavorTexts`, and reverse the entire array with `reverseFlavorTexts`.
-
-While the API will currently not rate limit the usage of this query, it may do so in the future.
-
-It is advisable to cache responses of this query.
-    */
+     * Returns a list of all the known Pokémon.
+     *
+     * For every Pokémon all the data on each requested field is returned.
+     *
+     * **_NOTE:_ To skip all CAP Pokémon, PokéStar Pokémon, Missingno, and 'M (00) provide an `offset` of 89**
+     *
+     * You can provide `take` to limit the amount of Pokémon to return (default: 1), set the offset of where to start with `offset`, and reverse the entire array with `reverse`.
+     *
+     * You can provide `takeFlavorTexts` to limit the amount of flavour texts to return, set the offset of where to start with `offsetFlavorTexts`, and reverse the entire array with `reverseFlavorTexts`.
+     *
+     * While the API will currently not rate limit the usage of this query, it may do so in the future.
+     *
+     * It is advisable to cache responses of this query.
+     */
     readonly getAllPokemon: ReadonlyArray<{
       /**
-The key of the Pokémon as stored in the API
-      */
-      readonly key: string,
+       * The key of the Pokémon as stored in the API
+       */
+      readonly key: 'pokestarsmeargle' | 'pokestarufo' | 'pokestarufo2' | 'pokestarbrycenman' | 'pokestarmt' | 'pokestarmt2' | 'pokestartransport' | 'pokestargiant' | 'pokestarhumanoid' | 'pokestarmonster' | 'pokestarf00' | 'pokestarf002' | 'pokestarspirit' | 'pokestarblackdoor' | 'pokestarwhitedoor' | 'pokestarblackbelt' | 'pokestarufopropu2' | 'syclar' | 'syclant' | 'revenankh' | 'embirch' | 'flarelm' | 'pyroak' | 'breezi' | 'fidgit' | 'rebble' | 'tactite' | 'stratagem' | 'privatyke' | 'arghonaut' | 'kitsunoh' | 'cyclohm' | 'colossoil' | 'krilowatt' | 'voodoll' | 'voodoom' | 'scratchet' | 'tomohawk' | 'necturine' | 'necturna' | 'mollux' | 'cupra' | 'argalis' | 'aurumoth' | 'brattler' | 'malaconda' | 'cawdet' | 'cawmodore' | 'volkritter' | 'volkraken' | 'snugglow' | 'plasmanta' | 'floatoy' | 'caimanoe' | 'naviathan' | 'crucibelle' | 'crucibellemega' | 'pluffle' | 'kerfluffle' | 'pajantom' | 'mumbao' | 'jumbao' | 'fawnifer' | 'electrelk' | 'caribolt' | 'smogecko' | 'smoguana' | 'smokomodo' | 'swirlpool' | 'coribalis' | 'snaelstrom' | 'justyke' | 'equilibra' | 'solotl' | 'astrolotl' | 'miasmite' | 'miasmaw' | 'chromera' | 'nohface' | 'monohm' | 'duohm' | 'dorsoil' | 'protowatt' | 'venomicon' | 'venomiconepilogue' | 'saharascal' | 'saharaja' | 'ababo' | 'scattervein' | 'hemogoblin' | 'cresceidon' | 'missingno' | 'm00' | 'bulbasaur' | 'ivysaur' | 'venusaur' | 'venusaurgmax' | 'venusaurmega' | 'charmander' | 'charmeleon' | 'charizard' | 'charizardmegax' | 'charizardmegay' | 'charizardgmax' | 'squirtle' | 'wartortle' | 'blastoise' | 'blastoisegmax' | 'blastoisemega' | 'caterpie' | 'metapod' | 'butterfree' | 'butterfreegmax' | 'weedle' | 'kakuna' | 'beedrill' | 'beedrillmega' | 'pidgey' | 'pidgeotto' | 'pidgeot' | 'pidgeotmega' | 'rattata' | 'rattataalola' | 'raticate' | 'raticatealola' | 'raticatealolatotem' | 'spearow' | 'fearow' | 'ekans' | 'arbok' | 'pikachu' | 'pikachugmax' | 'pikachucosplay' | 'pikachurockstar' | 'pikachubelle' | 'pikachupopstar' | 'pikachuphd' | 'pikachulibre' | 'pikachuoriginal' | 'pikachuhoenn' | 'pikachusinnoh' | 'pikachuunova' | 'pikachukalos' | 'pikachualola' | 'pikachupartner' | 'pikachustarter' | 'pikachuworld' | 'raichu' | 'raichualola' | 'sandshrew' | 'sandshrewalola' | 'sandslash' | 'sandslashalola' | 'nidoranf' | 'nidorina' | 'nidoqueen' | 'nidoranm' | 'nidorino' | 'nidoking' | 'clefairy' | 'clefable' | 'vulpix' | 'vulpixalola' | 'ninetales' | 'ninetalesalola' | 'jigglypuff' | 'wigglytuff' | 'zubat' | 'golbat' | 'oddish' | 'gloom' | 'vileplume' | 'paras' | 'parasect' | 'venonat' | 'venomoth' | 'diglett' | 'diglettalola' | 'dugtrio' | 'dugtrioalola' | 'meowth' | 'meowthalola' | 'meowthgalar' | 'meowthgmax' | 'persian' | 'persianalola' | 'psyduck' | 'golduck' | 'mankey' | 'primeape' | 'growlithe' | 'growlithehisui' | 'arcanine' | 'arcaninehisui' | 'poliwag' | 'poliwhirl' | 'poliwrath' | 'abra' | 'kadabra' | 'alakazam' | 'alakazammega' | 'machop' | 'machoke' | 'machamp' | 'machampgmax' | 'bellsprout' | 'weepinbell' | 'victreebel' | 'tentacool' | 'tentacruel' | 'geodude' | 'geodudealola' | 'graveler' | 'graveleralola' | 'golem' | 'golemalola' | 'ponyta' | 'ponytagalar' | 'rapidash' | 'rapidashgalar' | 'slowpoke' | 'slowpokegalar' | 'slowbro' | 'slowbrogalar' | 'slowbromega' | 'magnemite' | 'magneton' | 'farfetchd' | 'farfetchdgalar' | 'doduo' | 'dodrio' | 'seel' | 'dewgong' | 'grimer' | 'grimeralola' | 'muk' | 'mukalola' | 'shellder' | 'cloyster' | 'gastly' | 'haunter' | 'gengar' | 'gengarmega' | 'gengargmax' | 'onix' | 'drowzee' | 'hypno' | 'krabby' | 'kingler' | 'kinglergmax' | 'voltorb' | 'voltorbhisui' | 'electrode' | 'electrodehisui' | 'exeggcute' | 'exeggutor' | 'exeggutoralola' | 'cubone' | 'marowak' | 'marowakalola' | 'marowakalolatotem' | 'hitmonlee' | 'hitmonchan' | 'lickitung' | 'koffing' | 'weezing' | 'weezinggalar' | 'rhyhorn' | 'rhydon' | 'chansey' | 'tangela' | 'kangaskhan' | 'kangaskhanmega' | 'horsea' | 'seadra' | 'goldeen' | 'seaking' | 'staryu' | 'starmie' | 'mrmime' | 'mrmimegalar' | 'scyther' | 'jynx' | 'electabuzz' | 'magmar' | 'pinsir' | 'pinsirmega' | 'tauros' | 'taurospaldeacombat' | 'taurospaldeablaze' | 'taurospaldeaaqua' | 'magikarp' | 'gyarados' | 'gyaradosmega' | 'lapras' | 'laprasgmax' | 'ditto' | 'eevee' | 'eeveestarter' | 'eeveegmax' | 'vaporeon' | 'jolteon' | 'flareon' | 'porygon' | 'omanyte' | 'omastar' | 'kabuto' | 'kabutops' | 'aerodactyl' | 'aerodactylmega' | 'snorlax' | 'snorlaxgmax' | 'articuno' | 'articunogalar' | 'zapdos' | 'zapdosgalar' | 'moltres' | 'moltresgalar' | 'dratini' | 'dragonair' | 'dragonite' | 'mewtwo' | 'mewtwomegax' | 'mewtwomegay' | 'mew' | 'chikorita' | 'bayleef' | 'meganium' | 'cyndaquil' | 'quilava' | 'typhlosion' | 'typhlosionhisui' | 'totodile' | 'croconaw' | 'feraligatr' | 'sentret' | 'furret' | 'hoothoot' | 'noctowl' | 'ledyba' | 'ledian' | 'spinarak' | 'ariados' | 'crobat' | 'chinchou' | 'lanturn' | 'pichu' | 'pichuspikyeared' | 'cleffa' | 'igglybuff' | 'togepi' | 'togetic' | 'natu' | 'xatu' | 'mareep' | 'flaaffy' | 'ampharos' | 'ampharosmega' | 'bellossom' | 'marill' | 'azumarill' | 'sudowoodo' | 'politoed' | 'hoppip' | 'skiploom' | 'jumpluff' | 'aipom' | 'sunkern' | 'sunflora' | 'yanma' | 'wooper' | 'wooperpaldea' | 'quagsire' | 'espeon' | 'umbreon' | 'murkrow' | 'slowking' | 'slowkinggalar' | 'misdreavus' | 'unown' | 'wobbuffet' | 'girafarig' | 'pineco' | 'forretress' | 'dunsparce' | 'gligar' | 'steelix' | 'steelixmega' | 'snubbull' | 'granbull' | 'qwilfish' | 'qwilfishhisui' | 'scizor' | 'scizormega' | 'shuckle' | 'heracross' | 'heracrossmega' | 'sneasel' | 'sneaselhisui' | 'teddiursa' | 'ursaring' | 'slugma' | 'magcargo' | 'swinub' | 'piloswine' | 'corsola' | 'corsolagalar' | 'remoraid' | 'octillery' | 'delibird' | 'mantine' | 'skarmory' | 'houndour' | 'houndoom' | 'houndoommega' | 'kingdra' | 'phanpy' | 'donphan' | 'porygon2' | 'stantler' | 'smeargle' | 'tyrogue' | 'hitmontop' | 'smoochum' | 'elekid' | 'magby' | 'miltank' | 'blissey' | 'raikou' | 'entei' | 'suicune' | 'larvitar' | 'pupitar' | 'tyranitar' | 'tyranitarmega' | 'lugia' | 'hooh' | 'celebi' | 'treecko' | 'grovyle' | 'sceptile' | 'sceptilemega' | 'torchic' | 'combusken' | 'blaziken' | 'blazikenmega' | 'mudkip' | 'marshtomp' | 'swampert' | 'swampertmega' | 'poochyena' | 'mightyena' | 'zigzagoon' | 'zigzagoongalar' | 'linoone' | 'linoonegalar' | 'wurmple' | 'silcoon' | 'beautifly' | 'cascoon' | 'dustox' | 'lotad' | 'lombre' | 'ludicolo' | 'seedot' | 'nuzleaf' | 'shiftry' | 'taillow' | 'swellow' | 'wingull' | 'pelipper' | 'ralts' | 'kirlia' | 'gardevoir' | 'gardevoirmega' | 'surskit' | 'masquerain' | 'shroomish' | 'breloom' | 'slakoth' | 'vigoroth' | 'slaking' | 'nincada' | 'ninjask' | 'shedinja' | 'whismur' | 'loudred' | 'exploud' | 'makuhita' | 'hariyama' | 'azurill' | 'nosepass' | 'skitty' | 'delcatty' | 'sableye' | 'sableyemega' | 'mawile' | 'mawilemega' | 'aron' | 'lairon' | 'aggron' | 'aggronmega' | 'meditite' | 'medicham' | 'medichammega' | 'electrike' | 'manectric' | 'manectricmega' | 'plusle' | 'minun' | 'volbeat' | 'illumise' | 'roselia' | 'gulpin' | 'swalot' | 'carvanha' | 'sharpedo' | 'sharpedomega' | 'wailmer' | 'wailord' | 'numel' | 'camerupt' | 'cameruptmega' | 'torkoal' | 'spoink' | 'grumpig' | 'spinda' | 'trapinch' | 'vibrava' | 'flygon' | 'cacnea' | 'cacturne' | 'swablu' | 'altaria' | 'altariamega' | 'zangoose' | 'seviper' | 'lunatone' | 'solrock' | 'barboach' | 'whiscash' | 'corphish' | 'crawdaunt' | 'baltoy' | 'claydol' | 'lileep' | 'cradily' | 'anorith' | 'armaldo' | 'feebas' | 'milotic' | 'castform' | 'castformsunny' | 'castformrainy' | 'castformsnowy' | 'kecleon' | 'shuppet' | 'banette' | 'banettemega' | 'duskull' | 'dusclops' | 'tropius' | 'chimecho' | 'absol' | 'absolmega' | 'wynaut' | 'snorunt' | 'glalie' | 'glaliemega' | 'spheal' | 'sealeo' | 'walrein' | 'clamperl' | 'huntail' | 'gorebyss' | 'relicanth' | 'luvdisc' | 'bagon' | 'shelgon' | 'salamence' | 'salamencemega' | 'beldum' | 'metang' | 'metagross' | 'metagrossmega' | 'regirock' | 'regice' | 'registeel' | 'latias' | 'latiasmega' | 'latios' | 'latiosmega' | 'kyogre' | 'kyogreprimal' | 'groudon' | 'groudonprimal' | 'rayquaza' | 'rayquazamega' | 'jirachi' | 'deoxys' | 'deoxysattack' | 'deoxysdefense' | 'deoxysspeed' | 'turtwig' | 'grotle' | 'torterra' | 'chimchar' | 'monferno' | 'infernape' | 'piplup' | 'prinplup' | 'empoleon' | 'starly' | 'staravia' | 'staraptor' | 'bidoof' | 'bibarel' | 'kricketot' | 'kricketune' | 'shinx' | 'luxio' | 'luxray' | 'budew' | 'roserade' | 'cranidos' | 'rampardos' | 'shieldon' | 'bastiodon' | 'burmy' | 'wormadam' | 'wormadamsandy' | 'wormadamtrash' | 'mothim' | 'combee' | 'vespiquen' | 'pachirisu' | 'buizel' | 'floatzel' | 'cherubi' | 'cherrim' | 'cherrimsunshine' | 'shellos' | 'gastrodon' | 'ambipom' | 'drifloon' | 'drifblim' | 'buneary' | 'lopunny' | 'lopunnymega' | 'mismagius' | 'honchkrow' | 'glameow' | 'purugly' | 'chingling' | 'stunky' | 'skuntank' | 'bronzor' | 'bronzong' | 'bonsly' | 'mimejr' | 'happiny' | 'chatot' | 'spiritomb' | 'gible' | 'gabite' | 'garchomp' | 'garchompmega' | 'munchlax' | 'riolu' | 'lucario' | 'lucariomega' | 'hippopotas' | 'hippowdon' | 'skorupi' | 'drapion' | 'croagunk' | 'toxicroak' | 'carnivine' | 'finneon' | 'lumineon' | 'mantyke' | 'snover' | 'abomasnow' | 'abomasnowmega' | 'weavile' | 'magnezone' | 'lickilicky' | 'rhyperior' | 'tangrowth' | 'electivire' | 'magmortar' | 'togekiss' | 'yanmega' | 'leafeon' | 'glaceon' | 'gliscor' | 'mamoswine' | 'porygonz' | 'gallade' | 'gallademega' | 'probopass' | 'dusknoir' | 'froslass' | 'rotom' | 'rotomheat' | 'rotomwash' | 'rotomfrost' | 'rotomfan' | 'rotommow' | 'uxie' | 'mesprit' | 'azelf' | 'dialga' | 'dialgaorigin' | 'palkia' | 'palkiaorigin' | 'heatran' | 'regigigas' | 'giratina' | 'giratinaorigin' | 'cresselia' | 'phione' | 'manaphy' | 'darkrai' | 'shaymin' | 'shayminsky' | 'arceus' | 'arceusbug' | 'arceusdark' | 'arceusdragon' | 'arceuselectric' | 'arceusfairy' | 'arceusfighting' | 'arceusfire' | 'arceusflying' | 'arceusghost' | 'arceusgrass' | 'arceusground' | 'arceusice' | 'arceuspoison' | 'arceuspsychic' | 'arceusrock' | 'arceussteel' | 'arceuswater' | 'arceuslegend' | 'victini' | 'snivy' | 'servine' | 'serperior' | 'tepig' | 'pignite' | 'emboar' | 'oshawott' | 'dewott' | 'samurott' | 'samurotthisui' | 'patrat' | 'watchog' | 'lillipup' | 'herdier' | 'stoutland' | 'purrloin' | 'liepard' | 'pansage' | 'simisage' | 'pansear' | 'simisear' | 'panpour' | 'simipour' | 'munna' | 'musharna' | 'pidove' | 'tranquill' | 'unfezant' | 'blitzle' | 'zebstrika' | 'roggenrola' | 'boldore' | 'gigalith' | 'woobat' | 'swoobat' | 'drilbur' | 'excadrill' | 'audino' | 'audinomega' | 'timburr' | 'gurdurr' | 'conkeldurr' | 'tympole' | 'palpitoad' | 'seismitoad' | 'throh' | 'sawk' | 'sewaddle' | 'swadloon' | 'leavanny' | 'venipede' | 'whirlipede' | 'scolipede' | 'cottonee' | 'whimsicott' | 'petilil' | 'lilligant' | 'lilliganthisui' | 'basculin' | 'basculinbluestriped' | 'basculinwhitestriped' | 'sandile' | 'krokorok' | 'krookodile' | 'darumaka' | 'darumakagalar' | 'darmanitan' | 'darmanitangalar' | 'darmanitanzen' | 'darmanitangalarzen' | 'maractus' | 'dwebble' | 'crustle' | 'scraggy' | 'scrafty' | 'sigilyph' | 'yamask' | 'yamaskgalar' | 'cofagrigus' | 'tirtouga' | 'carracosta' | 'archen' | 'archeops' | 'trubbish' | 'garbodor' | 'garbodorgmax' | 'zorua' | 'zoruahisui' | 'zoroark' | 'zoroarkhisui' | 'minccino' | 'cinccino' | 'gothita' | 'gothorita' | 'gothitelle' | 'solosis' | 'duosion' | 'reuniclus' | 'ducklett' | 'swanna' | 'vanillite' | 'vanillish' | 'vanilluxe' | 'deerling' | 'sawsbuck' | 'emolga' | 'karrablast' | 'escavalier' | 'foongus' | 'amoonguss' | 'frillish' | 'frillishfemale' | 'jellicent' | 'jellicentfemale' | 'alomomola' | 'joltik' | 'galvantula' | 'ferroseed' | 'ferrothorn' | 'klink' | 'klang' | 'klinklang' | 'tynamo' | 'eelektrik' | 'eelektross' | 'elgyem' | 'beheeyem' | 'litwick' | 'lampent' | 'chandelure' | 'axew' | 'fraxure' | 'haxorus' | 'cubchoo' | 'beartic' | 'cryogonal' | 'shelmet' | 'accelgor' | 'stunfisk' | 'stunfiskgalar' | 'mienfoo' | 'mienshao' | 'druddigon' | 'golett' | 'golurk' | 'pawniard' | 'bisharp' | 'bouffalant' | 'rufflet' | 'braviary' | 'braviaryhisui' | 'vullaby' | 'mandibuzz' | 'heatmor' | 'durant' | 'deino' | 'zweilous' | 'hydreigon' | 'larvesta' | 'volcarona' | 'cobalion' | 'terrakion' | 'virizion' | 'tornadus' | 'tornadustherian' | 'thundurus' | 'thundurustherian' | 'reshiram' | 'zekrom' | 'landorus' | 'landorustherian' | 'kyurem' | 'kyuremblack' | 'kyuremwhite' | 'keldeo' | 'keldeoresolute' | 'meloetta' | 'meloettapirouette' | 'genesect' | 'genesectdouse' | 'genesectshock' | 'genesectburn' | 'genesectchill' | 'chespin' | 'quilladin' | 'chesnaught' | 'fennekin' | 'braixen' | 'delphox' | 'froakie' | 'frogadier' | 'greninja' | 'greninjaash' | 'bunnelby' | 'diggersby' | 'fletchling' | 'fletchinder' | 'talonflame' | 'scatterbug' | 'spewpa' | 'vivillon' | 'vivillonfancy' | 'vivillonpokeball' | 'litleo' | 'pyroar' | 'flabebe' | 'floette' | 'floetteeternal' | 'florges' | 'skiddo' | 'gogoat' | 'pancham' | 'pangoro' | 'furfrou' | 'espurr' | 'meowstic' | 'meowsticf' | 'honedge' | 'doublade' | 'aegislash' | 'aegislashblade' | 'spritzee' | 'aromatisse' | 'swirlix' | 'slurpuff' | 'inkay' | 'malamar' | 'binacle' | 'barbaracle' | 'skrelp' | 'dragalge' | 'clauncher' | 'clawitzer' | 'helioptile' | 'heliolisk' | 'tyrunt' | 'tyrantrum' | 'amaura' | 'aurorus' | 'sylveon' | 'hawlucha' | 'dedenne' | 'carbink' | 'goomy' | 'sliggoo' | 'sliggoohisui' | 'goodra' | 'goodrahisui' | 'klefki' | 'phantump' | 'trevenant' | 'pumpkaboo' | 'pumpkaboosmall' | 'pumpkaboolarge' | 'pumpkaboosuper' | 'gourgeist' | 'gourgeistsmall' | 'gourgeistlarge' | 'gourgeistsuper' | 'bergmite' | 'avalugg' | 'avalugghisui' | 'noibat' | 'noivern' | 'xerneas' | 'xerneasneutral' | 'yveltal' | 'zygarde' | 'zygarde10' | 'zygardecomplete' | 'diancie' | 'dianciemega' | 'hoopa' | 'hoopaunbound' | 'volcanion' | 'rowlet' | 'dartrix' | 'decidueye' | 'decidueyehisui' | 'litten' | 'torracat' | 'incineroar' | 'popplio' | 'brionne' | 'primarina' | 'pikipek' | 'trumbeak' | 'toucannon' | 'yungoos' | 'gumshoos' | 'gumshoostotem' | 'grubbin' | 'charjabug' | 'vikavolt' | 'vikavolttotem' | 'crabrawler' | 'crabominable' | 'oricorio' | 'oricoriopompom' | 'oricoriopau' | 'oricoriosensu' | 'cutiefly' | 'ribombee' | 'ribombeetotem' | 'rockruff' | 'lycanroc' | 'lycanrocmidnight' | 'lycanrocdusk' | 'wishiwashi' | 'wishiwashischool' | 'mareanie' | 'toxapex' | 'mudbray' | 'mudsdale' | 'dewpider' | 'araquanid' | 'araquanidtotem' | 'fomantis' | 'lurantis' | 'lurantistotem' | 'morelull' | 'shiinotic' | 'salandit' | 'salazzle' | 'salazzletotem' | 'stufful' | 'bewear' | 'bounsweet' | 'steenee' | 'tsareena' | 'comfey' | 'oranguru' | 'passimian' | 'wimpod' | 'golisopod' | 'sandygast' | 'palossand' | 'pyukumuku' | 'typenull' | 'silvally' | 'silvallybug' | 'silvallydark' | 'silvallydragon' | 'silvallyelectric' | 'silvallyfairy' | 'silvallyfighting' | 'silvallyfire' | 'silvallyflying' | 'silvallyghost' | 'silvallygrass' | 'silvallyground' | 'silvallyice' | 'silvallypoison' | 'silvallypsychic' | 'silvallyrock' | 'silvallysteel' | 'silvallywater' | 'minior' | 'miniormeteor' | 'komala' | 'turtonator' | 'togedemaru' | 'togedemarutotem' | 'mimikyu' | 'mimikyubusted' | 'mimikyutotem' | 'mimikyubustedtotem' | 'bruxish' | 'drampa' | 'dhelmise' | 'jangmoo' | 'hakamoo' | 'kommoo' | 'kommoototem' | 'tapukoko' | 'tapulele' | 'tapubulu' | 'tapufini' | 'cosmog' | 'cosmoem' | 'solgaleo' | 'lunala' | 'nihilego' | 'buzzwole' | 'pheromosa' | 'xurkitree' | 'celesteela' | 'kartana' | 'guzzlord' | 'necrozma' | 'necrozmaduskmane' | 'necrozmadawnwings' | 'necrozmaultra' | 'magearna' | 'magearnaoriginal' | 'marshadow' | 'poipole' | 'naganadel' | 'stakataka' | 'blacephalon' | 'zeraora' | 'meltan' | 'melmetal' | 'melmetalgmax' | 'grookey' | 'thwackey' | 'rillaboom' | 'rillaboomgmax' | 'scorbunny' | 'raboot' | 'cinderace' | 'cinderacegmax' | 'sobble' | 'drizzile' | 'inteleon' | 'inteleongmax' | 'skwovet' | 'greedent' | 'rookidee' | 'corvisquire' | 'corviknight' | 'corviknightgmax' | 'blipbug' | 'dottler' | 'orbeetle' | 'orbeetlegmax' | 'nickit' | 'thievul' | 'gossifleur' | 'eldegoss' | 'wooloo' | 'dubwool' | 'chewtle' | 'drednaw' | 'drednawgmax' | 'yamper' | 'boltund' | 'rolycoly' | 'carkol' | 'coalossal' | 'coalossalgmax' | 'applin' | 'flapple' | 'flapplegmax' | 'appletun' | 'appletungmax' | 'silicobra' | 'sandaconda' | 'sandacondagmax' | 'cramorant' | 'cramorantgulping' | 'cramorantgorging' | 'arrokuda' | 'barraskewda' | 'toxel' | 'toxtricity' | 'toxtricitylowkey' | 'toxtricitygmax' | 'toxtricitylowkeygmax' | 'sizzlipede' | 'centiskorch' | 'centiskorchgmax' | 'clobbopus' | 'grapploct' | 'sinistea' | 'sinisteaantique' | 'polteageist' | 'polteageistantique' | 'hatenna' | 'hattrem' | 'hatterene' | 'hatterenegmax' | 'impidimp' | 'morgrem' | 'grimmsnarl' | 'grimmsnarlgmax' | 'obstagoon' | 'perrserker' | 'cursola' | 'sirfetchd' | 'mrrime' | 'runerigus' | 'milcery' | 'alcremie' | 'alcremiegmax' | 'falinks' | 'pincurchin' | 'snom' | 'frosmoth' | 'stonjourner' | 'eiscue' | 'eiscuenoice' | 'indeedee' | 'indeedeef' | 'morpeko' | 'morpekohangry' | 'cufant' | 'copperajah' | 'copperajahgmax' | 'dracozolt' | 'arctozolt' | 'dracovish' | 'arctovish' | 'duraludon' | 'duraludongmax' | 'dreepy' | 'drakloak' | 'dragapult' | 'zacian' | 'zaciancrowned' | 'zamazenta' | 'zamazentacrowned' | 'eternatus' | 'eternatuseternamax' | 'kubfu' | 'urshifu' | 'urshifurapidstrike' | 'urshifugmax' | 'urshifurapidstrikegmax' | 'zarude' | 'zarudedada' | 'regieleki' | 'regidrago' | 'glastrier' | 'spectrier' | 'calyrex' | 'calyrexice' | 'calyrexshadow' | 'wyrdeer' | 'kleavor' | 'ursaluna' | 'ursalunabloodmoon' | 'basculegion' | 'basculegionf' | 'sneasler' | 'overqwil' | 'enamorus' | 'enamorustherian' | 'sprigatito' | 'floragato' | 'meowscarada' | 'fuecoco' | 'crocalor' | 'skeledirge' | 'quaxly' | 'quaxwell' | 'quaquaval' | 'lechonk' | 'oinkologne' | 'oinkolognef' | 'tarountula' | 'spidops' | 'nymble' | 'lokix' | 'pawmi' | 'pawmo' | 'pawmot' | 'tandemaus' | 'maushold' | 'mausholdfour' | 'fidough' | 'dachsbun' | 'smoliv' | 'dolliv' | 'arboliva' | 'squawkabilly' | 'squawkabillyblue' | 'squawkabillyyellow' | 'squawkabillywhite' | 'nacli' | 'naclstack' | 'garganacl' | 'charcadet' | 'armarouge' | 'ceruledge' | 'tadbulb' | 'bellibolt' | 'wattrel' | 'kilowattrel' | 'maschiff' | 'mabosstiff' | 'shroodle' | 'grafaiai' | 'bramblin' | 'brambleghast' | 'toedscool' | 'toedscruel' | 'klawf' | 'capsakid' | 'scovillain' | 'rellor' | 'rabsca' | 'flittle' | 'espathra' | 'tinkatink' | 'tinkatuff' | 'tinkaton' | 'wiglett' | 'wugtrio' | 'bombirdier' | 'finizen' | 'palafin' | 'palafinhero' | 'varoom' | 'revavroom' | 'cyclizar' | 'orthworm' | 'glimmet' | 'glimmora' | 'greavard' | 'houndstone' | 'flamigo' | 'cetoddle' | 'cetitan' | 'veluza' | 'dondozo' | 'tatsugiri' | 'annihilape' | 'clodsire' | 'farigiraf' | 'dudunsparce' | 'dudunsparcethreesegment' | 'kingambit' | 'greattusk' | 'screamtail' | 'brutebonnet' | 'fluttermane' | 'slitherwing' | 'sandyshocks' | 'irontreads' | 'ironbundle' | 'ironhands' | 'ironjugulis' | 'ironmoth' | 'ironthorns' | 'frigibax' | 'arctibax' | 'baxcalibur' | 'gimmighoul' | 'gimmighoulroaming' | 'gholdengo' | 'wochien' | 'chienpao' | 'tinglu' | 'chiyu' | 'roaringmoon' | 'ironvaliant' | 'koraidon' | 'miraidon' | 'walkingwake' | 'ironleaves' | 'dipplin' | 'poltchageist' | 'poltchageistartisan' | 'sinistcha' | 'sinistchamasterpiece' | 'okidogi' | 'munkidori' | 'fezandipiti' | 'ogerpon' | 'ogerponcornerstone' | 'ogerponcornerstonetera' | 'ogerponhearthflame' | 'ogerponhearthflametera' | 'ogerponwellspring' | 'ogerponwellspringtera' | 'ogerpontealtera' | 'archaludon' | 'hydrapple' | 'gougingfire' | 'ragingbolt' | 'ironboulder' | 'ironcrown' | 'terapagos' | 'terapagosterastal' | 'terapagosstellar' | 'pecharunt',
       /**
-The form identifier of a Pokémon
-      */
+       * The form identifier of a Pokémon
+       */
       readonly forme: (string | null),
       readonly Pokemon: Pokemon__Pokemon__output_type,
     }>,
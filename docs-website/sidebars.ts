@@ -19,6 +19,7 @@ const sidebars: SidebarsConfig = {
     'loadable-fields',
     'pagination',
     'mutation',
+    'subscription',
     'conditional-fetching',
     'abstract-types',
     'data-driven-dependencies',
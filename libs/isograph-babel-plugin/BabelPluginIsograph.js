@@ -2,13 +2,29 @@
 
 const compileTag = require('./compileTag');
 const cosmiconfig = require('cosmiconfig');
+const { parse: parseToml } = require('smol-toml');
 
+// The config is searched for in the same places, and in the same order, as
+// the compiler searches for it.
 const configExplorer = cosmiconfig('isograph', {
-  searchPlaces: ['isograph.config.json'],
+  searchPlaces: [
+    'isograph.config.json',
+    'package.json',
+    'isograph.config.toml',
+  ],
   loaders: {
     '.json': cosmiconfig.loadJson,
+    '.toml': loadToml,
   },
 });
+
+/**
+ * @param {string} _filepath
+ * @param {string} content
+ */
+function loadToml(_filepath, content) {
+  return parseToml(content);
+}
 /** @type {NonNullable<import("cosmiconfig").CosmiconfigResult>} */
 let IsographConfig;
 const result = configExplorer.searchSync();
@@ -16,7 +32,8 @@ if (result) {
   IsographConfig = result;
 } else {
   throw new Error(
-    'No config found. Do you have a isograph.config.json file somewhere?',
+    'No config found. Do you have a isograph.config.json file, a package.json ' +
+      'file with an "isograph" key, or a isograph.config.toml file, somewhere?',
   );
 }
 
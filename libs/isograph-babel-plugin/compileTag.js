@@ -86,6 +86,39 @@ function getTypeAndField(path) {
   return { keyword, type, field };
 }
 
+/**
+ * If the config contains multiple projects, returns the project whose
+ * project_root contains the file. Otherwise, returns the config.
+ *
+ * @param {NonNullable<import("cosmiconfig").CosmiconfigResult>} config
+ * @param {string} cwd
+ * @param {string} filename
+ * @returns {any}
+ */
+function getProjectConfig(config, cwd, filename) {
+  const projects = config.config['projects'];
+  if (projects == null) {
+    return config.config;
+  }
+  const project = projects.find((/** @type {any} */ project) => {
+    const relativePath = pathModule.relative(
+      pathModule.join(cwd, project['project_root']),
+      filename,
+    );
+    return (
+      !relativePath.startsWith('..') && !pathModule.isAbsolute(relativePath)
+    );
+  });
+  if (project == null) {
+    throw new Error(
+      'BabelPluginIsograph: The file ' +
+        filename +
+        ' is not contained in the project_root of any project in the Isograph config.',
+    );
+  }
+  return project;
+}
+
 /**
  * @param {typeof babel.types} t
  * @param {babel.NodePath<babel.types.CallExpression>} path
@@ -98,11 +131,12 @@ function compileImportStatement(t, path, type, field, artifactType, config) {
   const filename = path.state.filename;
   const folder = pathModule.dirname(filename);
   const cwd = pathModule.dirname(config.filepath);
+  const projectConfig = getProjectConfig(config, cwd, filename);
   const artifactDirectory = pathModule.join(
     cwd,
-    config.config['artifact_directory'] ?? config.config['project_root'],
+    projectConfig['artifact_directory'] ?? projectConfig['project_root'],
   );
-  const module = config.config['options']?.['module'];
+  const module = projectConfig['options']?.['module'];
 
   const fileToArtifactDir = pathModule.relative(folder, artifactDirectory);
   const artifactDirToArtifact = `/__isograph/${type}/${field}/${artifactType}.ts`;
@@ -1,5 +1,5 @@
 import { ParentCache } from '@isograph/react-disposable-state';
-import { IsographEntrypoint } from './entrypoint';
+import { IsographEntrypoint, type IsographOperation } from './entrypoint';
 import {
   FragmentReference,
   Variables,
@@ -53,6 +53,7 @@ export type CacheMap<T> = { [index: string]: ParentCache<T> };
 export type IsographEnvironment = {
   readonly store: IsographStore;
   readonly networkFunction: IsographNetworkFunction;
+  readonly subscriptionNetworkFunction: IsographSubscriptionNetworkFunction | null;
   readonly missingFieldHandler: MissingFieldHandler | null;
   readonly componentCache: FieldCache<React.FC<any>>;
   readonly eagerReaderCache: FieldCache<StartUpdate<any> | undefined>;
@@ -79,11 +80,24 @@ export type MissingFieldHandler = (
   variables: Variables | null,
 ) => Link | undefined;
 
+// If persisted queries are enabled, the network function is passed a
+// PersistedOperation, containing the id of the persisted query, instead of
+// an Operation, containing the query text.
 export type IsographNetworkFunction = (
-  queryText: string,
+  operation: IsographOperation,
   variables: Variables,
 ) => Promise<any>;
 
+// Subscriptions are fetched with this function, which should open a streaming
+// transport (e.g. a websocket), call onNext with each network response, and
+// return a function that closes the transport.
+export type IsographSubscriptionNetworkFunction = (
+  operation: IsographOperation,
+  variables: Variables,
+  onNext: (networkResponse: any) => void,
+  onError: (error: any) => void,
+) => () => void;
+
 export type Link = {
   readonly __link: DataId;
   readonly __typename: TypeName;
@@ -130,6 +144,7 @@ export function createIsographEnvironment(
   networkFunction: IsographNetworkFunction,
   missingFieldHandler?: MissingFieldHandler | null,
   logFunction?: LogFunction | null,
+  subscriptionNetworkFunction?: IsographSubscriptionNetworkFunction | null,
 ): IsographEnvironment {
   logFunction?.({
     kind: 'EnvironmentCreated',
@@ -137,6 +152,7 @@ export function createIsographEnvironment(
   return {
     store,
     networkFunction,
+    subscriptionNetworkFunction: subscriptionNetworkFunction ?? null,
     missingFieldHandler: missingFieldHandler ?? null,
     componentCache: {},
     eagerReaderCache: {},
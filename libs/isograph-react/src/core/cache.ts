@@ -4,7 +4,9 @@ import {
   ParentCache,
 } from '@isograph/react-disposable-state';
 import {
+  getQueryTextOrOperationId,
   IsographEntrypoint,
+  NormalizationDeferredFragment,
   NormalizationInlineFragment,
   NormalizationLinkedField,
   NormalizationScalarField,
@@ -94,7 +96,7 @@ export function getOrCreateCacheForArtifact<
   fetchOptions?: FetchOptions<TClientFieldValue>,
 ): ParentCache<FragmentReference<TReadFromStore, TClientFieldValue>> {
   const cacheKey =
-    entrypoint.networkRequestInfo.queryText +
+    getQueryTextOrOperationId(entrypoint.networkRequestInfo) +
     JSON.stringify(stableCopy(variables));
   const factory = () => {
     const [networkRequest, disposeNetworkRequest] = maybeMakeNetworkRequest(
@@ -428,6 +430,21 @@ function normalizeDataIntoRecord(
           recordHasBeenUpdated || inlineFragmentResultedInChange;
         break;
       }
+      case 'Defer': {
+        const deferredFragmentResultedInChange = normalizeDeferredFragment(
+          environment,
+          normalizationNode,
+          networkResponseParentRecord,
+          targetParentRecord,
+          targetParentRecordLink,
+          variables,
+          nestedRefetchQueries,
+          mutableEncounteredIds,
+        );
+        recordHasBeenUpdated =
+          recordHasBeenUpdated || deferredFragmentResultedInChange;
+        break;
+      }
       default: {
         // Ensure we have covered all variants
         let _: never = normalizationNode;
@@ -608,6 +625,223 @@ function normalizeInlineFragment(
   return false;
 }
 
+/**
+ * Mutate targetParentRecord with a given deferred fragment ast node. The deferred
+ * fragment is only normalized if the network response contains all of its fields
+ * (e.g. if the server ignored @defer). Otherwise, the fields that have not yet
+ * arrived would be stored as missing. Deferred payloads that arrive later are
+ * normalized with normalizeIncrementalPayload.
+ */
+function normalizeDeferredFragment(
+  environment: IsographEnvironment,
+  astNode: NormalizationDeferredFragment,
+  networkResponseParentRecord: NetworkResponseObject,
+  targetParentRecord: StoreRecord,
+  targetParentRecordLink: Link,
+  variables: Variables,
+  nestedRefetchQueries: RefetchQueryNormalizationArtifactWrapper[],
+  mutableEncounteredIds: EncounteredIds,
+): RecordHasBeenUpdated {
+  if (
+    !deferredFragmentHasArrived(astNode.selections, networkResponseParentRecord)
+  ) {
+    return false;
+  }
+  return normalizeDataIntoRecord(
+    environment,
+    astNode.selections,
+    networkResponseParentRecord,
+    targetParentRecord,
+    targetParentRecordLink,
+    variables,
+    nestedRefetchQueries,
+    mutableEncounteredIds,
+  );
+}
+
+function deferredFragmentHasArrived(
+  selections: NormalizationAstNodes,
+  networkResponseParentRecord: NetworkResponseObject,
+): boolean {
+  return selections.every((selection) => {
+    switch (selection.kind) {
+      case 'Scalar':
+      case 'Linked':
+        return (
+          networkResponseParentRecord[getNetworkResponseKey(selection)] !==
+          undefined
+        );
+      case 'InlineFragment':
+        return (
+          networkResponseParentRecord[TYPENAME_FIELD_NAME] !==
+            selection.type ||
+          deferredFragmentHasArrived(
+            selection.selections,
+            networkResponseParentRecord,
+          )
+        );
+      case 'Defer':
+        // Nested deferred fragments may arrive later
+        return true;
+    }
+  });
+}
+
+/**
+ * The payload of a deferred fragment, i.e. an item of the `incremental` array of
+ * a network response. The path is the path of the object containing the deferred
+ * fragment, from the root of the network response.
+ */
+export type IncrementalPayload = {
+  readonly label?: string;
+  readonly path: readonly (string | number)[];
+  readonly data?: NetworkResponseObject | null;
+};
+
+/**
+ * Normalize the payload of a deferred fragment into the record at its path, using
+ * the selections of the deferred fragment with the same label. Returns whether
+ * such a deferred fragment was found.
+ */
+export function normalizeIncrementalPayload(
+  environment: IsographEnvironment,
+  normalizationAst: NormalizationAstNodes,
+  incrementalPayload: IncrementalPayload,
+  variables: Variables,
+  nestedRefetchQueries: RefetchQueryNormalizationArtifactWrapper[],
+  root: Link,
+): boolean {
+  const { label, path, data } = incrementalPayload;
+  if (label == null || data == null) {
+    return false;
+  }
+
+  let selections = normalizationAst;
+  let recordLink = root;
+  let pathIndex = 0;
+  while (pathIndex < path.length) {
+    const networkResponseKey = path[pathIndex++];
+    const linkedField =
+      typeof networkResponseKey === 'string'
+        ? findLinkedField(selections, networkResponseKey, recordLink.__typename)
+        : null;
+    if (linkedField == null) {
+      return false;
+    }
+
+    let value: DataTypeValue =
+      environment.store[recordLink.__typename]?.[recordLink.__link]?.[
+        getParentRecordKey(linkedField, variables)
+      ];
+    while (Array.isArray(value)) {
+      const index = path[pathIndex++];
+      if (typeof index !== 'number') {
+        return false;
+      }
+      value = value[index];
+    }
+
+    const link = getLink(value);
+    if (link == null) {
+      return false;
+    }
+    selections = linkedField.selections;
+    recordLink = link;
+  }
+
+  const deferredFragment = findDeferredFragment(
+    selections,
+    label,
+    recordLink.__typename,
+  );
+  if (deferredFragment == null) {
+    return false;
+  }
+
+  normalizeData(
+    environment,
+    deferredFragment.selections,
+    data,
+    variables,
+    nestedRefetchQueries,
+    recordLink,
+  );
+  return true;
+}
+
+function findLinkedField(
+  selections: NormalizationAstNodes,
+  networkResponseKey: string,
+  typename: TypeName,
+): NormalizationLinkedField | null {
+  for (const selection of selections) {
+    switch (selection.kind) {
+      case 'Scalar':
+        break;
+      case 'Linked':
+        if (getNetworkResponseKey(selection) === networkResponseKey) {
+          return selection;
+        }
+        break;
+      case 'InlineFragment':
+      case 'Defer': {
+        if (
+          selection.kind === 'InlineFragment' &&
+          selection.type !== typename
+        ) {
+          break;
+        }
+        const linkedField = findLinkedField(
+          selection.selections,
+          networkResponseKey,
+          typename,
+        );
+        if (linkedField != null) {
+          return linkedField;
+        }
+        break;
+      }
+    }
+  }
+  return null;
+}
+
+function findDeferredFragment(
+  selections: NormalizationAstNodes,
+  label: string,
+  typename: TypeName,
+): NormalizationDeferredFragment | null {
+  for (const selection of selections) {
+    switch (selection.kind) {
+      case 'Scalar':
+      case 'Linked':
+        break;
+      case 'InlineFragment':
+      case 'Defer': {
+        if (selection.kind === 'Defer' && selection.label === label) {
+          return selection;
+        }
+        if (
+          selection.kind === 'InlineFragment' &&
+          selection.type !== typename
+        ) {
+          break;
+        }
+        const deferredFragment = findDeferredFragment(
+          selection.selections,
+          label,
+          typename,
+        );
+        if (deferredFragment != null) {
+          return deferredFragment;
+        }
+        break;
+      }
+    }
+  }
+  return null;
+}
+
 function dataIdsAreTheSame(
   existingValue: DataTypeValue,
   newDataIds: (Link | null)[],
@@ -196,6 +196,21 @@ function checkFromRecord(
 
         continue normalizationAstLoop;
       }
+      case 'Defer': {
+        const result = checkFromRecord(
+          environment,
+          normalizationAstNode.selections,
+          variables,
+          record,
+          recordLink,
+        );
+
+        if (result.kind === 'MissingData') {
+          return result;
+        }
+
+        continue normalizationAstLoop;
+      }
       default: {
         let _: never = normalizationAstNode;
         _;
@@ -17,11 +17,59 @@ export type ReaderWithRefetchQueries<
   readonly nestedRefetchQueries: RefetchQueryNormalizationArtifactWrapper[];
 };
 
-export type NetworkRequestInfo<TNormalizationAst> = {
-  readonly kind: 'NetworkRequestInfo';
-  readonly queryText: string;
-  readonly normalizationAst: TNormalizationAst;
-};
+export type NetworkRequestInfo<TNormalizationAst> =
+  | {
+      readonly kind: 'NetworkRequestInfo';
+      readonly queryText: string;
+      readonly normalizationAst: TNormalizationAst;
+    }
+  | {
+      // Generated instead of the above if persisted queries are enabled
+      readonly kind: 'PersistedNetworkRequestInfo';
+      readonly operationId: string;
+      readonly normalizationAst: TNormalizationAst;
+    };
+
+/**
+ * The query text or, if persisted queries are enabled, the id of the
+ * persisted query.
+ */
+export function getQueryTextOrOperationId(
+  networkRequestInfo: NetworkRequestInfo<unknown>,
+): string {
+  return networkRequestInfo.kind === 'PersistedNetworkRequestInfo'
+    ? networkRequestInfo.operationId
+    : networkRequestInfo.queryText;
+}
+
+/**
+ * The operation that is passed to the network function. If persisted queries
+ * are enabled, this contains the id of the persisted query instead of the
+ * query text.
+ */
+export type IsographOperation =
+  | {
+      readonly kind: 'Operation';
+      readonly queryText: string;
+    }
+  | {
+      readonly kind: 'PersistedOperation';
+      readonly operationId: string;
+    };
+
+export function getOperation(
+  networkRequestInfo: NetworkRequestInfo<unknown>,
+): IsographOperation {
+  return networkRequestInfo.kind === 'PersistedNetworkRequestInfo'
+    ? {
+        kind: 'PersistedOperation',
+        operationId: networkRequestInfo.operationId,
+      }
+    : { kind: 'Operation', queryText: networkRequestInfo.queryText };
+}
+
+export type OperationKind = 'Query' | 'Mutation' | 'Subscription';
+
 // This type should be treated as an opaque type.
 export type IsographEntrypoint<
   TReadFromStore extends UnknownTReadFromStore,
@@ -29,12 +77,21 @@ export type IsographEntrypoint<
   TNormalizationAst extends NormalizationAst | NormalizationAstLoader,
 > = {
   readonly kind: 'Entrypoint';
+  readonly operationKind: OperationKind;
   readonly networkRequestInfo: NetworkRequestInfo<TNormalizationAst>;
   readonly readerWithRefetchQueries: ReaderWithRefetchQueries<
     TReadFromStore,
     TClientFieldValue
   >;
   readonly concreteType: TypeName;
+  // Present if fields are selected with @loadable(defer: true). Maps the label of
+  // each deferred fragment to a loader for the reader of the field whose
+  // selections it contains.
+  readonly deferredFragmentReaders?: {
+    readonly [label: string]: () => Promise<
+      TopLevelReaderArtifact<any, any, any>
+    >;
+  };
 };
 
 export type IsographEntrypointLoader<
@@ -51,7 +108,8 @@ export type IsographEntrypointLoader<
 export type NormalizationAstNode =
   | NormalizationScalarField
   | NormalizationLinkedField
-  | NormalizationInlineFragment;
+  | NormalizationInlineFragment
+  | NormalizationDeferredFragment;
 
 export type NormalizationAstNodes = ReadonlyArray<NormalizationAstNode>;
 
@@ -85,6 +143,17 @@ export type NormalizationInlineFragment = {
   readonly selections: NormalizationAstNodes;
 };
 
+/**
+ * The selections of a field selected with @loadable(defer: true). These are
+ * selected in the parent query under `... @defer(label: "Type__field")`, and are
+ * missing from the network response until the deferred payload has arrived.
+ */
+export type NormalizationDeferredFragment = {
+  readonly kind: 'Defer';
+  readonly label: string;
+  readonly selections: NormalizationAstNodes;
+};
+
 // This is more like an entrypoint, but one specifically for a refetch query/mutation
 export type RefetchQueryNormalizationArtifact = {
   readonly kind: 'RefetchQuery';
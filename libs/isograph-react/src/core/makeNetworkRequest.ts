@@ -1,8 +1,9 @@
 import { ItemCleanupPair } from '@isograph/disposable-types';
-import { normalizeData } from './cache';
+import { normalizeData, normalizeIncrementalPayload } from './cache';
 import { check, DEFAULT_SHOULD_FETCH_VALUE, FetchOptions } from './check';
 import { getOrCreateCachedComponent } from './componentCache';
 import {
+  getOperation,
   IsographEntrypoint,
   RefetchQueryNormalizationArtifact,
   type NormalizationAst,
@@ -120,18 +121,132 @@ export function makeNetworkRequest<
     networkRequestId: myNetworkRequestId,
   }));
 
+  if (
+    artifact.kind === 'Entrypoint' &&
+    artifact.operationKind === 'Subscription'
+  ) {
+    return makeSubscriptionNetworkRequest(
+      environment,
+      artifact,
+      variables,
+      myNetworkRequestId,
+      fetchOptions,
+    );
+  }
+
   let status: NetworkRequestStatus = {
     kind: 'UndisposedIncomplete',
   };
-  // This should be an observable, not a promise
-  const promise = Promise.all([
-    environment.networkFunction(
-      artifact.networkRequestInfo.queryText,
-      variables,
-    ),
-    loadNormalizationAst(artifact.networkRequestInfo.normalizationAst),
-  ])
-    .then(([networkResponse, normalizationAst]) => {
+  const root = { __link: ROOT_ID, __typename: artifact.concreteType };
+  const nestedRefetchQueries =
+    artifact.kind === 'Entrypoint'
+      ? artifact.readerWithRefetchQueries.nestedRefetchQueries
+      : [];
+
+  const normalizeIncrementalPayloads = (
+    networkResponse: any,
+    normalizationAst: NormalizationAst,
+  ) => {
+    for (const incrementalPayload of networkResponse.incremental ?? []) {
+      const wasNormalized = normalizeIncrementalPayload(
+        environment,
+        normalizationAst.selections,
+        incrementalPayload,
+        variables,
+        nestedRefetchQueries,
+        root,
+      );
+      const label: string | undefined = incrementalPayload.label;
+      if (wasNormalized && label != null && artifact.kind === 'Entrypoint') {
+        // Start loading the reader of the deferred field, now that its data
+        // has arrived.
+        artifact.deferredFragmentReaders?.[label]?.().catch(() => {});
+      }
+    }
+  };
+
+  const normalizeInitialNetworkResponse = (
+    networkResponse: any,
+    normalizationAst: NormalizationAst,
+  ) => {
+    logMessage(environment, () => ({
+      kind: 'ReceivedNetworkResponse',
+      networkResponse,
+      networkRequestId: myNetworkRequestId,
+    }));
+
+    if (networkResponse.errors != null) {
+      try {
+        fetchOptions?.onError?.();
+      } catch {}
+      throw new Error('GraphQL network response had errors', {
+        cause: networkResponse,
+      });
+    }
+
+    if (status.kind === 'UndisposedIncomplete') {
+      normalizeData(
+        environment,
+        normalizationAst.selections,
+        networkResponse.data ?? {},
+        variables,
+        nestedRefetchQueries,
+        root,
+      );
+      normalizeIncrementalPayloads(networkResponse, normalizationAst);
+      const retainedQuery = {
+        normalizationAst: normalizationAst.selections,
+        variables,
+        root,
+      };
+      status = {
+        kind: 'UndisposedComplete',
+        retainedQuery,
+      };
+      retainQuery(environment, retainedQuery);
+    }
+
+    const onComplete = fetchOptions?.onComplete;
+    if (onComplete != null) {
+      let data = readDataForOnComplete(artifact, environment, root, variables);
+
+      try {
+        // @ts-expect-error this problem will be fixed when we remove RefetchQueryNormalizationArtifact
+        // (or we can fix this by having a single param of type { kind: 'Entrypoint', entrypoint,
+        // fetchOptions: FetchOptions<TReadFromStore> } | { kind: 'RefetchQuery', refetchQuery,
+        // fetchOptions: FetchOptions<void> }).
+        onComplete(data);
+      } catch {}
+    }
+  };
+
+  // The returned promise has already resolved when the deferred payloads arrive,
+  // so errors are only reported to onError.
+  const normalizeSubsequentNetworkResponses = (
+    networkResponses: AsyncIterator<any>,
+    normalizationAst: NormalizationAst,
+  ) => {
+    const onError = (error: any) => {
+      logMessage(environment, () => ({
+        kind: 'ReceivedNetworkError',
+        networkRequestId: myNetworkRequestId,
+        error,
+      }));
+      try {
+        fetchOptions?.onError?.();
+      } catch {}
+    };
+
+    networkResponses.next().then((result) => {
+      if (result.done) {
+        return;
+      }
+      if (status.kind !== 'UndisposedComplete') {
+        networkResponses.return?.();
+        return;
+      }
+
+      const networkResponse = result.value;
       logMessage(environment, () => ({
         kind: 'ReceivedNetworkResponse',
         networkResponse,
@@ -139,55 +254,63 @@ export function makeNetworkRequest<
       }));
 
       if (networkResponse.errors != null) {
-        try {
-          fetchOptions?.onError?.();
-        } catch {}
-        throw new Error('GraphQL network response had errors', {
-          cause: networkResponse,
-        });
+        onError(
+          new Error('GraphQL network response had errors', {
+            cause: networkResponse,
+          }),
+        );
+        networkResponses.return?.();
+        return;
       }
 
-      const root = { __link: ROOT_ID, __typename: artifact.concreteType };
-      if (status.kind === 'UndisposedIncomplete') {
-        normalizeData(
-          environment,
-          normalizationAst.selections,
-          networkResponse.data ?? {},
-          variables,
-          artifact.kind === 'Entrypoint'
-            ? artifact.readerWithRefetchQueries.nestedRefetchQueries
-            : [],
-          root,
-        );
-        const retainedQuery = {
-          normalizationAst: normalizationAst.selections,
-          variables,
-          root,
-        };
-        status = {
-          kind: 'UndisposedComplete',
-          retainedQuery,
-        };
-        retainQuery(environment, retainedQuery);
+      normalizeIncrementalPayloads(networkResponse, normalizationAst);
+      if (networkResponse.hasNext !== false) {
+        normalizeSubsequentNetworkResponses(networkResponses, normalizationAst);
       }
+    }, onError);
+  };
 
-      const onComplete = fetchOptions?.onComplete;
-      if (onComplete != null) {
-        let data = readDataForOnComplete(
-          artifact,
-          environment,
-          root,
-          variables,
+  // This should be an observable, not a promise
+  const promise = Promise.all([
+    environment.networkFunction(
+      getOperation(artifact.networkRequestInfo),
+      variables,
+    ),
+    loadNormalizationAst(artifact.networkRequestInfo.normalizationAst),
+  ])
+    .then(([networkResponseOrResponses, normalizationAst]) => {
+      // If fields are selected with @loadable(defer: true), the network function
+      // can resolve with an async iterable of network responses: the initial
+      // response, followed by responses containing the deferred payloads.
+      if (!isAsyncIterable(networkResponseOrResponses)) {
+        normalizeInitialNetworkResponse(
+          networkResponseOrResponses,
+          normalizationAst,
         );
+        return;
+      }
 
+      const networkResponses =
+        networkResponseOrResponses[Symbol.asyncIterator]();
+      return networkResponses.next().then((result) => {
+        if (result.done) {
+          throw new Error(
+            'Expected the network function to return at least one network response.',
+          );
+        }
         try {
-          // @ts-expect-error this problem will be fixed when we remove RefetchQueryNormalizationArtifact
-          // (or we can fix this by having a single param of type { kind: 'Entrypoint', entrypoint,
-          // fetchOptions: FetchOptions<TReadFromStore> } | { kind: 'RefetchQuery', refetchQuery,
-          // fetchOptions: FetchOptions<void> }).
-          onComplete(data);
-        } catch {}
-      }
+          normalizeInitialNetworkResponse(result.value, normalizationAst);
+        } catch (e) {
+          networkResponses.return?.();
+          throw e;
+        }
+        if (result.value.hasNext !== false) {
+          normalizeSubsequentNetworkResponses(
+            networkResponses,
+            normalizationAst,
+          );
+        }
+      });
     })
     .catch((e) => {
       logMessage(environment, () => ({
@@ -223,6 +346,170 @@ export function makeNetworkRequest<
   return response;
 }
 
+/**
+ * Subscriptions are fetched with the environment's subscriptionNetworkFunction.
+ * Each network response is normalized into the store, until the returned
+ * cleanup function is called. The returned promise resolves (and onComplete is
+ * called) when the first network response has been normalized. If a network
+ * response has errors, onError is called and the subscription is unsubscribed.
+ */
+function makeSubscriptionNetworkRequest<
+  TReadFromStore extends UnknownTReadFromStore,
+  TClientFieldValue,
+  TNormalizationAst extends NormalizationAst | NormalizationAstLoader,
+>(
+  environment: IsographEnvironment,
+  artifact: IsographEntrypoint<
+    TReadFromStore,
+    TClientFieldValue,
+    TNormalizationAst
+  >,
+  variables: ExtractParameters<TReadFromStore>,
+  networkRequestId: string,
+  fetchOptions?: FetchOptions<TClientFieldValue>,
+): ItemCleanupPair<PromiseWrapper<void, AnyError>> {
+  const subscriptionNetworkFunction = environment.subscriptionNetworkFunction;
+  if (subscriptionNetworkFunction == null) {
+    throw new Error(
+      'Attempted to fetch a subscription, but no subscriptionNetworkFunction ' +
+        'was passed to createIsographEnvironment.',
+    );
+  }
+
+  let status: NetworkRequestStatus = {
+    kind: 'UndisposedIncomplete',
+  };
+  let unsubscribe: (() => void) | null = null;
+  // Set if a network response had errors before subscriptionNetworkFunction
+  // returned, in which case we must unsubscribe once it returns.
+  let shouldUnsubscribe = false;
+  const root = { __link: ROOT_ID, __typename: artifact.concreteType };
+
+  const onError = (error: any) => {
+    logMessage(environment, () => ({
+      kind: 'ReceivedNetworkError',
+      networkRequestId,
+      error,
+    }));
+    try {
+      fetchOptions?.onError?.();
+    } catch {}
+  };
+
+  const promise = Promise.resolve(
+    loadNormalizationAst(artifact.networkRequestInfo.normalizationAst),
+  ).then(
+    (normalizationAst) =>
+      new Promise<void>((resolve, reject) => {
+        if (status.kind === 'Disposed') {
+          resolve();
+          return;
+        }
+        unsubscribe = subscriptionNetworkFunction(
+          getOperation(artifact.networkRequestInfo),
+          variables,
+          (networkResponse) => {
+            logMessage(environment, () => ({
+              kind: 'ReceivedNetworkResponse',
+              networkResponse,
+              networkRequestId,
+            }));
+
+            if (networkResponse.errors != null) {
+              const error = new Error('GraphQL network response had errors', {
+                cause: networkResponse,
+              });
+              onError(error);
+              reject(error);
+              if (unsubscribe != null) {
+                unsubscribe();
+              } else {
+                shouldUnsubscribe = true;
+              }
+              return;
+            }
+
+            if (status.kind === 'Disposed') {
+              return;
+            }
+            normalizeData(
+              environment,
+              normalizationAst.selections,
+              networkResponse.data ?? {},
+              variables,
+              artifact.readerWithRefetchQueries.nestedRefetchQueries,
+              root,
+            );
+            if (status.kind === 'UndisposedIncomplete') {
+              const retainedQuery = {
+                normalizationAst: normalizationAst.selections,
+                variables,
+                root,
+              };
+              status = {
+                kind: 'UndisposedComplete',
+                retainedQuery,
+              };
+              retainQuery(environment, retainedQuery);
+
+              const onComplete = fetchOptions?.onComplete;
+              if (onComplete != null) {
+                // artifact is an entrypoint, so this is never null
+                const data = readDataForOnComplete(
+                  artifact,
+                  environment,
+                  root,
+                  variables,
+                ) as TClientFieldValue;
+                try {
+                  onComplete(data);
+                } catch {}
+              }
+            }
+            resolve();
+          },
+          (error) => {
+            onError(error);
+            reject(error);
+          },
+        );
+        if (shouldUnsubscribe) {
+          unsubscribe();
+        }
+      }),
+  );
+
+  const wrapper = wrapPromise(promise);
+
+  const response: ItemCleanupPair<PromiseWrapper<void, AnyError>> = [
+    wrapper,
+    () => {
+      unsubscribe?.();
+      if (status.kind === 'UndisposedComplete') {
+        const didUnretainSomeQuery = unretainQuery(
+          environment,
+          status.retainedQuery,
+        );
+        if (didUnretainSomeQuery) {
+          garbageCollectEnvironment(environment);
+        }
+      }
+      status = {
+        kind: 'Disposed',
+      };
+    },
+  ];
+  return response;
+}
+
+function isAsyncIterable(value: unknown): value is AsyncIterable<any> {
+  return (
+    value != null &&
+    typeof value === 'object' &&
+    Symbol.asyncIterator in value
+  );
+}
+
 type NetworkRequestStatus =
   | {
       readonly kind: 'UndisposedIncomplete';
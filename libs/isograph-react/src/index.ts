@@ -32,6 +32,7 @@ export {
   type DataTypeValue,
   type IsographEnvironment,
   type IsographNetworkFunction,
+  type IsographSubscriptionNetworkFunction,
   type IsographStore,
   type MissingFieldHandler,
   type Link,
@@ -75,6 +76,7 @@ export {
   type NormalizationLinkedField,
   type NormalizationScalarField,
   type IsographEntrypoint,
+  type OperationKind,
   assertIsEntrypoint,
   type RefetchQueryNormalizationArtifact,
   type RefetchQueryNormalizationArtifactWrapper,
@@ -82,7 +84,9 @@ export {
   type ExtractReadFromStore,
   type ExtractResolverResult,
   type NetworkRequestInfo,
+  type IsographOperation,
   type NormalizationInlineFragment,
+  type NormalizationDeferredFragment,
   type ReaderWithRefetchQueries,
   type IsographEntrypointLoader,
 } from './core/entrypoint';
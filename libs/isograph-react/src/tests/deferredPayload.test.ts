@@ -0,0 +1,164 @@
+import { describe, expect, test, vi } from 'vitest';
+import { normalizeData, normalizeIncrementalPayload } from '../core/cache';
+import type {
+  NormalizationAstNodes,
+  RefetchQueryNormalizationArtifact,
+} from '../core/entrypoint';
+import {
+  createIsographEnvironment,
+  createIsographStore,
+  ROOT_ID,
+  type IsographStore,
+} from '../core/IsographEnvironment';
+import { makeNetworkRequest } from '../core/makeNetworkRequest';
+
+// The normalization AST of `query { me { id, name, ... @defer(label: "User__Bio")
+// { id, bio } } }`, i.e. of a query in which User.Bio is selected with
+// @loadable(defer: true).
+const selections: NormalizationAstNodes = [
+  {
+    kind: 'Linked',
+    fieldName: 'me',
+    arguments: null,
+    concreteType: 'User',
+    selections: [
+      { kind: 'Scalar', fieldName: 'id', arguments: null },
+      { kind: 'Scalar', fieldName: 'name', arguments: null },
+      {
+        kind: 'Defer',
+        label: 'User__Bio',
+        selections: [
+          { kind: 'Scalar', fieldName: 'id', arguments: null },
+          { kind: 'Scalar', fieldName: 'bio', arguments: null },
+        ],
+      },
+    ],
+  },
+];
+
+const root = { __link: ROOT_ID, __typename: 'Query' };
+
+const initialNetworkResponse = {
+  data: { me: { id: '1', name: 'Alice' } },
+  hasNext: true,
+};
+
+const subsequentNetworkResponse = {
+  incremental: [
+    { label: 'User__Bio', path: ['me'], data: { id: '1', bio: 'Hello' } },
+  ],
+  hasNext: false,
+};
+
+const storeWithoutBio = (): IsographStore => ({
+  Query: {
+    [ROOT_ID]: {
+      me: { __link: '1', __typename: 'User' },
+    },
+  },
+  User: {
+    '1': { id: '1', name: 'Alice' },
+  },
+});
+
+const storeWithBio = (): IsographStore => ({
+  Query: {
+    [ROOT_ID]: {
+      me: { __link: '1', __typename: 'User' },
+    },
+  },
+  User: {
+    '1': { id: '1', name: 'Alice', bio: 'Hello' },
+  },
+});
+
+describe('deferred payloads', () => {
+  test('deferred selections are not normalized until their payload arrives', () => {
+    const store = createIsographStore();
+    const environment = createIsographEnvironment(store, vi.fn());
+
+    normalizeData(
+      environment,
+      selections,
+      initialNetworkResponse.data,
+      {},
+      [],
+      root,
+    );
+    expect(store).toStrictEqual(storeWithoutBio());
+
+    const wasNormalized = normalizeIncrementalPayload(
+      environment,
+      selections,
+      subsequentNetworkResponse.incremental[0]!,
+      {},
+      [],
+      root,
+    );
+    expect(wasNormalized).toBe(true);
+    expect(store).toStrictEqual(storeWithBio());
+  });
+
+  test('payloads with an unknown label or path are ignored', () => {
+    const store = createIsographStore();
+    const environment = createIsographEnvironment(store, vi.fn());
+    normalizeData(
+      environment,
+      selections,
+      initialNetworkResponse.data,
+      {},
+      [],
+      root,
+    );
+
+    expect(
+      normalizeIncrementalPayload(
+        environment,
+        selections,
+        { label: 'User__Unknown', path: ['me'], data: { bio: 'Hello' } },
+        {},
+        [],
+        root,
+      ),
+    ).toBe(false);
+    expect(
+      normalizeIncrementalPayload(
+        environment,
+        selections,
+        { label: 'User__Bio', path: ['you'], data: { bio: 'Hello' } },
+        {},
+        [],
+        root,
+      ),
+    ).toBe(false);
+    expect(store).toStrictEqual(storeWithoutBio());
+  });
+
+  test('makeNetworkRequest normalizes each streamed network response', async () => {
+    const store = createIsographStore();
+    async function* networkResponses() {
+      yield initialNetworkResponse;
+      yield subsequentNetworkResponse;
+    }
+    const environment = createIsographEnvironment(
+      store,
+      vi.fn().mockResolvedValue(networkResponses()),
+    );
+    const artifact: RefetchQueryNormalizationArtifact = {
+      kind: 'RefetchQuery',
+      networkRequestInfo: {
+        kind: 'NetworkRequestInfo',
+        queryText: 'query Home { ... }',
+        normalizationAst: { kind: 'NormalizationAst', selections },
+      },
+      concreteType: 'Query',
+    };
+
+    const [networkRequest] = makeNetworkRequest(environment, artifact, {});
+    await networkRequest.promise;
+
+    await vi.waitFor(() => {
+      expect(store).toStrictEqual(storeWithBio());
+    });
+  });
+});
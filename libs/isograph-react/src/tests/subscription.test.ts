@@ -0,0 +1,218 @@
+import { describe, expect, test, vi } from 'vitest';
+import type { IsographEntrypoint, NormalizationAst } from '../core/entrypoint';
+import {
+  createIsographEnvironment,
+  createIsographStore,
+  type IsographSubscriptionNetworkFunction,
+} from '../core/IsographEnvironment';
+import { makeNetworkRequest } from '../core/makeNetworkRequest';
+
+// The entrypoint of `subscription { counter { id, count } }`
+const entrypoint: IsographEntrypoint<any, string, NormalizationAst> = {
+  kind: 'Entrypoint',
+  operationKind: 'Subscription',
+  networkRequestInfo: {
+    kind: 'NetworkRequestInfo',
+    queryText: 'subscription CounterSubscription { counter { id, count } }',
+    normalizationAst: {
+      kind: 'NormalizationAst',
+      selections: [
+        {
+          kind: 'Linked',
+          fieldName: 'counter',
+          arguments: null,
+          concreteType: 'Counter',
+          selections: [
+            { kind: 'Scalar', fieldName: 'id', arguments: null },
+            { kind: 'Scalar', fieldName: 'count', arguments: null },
+          ],
+        },
+      ],
+    },
+  },
+  concreteType: 'Subscription',
+  readerWithRefetchQueries: {
+    kind: 'ReaderWithRefetchQueries',
+    nestedRefetchQueries: [],
+    readerArtifact: {
+      kind: 'EagerReaderArtifact',
+      fieldName: 'CounterSubscription',
+      readerAst: [],
+      resolver: () => 'CounterSubscription value',
+      hasUpdatable: false,
+    },
+  },
+};
+
+const counterPayload = (count: number) => ({
+  data: { counter: { id: '1', count } },
+});
+
+const errorPayload = { errors: [{ message: 'Not allowed' }] };
+
+/**
+ * A subscriptionNetworkFunction whose onNext and onError callbacks can be called
+ * by the test, once it has been called by makeNetworkRequest.
+ */
+function mockSubscriptionNetworkFunction() {
+  const unsubscribe = vi.fn();
+  let callbacks: {
+    onNext: (networkResponse: any) => void;
+    onError: (error: any) => void;
+  } | null = null;
+  const subscribe: IsographSubscriptionNetworkFunction = (
+    _operation,
+    _variables,
+    onNext,
+    onError,
+  ) => {
+    callbacks = { onNext, onError };
+    return unsubscribe;
+  };
+  const subscriptionNetworkFunction = vi.fn(subscribe);
+  const subscribed = async () => {
+    await vi.waitFor(() => {
+      expect(subscriptionNetworkFunction).toHaveBeenCalledTimes(1);
+    });
+    return callbacks!;
+  };
+  return { subscriptionNetworkFunction, unsubscribe, subscribed };
+}
+
+describe('makeNetworkRequest with a subscription', () => {
+  test('each network response is normalized', async () => {
+    const store = createIsographStore();
+    const { subscriptionNetworkFunction, subscribed } =
+      mockSubscriptionNetworkFunction();
+    const environment = createIsographEnvironment(
+      store,
+      vi.fn(),
+      null,
+      null,
+      subscriptionNetworkFunction,
+    );
+
+    const [networkRequest] = makeNetworkRequest(environment, entrypoint, {});
+    const { onNext } = await subscribed();
+
+    onNext(counterPayload(1));
+    await networkRequest.promise;
+    expect(store['Counter']).toStrictEqual({ '1': { id: '1', count: 1 } });
+
+    onNext(counterPayload(2));
+    expect(store['Counter']).toStrictEqual({ '1': { id: '1', count: 2 } });
+  });
+
+  test('onComplete is called once, with the first network response', async () => {
+    const { subscriptionNetworkFunction, subscribed } =
+      mockSubscriptionNetworkFunction();
+    const environment = createIsographEnvironment(
+      createIsographStore(),
+      vi.fn(),
+      null,
+      null,
+      subscriptionNetworkFunction,
+    );
+    const onComplete = vi.fn();
+
+    const [networkRequest] = makeNetworkRequest(
+      environment,
+      entrypoint,
+      {},
+      { onComplete },
+    );
+    const { onNext } = await subscribed();
+    onNext(counterPayload(1));
+    onNext(counterPayload(2));
+    await networkRequest.promise;
+
+    expect(onComplete).toHaveBeenCalledTimes(1);
+    expect(onComplete).toHaveBeenCalledWith('CounterSubscription value');
+  });
+
+  test('the subscription is unsubscribed if a network response has errors', async () => {
+    const store = createIsographStore();
+    const { subscriptionNetworkFunction, unsubscribe, subscribed } =
+      mockSubscriptionNetworkFunction();
+    const environment = createIsographEnvironment(
+      store,
+      vi.fn(),
+      null,
+      null,
+      subscriptionNetworkFunction,
+    );
+    const onError = vi.fn();
+
+    const [networkRequest] = makeNetworkRequest(
+      environment,
+      entrypoint,
+      {},
+      { onError },
+    );
+    const { onNext } = await subscribed();
+    onNext(errorPayload);
+
+    await expect(networkRequest.promise).rejects.toThrow(
+      'GraphQL network response had errors',
+    );
+    expect(onError).toHaveBeenCalledTimes(1);
+    expect(unsubscribe).toHaveBeenCalledTimes(1);
+    expect(store['Counter']).toBeUndefined();
+  });
+
+  test('the subscription is unsubscribed if a network response with errors arrives before subscribing returns', async () => {
+    const unsubscribe = vi.fn();
+    const environment = createIsographEnvironment(
+      createIsographStore(),
+      vi.fn(),
+      null,
+      null,
+      (_operation, _variables, onNext) => {
+        onNext(errorPayload);
+        return unsubscribe;
+      },
+    );
+    const onError = vi.fn();
+
+    const [networkRequest] = makeNetworkRequest(
+      environment,
+      entrypoint,
+      {},
+      { onError },
+    );
+
+    await expect(networkRequest.promise).rejects.toThrow(
+      'GraphQL network response had errors',
+    );
+    expect(onError).toHaveBeenCalledTimes(1);
+    expect(unsubscribe).toHaveBeenCalledTimes(1);
+  });
+
+  test('disposing unsubscribes, and later network responses are ignored', async () => {
+    const store = createIsographStore();
+    const { subscriptionNetworkFunction, unsubscribe, subscribed } =
+      mockSubscriptionNetworkFunction();
+    const environment = createIsographEnvironment(
+      store,
+      vi.fn(),
+      null,
+      null,
+      subscriptionNetworkFunction,
+    );
+
+    const [networkRequest, dispose] = makeNetworkRequest(
+      environment,
+      entrypoint,
+      {},
+    );
+    const { onNext } = await subscribed();
+    onNext(counterPayload(1));
+    await networkRequest.promise;
+
+    dispose();
+    expect(unsubscribe).toHaveBeenCalledTimes(1);
+
+    onNext(counterPayload(2));
+    expect(store['Counter']?.['1']?.['count']).not.toBe(2);
+  });
+});